@@ -12,6 +12,7 @@ use crate::{
     shapes::{
         enums::{GenderEnum, TeamClassificationEnum},
         r#match::MatchEntry,
+        timing::SetTimingEntry,
     },
 };
 use futures::TryFutureExt;
@@ -45,6 +46,8 @@ pub struct MatchMetadata {
     pub gender: Option<GenderEnum>,
     pub year: u16,
     pub classification: Option<TeamClassificationEnum>,
+    #[serde(default)]
+    pub timing: Vec<SetTimingEntry>,
 }
 
 // generate a deterministic UUID v5 from team_id and match_id
@@ -103,6 +106,14 @@ async fn create_match_archive(
         gender: m.team.gender,
         year: m.team.year,
         classification: m.team.classification,
+        timing: m
+            .sets
+            .iter()
+            .filter_map(|set| {
+                let (snapshot, _) = set.compute_snapshot().ok()?;
+                snapshot.get_timing(set.set_number)
+            })
+            .collect(),
     };
     let metadata_json = to_string_pretty(&metadata).map_err(|e| AppError::IO(IOError::from(e)))?;
     zip.start_file::<_, ()>(
@@ -2,9 +2,9 @@ use std::{path::PathBuf, sync::Arc};
 
 use crate::{
     providers::{
-        match_reader::MatchReader, match_writer::MatchWriter, set_writer::SetWriter,
-        settings_reader::SettingsReader, settings_writer::SettingsWriter, team_reader::TeamReader,
-        team_writer::TeamWriter,
+        match_reader::MatchReader, match_writer::MatchWriter, opponent_reader::OpponentReader,
+        opponent_writer::OpponentWriter, set_writer::SetWriter, settings_reader::SettingsReader,
+        settings_writer::SettingsWriter, team_reader::TeamReader, team_writer::TeamWriter,
     },
     screens::{screen::ScreenAsync, team_list_screen::TeamListScreen},
     shapes::{settings::Settings, team::TeamEntry},
@@ -21,6 +21,8 @@ impl App {
         SW: SettingsWriter + Send + Sync + 'static,
         MR: MatchReader + Send + Sync + 'static,
         MW: MatchWriter + Send + Sync + 'static,
+        OR: OpponentReader + Send + Sync + 'static,
+        OW: OpponentWriter + Send + Sync + 'static,
         SSW: SetWriter + Send + Sync + 'static,
         SR: SettingsReader + Send + Sync + 'static,
     >(
@@ -33,6 +35,8 @@ impl App {
         set_writer: Arc<SSW>,
         match_reader: Arc<MR>,
         match_writer: Arc<MW>,
+        opponent_reader: Arc<OR>,
+        opponent_writer: Arc<OW>,
         settings_reader: Arc<SR>,
     ) -> Self {
         Self {
@@ -45,6 +49,8 @@ impl App {
                 settings_writer,
                 match_reader,
                 match_writer,
+                opponent_reader,
+                opponent_writer,
                 set_writer,
                 settings_reader,
             ))],
@@ -1,7 +1,16 @@
 pub const MATCH_DESCRIPTOR_FILE_NAME: &str = "match.json";
 pub const TEAM_DESCRIPTOR_FILE_NAME: &str = "team.json";
+pub const OPPONENTS_FILE_NAME: &str = "opponents.json";
 pub const UPLOAD_QUEUE_FILE_NAME: &str = "upload_queue.json";
 pub const DEFAULT_SET_TARGET_SCORE: u8 = 25;
 pub const TIE_BREAK_SET_TARGET_SCORE: u8 = 15;
 pub const MAX_SUBSTITUTIONS: usize = 6;
 pub const DEFAULT_LANGUAGE: &str = "en";
+/// Pauses between rallies longer than this (in seconds) are reported as long breaks.
+pub const LONG_BREAK_SECONDS: i64 = 90;
+/// Seconds of video kept before an event when it is exported as a clip.
+pub const CLIP_PRE_ROLL_SECONDS: f64 = 5.0;
+/// Seconds of video kept after an event when it is exported as a clip.
+pub const CLIP_POST_ROLL_SECONDS: f64 = 3.0;
+/// Frame rate used for the timecodes of the exported edit decision lists.
+pub const EDL_FRAMES_PER_SECOND: u32 = 25;
@@ -228,6 +228,206 @@ pub struct Labels {
     pub delete: &'static str,
     pub reset: &'static str,
     pub reset_to_defaults_confirmation: &'static str,
+    pub rotation_analysis: &'static str,
+    pub sideout_perc: &'static str,
+    pub break_point_perc: &'static str,
+    pub first_ball_sideout_perc: &'static str,
+    pub rotation_passes: &'static str,
+    pub won: &'static str,
+    pub lost: &'static str,
+    pub balance: &'static str,
+    pub balance_per_pass: &'static str,
+    pub reception_analysis: &'static str,
+    pub share: &'static str,
+    pub sideout_after_pass: &'static str,
+    pub kill_after_pass: &'static str,
+    pub pass_quality: &'static str,
+    pub setter_distribution: &'static str,
+    pub top_zone: &'static str,
+    pub predictability: &'static str,
+    pub zone: &'static str,
+    pub distribution_kill: &'static str,
+    pub on_court: &'static str,
+    pub rallies: &'static str,
+    pub plus_minus: &'static str,
+    pub started_entered: &'static str,
+    pub lib: &'static str,
+    pub lineup_combinations: &'static str,
+    pub combination: &'static str,
+    pub pairs: &'static str,
+    pub pair: &'static str,
+    pub sort: &'static str,
+    pub sorted_by: &'static str,
+    pub could_not_save_opponent: &'static str,
+    pub away: &'static str,
+    pub venue: &'static str,
+    pub head_to_head: &'static str,
+    pub opponents: &'static str,
+    pub aliases: &'static str,
+    pub notes: &'static str,
+    pub new_opponent: &'static str,
+    pub edit_opponent: &'static str,
+    pub no_opponents_yet: &'static str,
+    pub no_opponent_selected: &'static str,
+    pub could_not_load_opponents: &'static str,
+    pub opponent_name_already_in_use: &'static str,
+    pub matches: &'static str,
+    pub right_handed: &'static str,
+    pub left_handed: &'static str,
+    pub birth_date: &'static str,
+    pub height_cm: &'static str,
+    pub dominant_hand: &'static str,
+    pub secondary_role: &'static str,
+    pub captain: &'static str,
+    pub no_role: &'static str,
+    pub dig: &'static str,
+    pub invalid_birth_date: &'static str,
+    pub not_set: &'static str,
+    pub set_touch: &'static str,
+    pub free_ball: &'static str,
+    pub attacker_facing_single_block: &'static str,
+    pub set_and_free_ball: &'static str,
+    pub won_perc: &'static str,
+    pub after_set: &'static str,
+    pub kill_perc: &'static str,
+    pub solo_block: &'static str,
+    pub double_block: &'static str,
+    pub triple_block: &'static str,
+    pub blockers: &'static str,
+    pub touched: &'static str,
+    pub block_composition: &'static str,
+    pub opponent_attack_zone: &'static str,
+    pub block_eval_hint: &'static str,
+    pub quick_tempo: &'static str,
+    pub half_tempo: &'static str,
+    pub high_tempo: &'static str,
+    pub tempo: &'static str,
+    pub code: &'static str,
+    pub expected_role: &'static str,
+    pub attack_combinations: &'static str,
+    pub new_attack_combination: &'static str,
+    pub edit_attack_combination: &'static str,
+    pub no_attack_combinations_yet: &'static str,
+    pub code_cannot_be_empty: &'static str,
+    pub code_already_in_use: &'static str,
+    pub could_not_save_team: &'static str,
+    pub unknown_attack_combination: &'static str,
+    pub attack_combination_hint: &'static str,
+    pub remove_attack_combination_confirmation: &'static str,
+    pub float_serve: &'static str,
+    pub jump_float_serve: &'static str,
+    pub jump_spin_serve: &'static str,
+    pub underhand_serve: &'static str,
+    pub serve_type: &'static str,
+    pub serve_types: &'static str,
+    pub serve_type_hint: &'static str,
+    pub server: &'static str,
+    pub rally: &'static str,
+    pub short_rally: &'static str,
+    pub medium_rally: &'static str,
+    pub long_rally: &'static str,
+    pub rally_length: &'static str,
+    pub contacts: &'static str,
+    pub duration: &'static str,
+    pub serving: &'static str,
+    pub winner: &'static str,
+    pub no_rallies_yet: &'static str,
+    pub rally_browser: &'static str,
+    pub timing: &'static str,
+    pub start_time: &'static str,
+    pub end_time: &'static str,
+    pub playing_time: &'static str,
+    pub between_rallies: &'static str,
+    pub long_breaks: &'static str,
+    pub time_of_day: &'static str,
+    pub attach_video: &'static str,
+    pub sync_video: &'static str,
+    pub export_clips: &'static str,
+    pub video: &'static str,
+    pub video_position: &'static str,
+    pub sync_video_hint: &'static str,
+    pub invalid_video_position: &'static str,
+    pub no_video: &'static str,
+    pub no_clips_found: &'static str,
+    pub could_not_save_set: &'static str,
+    pub player_not_found: &'static str,
+    pub event_type: &'static str,
+    pub evaluation: &'static str,
+    pub clip_list_format: &'static str,
+    pub edl_format: &'static str,
+    pub ffmpeg_concat_format: &'static str,
+    pub xml_timeline_format: &'static str,
+    pub last_event: &'static str,
+    pub set_word: &'static str,
+    pub tags: &'static str,
+    pub note_target: &'static str,
+    pub add_notes: &'static str,
+    pub no_events_yet: &'static str,
+    pub could_not_save_notes: &'static str,
+    pub score_adjustment: &'static str,
+    pub score_adjustments: &'static str,
+    pub point_awarded_to: &'static str,
+    pub time: &'static str,
+    pub mid_set_start: &'static str,
+    pub our_score: &'static str,
+    pub their_score: &'static str,
+    pub setter_position: &'static str,
+    pub invalid_mid_set_start: &'static str,
+    pub mid_set_start_hint: &'static str,
+    pub scouted_from: &'static str,
+    pub partially_scouted: &'static str,
+    pub fix_lineup: &'static str,
+    pub wrong_server: &'static str,
+    pub back_row_attack: &'static str,
+    pub back_row_block: &'static str,
+    pub libero_serve: &'static str,
+    pub libero_attack: &'static str,
+    pub lineup_warning: &'static str,
+    pub lineup_warning_hint: &'static str,
+    pub fix_lineup_hint: &'static str,
+    pub could_not_fix_lineup: &'static str,
+    pub change_server_hint: &'static str,
+    pub expected_server: &'static str,
+    pub edit_set_setup: &'static str,
+    pub setup_conflicts: &'static str,
+    pub setup_conflicts_hint: &'static str,
+    pub lineup_templates: &'static str,
+    pub save_lineup_template: &'static str,
+    pub same_as_previous_set: &'static str,
+    pub previous_set_rotated_by: &'static str,
+    pub template_name: &'static str,
+    pub template_name_cannot_be_empty: &'static str,
+    pub save_lineup_template_hint: &'static str,
+    pub no_lineup_templates: &'static str,
+    pub lineup_template_not_available: &'static str,
+    pub code_line: &'static str,
+    pub code_line_hint: &'static str,
+    pub code_line_events: &'static str,
+    pub code_line_error: &'static str,
+    pub invalid_code: &'static str,
+    pub code_missing_evaluation: &'static str,
+    pub code_evaluation_not_available: &'static str,
+    pub code_missing_player: &'static str,
+    pub player_not_on_court: &'static str,
+    pub player_not_allowed_for_event: &'static str,
+    pub player_cannot_be_replaced: &'static str,
+    pub invalid_replacement: &'static str,
+    pub opponent_event: &'static str,
+    pub lineup_change: &'static str,
+    pub point_to_us: &'static str,
+    pub point_to_them: &'static str,
+    pub eval_key: &'static str,
+    pub event_option: &'static str,
+    pub player_slot: &'static str,
+    pub scouting_keys: &'static str,
+    pub key_already_used: &'static str,
+    pub key_types_combination: &'static str,
+    pub redo: &'static str,
+    pub redo_available: &'static str,
+    pub set_over_hint: &'static str,
+    pub live_stats: &'static str,
+    pub reception_positive_perc: &'static str,
+    pub attack_efficiency_perc: &'static str,
 }
 
 const EN: Labels = Labels {
@@ -451,6 +651,206 @@ const EN: Labels = Labels {
     delete: "delete",
     reset: "reset to defaults",
     reset_to_defaults_confirmation: "resetting keybindings to their default values: irreversible operation. Are you sure? (y/n)",
+    rotation_analysis: "rotation analysis",
+    sideout_perc: "so%",
+    break_point_perc: "bp%",
+    first_ball_sideout_perc: "fbso%",
+    rotation_passes: "passes",
+    won: "won",
+    lost: "lost",
+    balance: "balance",
+    balance_per_pass: "bal/pass",
+    reception_analysis: "reception analysis",
+    share: "share",
+    sideout_after_pass: "side-out % after pass",
+    kill_after_pass: "kill % after pass",
+    pass_quality: "pass quality",
+    setter_distribution: "setter distribution",
+    top_zone: "top zone",
+    predictability: "predictability",
+    zone: "zone",
+    distribution_kill: "dist / kill",
+    on_court: "on court",
+    rallies: "ral",
+    plus_minus: "+/-",
+    started_entered: "st/en",
+    lib: "lib",
+    lineup_combinations: "lineup combinations",
+    combination: "combination",
+    pairs: "pairs",
+    pair: "pair",
+    sort: "sort",
+    sorted_by: "sorted by",
+    could_not_save_opponent: "could not save opponent",
+    away: "away",
+    venue: "venue",
+    head_to_head: "head-to-head",
+    opponents: "opponents",
+    aliases: "aliases (comma separated)",
+    notes: "notes",
+    new_opponent: "new opponent",
+    edit_opponent: "edit opponent",
+    no_opponents_yet: "no opponents yet",
+    no_opponent_selected: "no opponent selected",
+    could_not_load_opponents: "could not load opponents",
+    opponent_name_already_in_use: "name or alias already used by another opponent",
+    matches: "matches",
+    right_handed: "right",
+    left_handed: "left",
+    birth_date: "birth date",
+    height_cm: "height (cm)",
+    dominant_hand: "dominant hand",
+    secondary_role: "secondary role",
+    captain: "captain",
+    no_role: "no role",
+    dig: "dig",
+    invalid_birth_date: "invalid birth date",
+    not_set: "-",
+    set_touch: "set",
+    free_ball: "free ball",
+    attacker_facing_single_block: "attacker facing a single block or none",
+    set_and_free_ball: "sets and free balls",
+    won_perc: "won%",
+    after_set: "attack after set",
+    kill_perc: "kill%",
+    solo_block: "solo",
+    double_block: "double",
+    triple_block: "triple",
+    blockers: "blockers",
+    touched: "touched",
+    block_composition: "block composition",
+    opponent_attack_zone: "opponent attack zone",
+    block_eval_hint: "{} => add/remove blocker, {} => touched",
+    quick_tempo: "quick",
+    half_tempo: "half",
+    high_tempo: "high",
+    tempo: "tempo",
+    code: "code",
+    expected_role: "expected attacker",
+    attack_combinations: "attack combinations",
+    new_attack_combination: "new attack combination",
+    edit_attack_combination: "edit attack combination",
+    no_attack_combinations_yet: "no attack combinations yet",
+    code_cannot_be_empty: "code cannot be empty",
+    code_already_in_use: "code already in use",
+    could_not_save_team: "could not save the team",
+    unknown_attack_combination: "unknown attack combination: {}",
+    attack_combination_hint: "type a combination code, then the evaluation",
+    remove_attack_combination_confirmation: "removing attack combination '{}'. Are you sure? (y/n)",
+    float_serve: "float",
+    jump_float_serve: "jump float",
+    jump_spin_serve: "jump spin",
+    underhand_serve: "underhand",
+    serve_type: "serve type",
+    serve_types: "serve types",
+    serve_type_hint: "{} => change serve type",
+    server: "server",
+    rally: "rally",
+    short_rally: "short (0-2 contacts)",
+    medium_rally: "medium (3-5 contacts)",
+    long_rally: "long (6+ contacts)",
+    rally_length: "rally length",
+    contacts: "contacts",
+    duration: "duration",
+    serving: "serving",
+    winner: "winner",
+    no_rallies_yet: "no rallies yet",
+    rally_browser: "rallies",
+    timing: "timing",
+    start_time: "start",
+    end_time: "end",
+    playing_time: "playing time",
+    between_rallies: "avg between rallies",
+    long_breaks: "long breaks",
+    time_of_day: "time of day",
+    attach_video: "attach video",
+    sync_video: "sync video",
+    export_clips: "export clips",
+    video: "video",
+    video_position: "video position (m:ss)",
+    sync_video_hint: "enter the position in the video where this event can be seen:",
+    invalid_video_position: "invalid video position",
+    no_video: "no video attached",
+    no_clips_found: "no events match the filter",
+    could_not_save_set: "could not save set",
+    player_not_found: "player not found",
+    event_type: "event type",
+    evaluation: "evaluation",
+    clip_list_format: "format",
+    edl_format: "EDL (CMX 3600)",
+    ffmpeg_concat_format: "ffmpeg concat script",
+    xml_timeline_format: "XML timeline (Sportscode/Hudl)",
+    last_event: "last event",
+    set_word: "set",
+    tags: "tags",
+    note_target: "refers to",
+    add_notes: "notes and tags",
+    no_events_yet: "no events yet",
+    could_not_save_notes: "could not save notes",
+    score_adjustment: "score adjustment",
+    score_adjustments: "score adjustments",
+    point_awarded_to: "point awarded to",
+    time: "time",
+    mid_set_start: "start mid-set",
+    our_score: "our score",
+    their_score: "their score",
+    setter_position: "setter position",
+    invalid_mid_set_start: "invalid score: the set would already be over",
+    mid_set_start_hint: "enter the lineup as in the scoresheet: it is rotated to the setter position",
+    scouted_from: "scouted from",
+    partially_scouted: "partially scouted",
+    fix_lineup: "fix lineup",
+    wrong_server: "the server is not the expected one",
+    back_row_attack: "back-row player attacking at the net",
+    back_row_block: "back-row player blocking",
+    libero_serve: "the libero is serving",
+    libero_attack: "the libero is attacking from the front court",
+    lineup_warning: "lineup warning",
+    lineup_warning_hint: "confirm to record the event flagged, or go back to change it",
+    fix_lineup_hint: "fix lineup: swap {} with the expected server in the starting lineup",
+    could_not_fix_lineup: "could not fix the lineup: the players were substituted",
+    change_server_hint: "{} => change the server",
+    expected_server: "expected server",
+    edit_set_setup: "edit set setup",
+    setup_conflicts: "setup conflicts",
+    setup_conflicts_hint: "these events do not fit the new starting setup: go back and change it",
+    lineup_templates: "lineup templates",
+    save_lineup_template: "save as template",
+    same_as_previous_set: "same as previous set",
+    previous_set_rotated_by: "previous set rotated by {}",
+    template_name: "template name",
+    template_name_cannot_be_empty: "the template name cannot be empty",
+    save_lineup_template_hint: "a template with the same name is replaced",
+    no_lineup_templates: "no saved lineups and no previous set yet",
+    lineup_template_not_available: "some players of this lineup are not in the team anymore",
+    code_line: "code line",
+    code_line_hint: "codes separated by spaces, e.g. S# 7P+ 12AX1# 4.9BT- 3R15 5CS OE MU",
+    code_line_events: "events to add",
+    code_line_error: "code {}: {}",
+    invalid_code: "invalid code: {}",
+    code_missing_evaluation: "missing evaluation: {}",
+    code_evaluation_not_available: "evaluation not available: {}",
+    code_missing_player: "missing player number: {}",
+    player_not_on_court: "no player with number {} on court",
+    player_not_allowed_for_event: "player {} cannot be chosen for this event",
+    player_cannot_be_replaced: "player {} cannot be replaced",
+    invalid_replacement: "player {} cannot come in",
+    opponent_event: "opponent score / error",
+    lineup_change: "libero / setter change",
+    point_to_us: "point to us",
+    point_to_them: "point to them",
+    eval_key: "evaluation {}",
+    event_option: "block touch / serve type",
+    player_slot: "player {}",
+    scouting_keys: "scouting keys",
+    key_already_used: "{} is already used by: {}",
+    key_types_combination: "{} types the attack combination codes, evaluations cannot use letters or digits",
+    redo: "redo",
+    redo_available: "{} to redo",
+    set_over_hint: "press {} to undo the last event or {} to go back",
+    live_stats: "live stats",
+    reception_positive_perc: "rec. pos%",
+    attack_efficiency_perc: "att. eff%",
     #[cfg(feature = "self-update")]
     updated_to_version: "updated to version",
     #[cfg(feature = "self-update")]
@@ -678,6 +1078,206 @@ const IT: Labels = Labels {
     delete: "elimina",
     reset: "ripristina i valori predefiniti",
     reset_to_defaults_confirmation: "ripristino delle scorciatoie ai valori predefiniti: operazione irreversibile. Confermi? (s/n)",
+    rotation_analysis: "analisi delle rotazioni",
+    sideout_perc: "cp%",
+    break_point_perc: "bp%",
+    first_ball_sideout_perc: "cp1%",
+    rotation_passes: "passaggi",
+    won: "vinti",
+    lost: "persi",
+    balance: "saldo",
+    balance_per_pass: "saldo/pass",
+    reception_analysis: "analisi della ricezione",
+    share: "quota",
+    sideout_after_pass: "% cambio palla dopo ricezione",
+    kill_after_pass: "% punto dopo ricezione",
+    pass_quality: "qualità ricezione",
+    setter_distribution: "distribuzione del palleggiatore",
+    top_zone: "zona prev.",
+    predictability: "prevedibilità",
+    zone: "zona",
+    distribution_kill: "dist / pt",
+    on_court: "in campo",
+    rallies: "sca",
+    plus_minus: "+/-",
+    started_entered: "tit/ent",
+    lib: "lib",
+    lineup_combinations: "combinazioni di formazione",
+    combination: "combinazione",
+    pairs: "coppie",
+    pair: "coppia",
+    sort: "ordina",
+    sorted_by: "ordinato per",
+    could_not_save_opponent: "impossibile salvare l'avversario",
+    away: "trasferta",
+    venue: "campo",
+    head_to_head: "scontri diretti",
+    opponents: "avversari",
+    aliases: "alias (separati da virgola)",
+    notes: "note",
+    new_opponent: "nuovo avversario",
+    edit_opponent: "modifica avversario",
+    no_opponents_yet: "nessun avversario",
+    no_opponent_selected: "nessun avversario selezionato",
+    could_not_load_opponents: "impossibile caricare gli avversari",
+    opponent_name_already_in_use: "nome o alias già usato da un altro avversario",
+    matches: "partite",
+    right_handed: "destro",
+    left_handed: "mancino",
+    birth_date: "data di nascita",
+    height_cm: "altezza (cm)",
+    dominant_hand: "mano dominante",
+    secondary_role: "ruolo secondario",
+    captain: "capitano",
+    no_role: "senza ruolo",
+    dig: "dif",
+    invalid_birth_date: "data di nascita non valida",
+    not_set: "-",
+    set_touch: "alzata",
+    free_ball: "free ball",
+    attacker_facing_single_block: "attaccante contro muro a uno o senza muro",
+    set_and_free_ball: "alzate e free ball",
+    won_perc: "vinti%",
+    after_set: "attacco dopo alzata",
+    kill_perc: "punto%",
+    solo_block: "singolo",
+    double_block: "doppio",
+    triple_block: "triplo",
+    blockers: "muro",
+    touched: "toccato",
+    block_composition: "composizione muro",
+    opponent_attack_zone: "zona attacco avversario",
+    block_eval_hint: "{} => aggiungi/togli murista, {} => toccato",
+    quick_tempo: "veloce",
+    half_tempo: "mezza",
+    high_tempo: "alta",
+    tempo: "tempo",
+    code: "codice",
+    expected_role: "attaccante previsto",
+    attack_combinations: "combinazioni d'attacco",
+    new_attack_combination: "nuova combinazione d'attacco",
+    edit_attack_combination: "modifica combinazione d'attacco",
+    no_attack_combinations_yet: "nessuna combinazione d'attacco",
+    code_cannot_be_empty: "il codice non può essere vuoto",
+    code_already_in_use: "codice già utilizzato",
+    could_not_save_team: "impossibile salvare la squadra",
+    unknown_attack_combination: "combinazione d'attacco sconosciuta: {}",
+    attack_combination_hint: "digita il codice della combinazione, poi la valutazione",
+    remove_attack_combination_confirmation: "rimozione della combinazione d'attacco '{}'. Sei sicuro? (s/n)",
+    float_serve: "float",
+    jump_float_serve: "salto float",
+    jump_spin_serve: "salto spin",
+    underhand_serve: "dal basso",
+    serve_type: "tipo di battuta",
+    serve_types: "tipi di battuta",
+    serve_type_hint: "{} => cambia tipo di battuta",
+    server: "battitore",
+    rally: "scambio",
+    short_rally: "breve (0-2 tocchi)",
+    medium_rally: "medio (3-5 tocchi)",
+    long_rally: "lungo (6+ tocchi)",
+    rally_length: "lunghezza scambio",
+    contacts: "tocchi",
+    duration: "durata",
+    serving: "in battuta",
+    winner: "vincitore",
+    no_rallies_yet: "nessuno scambio",
+    rally_browser: "scambi",
+    timing: "tempi",
+    start_time: "inizio",
+    end_time: "fine",
+    playing_time: "tempo effettivo",
+    between_rallies: "media tra scambi",
+    long_breaks: "pause lunghe",
+    time_of_day: "orario",
+    attach_video: "collega video",
+    sync_video: "sincronizza video",
+    export_clips: "esporta clip",
+    video: "video",
+    video_position: "posizione nel video (m:ss)",
+    sync_video_hint: "inserisci la posizione nel video in cui si vede questo evento:",
+    invalid_video_position: "posizione nel video non valida",
+    no_video: "nessun video collegato",
+    no_clips_found: "nessun evento corrisponde al filtro",
+    could_not_save_set: "impossibile salvare il set",
+    player_not_found: "giocatore non trovato",
+    event_type: "tipo di evento",
+    evaluation: "valutazione",
+    clip_list_format: "formato",
+    edl_format: "EDL (CMX 3600)",
+    ffmpeg_concat_format: "script concat di ffmpeg",
+    xml_timeline_format: "timeline XML (Sportscode/Hudl)",
+    last_event: "ultimo evento",
+    set_word: "set",
+    tags: "tag",
+    note_target: "riferita a",
+    add_notes: "note e tag",
+    no_events_yet: "nessun evento",
+    could_not_save_notes: "impossibile salvare le note",
+    score_adjustment: "correzione punteggio",
+    score_adjustments: "correzioni del punteggio",
+    point_awarded_to: "punto assegnato a",
+    time: "ora",
+    mid_set_start: "inizia a set in corso",
+    our_score: "nostro punteggio",
+    their_score: "punteggio avversari",
+    setter_position: "posizione del palleggiatore",
+    invalid_mid_set_start: "punteggio non valido: il set sarebbe già concluso",
+    mid_set_start_hint: "inserisci la formazione del referto: viene ruotata fino alla posizione del palleggiatore",
+    scouted_from: "rilevato da",
+    partially_scouted: "rilevato in parte",
+    fix_lineup: "correggi formazione",
+    wrong_server: "il battitore non è quello previsto",
+    back_row_attack: "giocatore di seconda linea che attacca a rete",
+    back_row_block: "giocatore di seconda linea a muro",
+    libero_serve: "il libero è in battuta",
+    libero_attack: "il libero attacca dalla prima linea",
+    lineup_warning: "avviso formazione",
+    lineup_warning_hint: "conferma per registrare l'evento segnalato, o torna indietro per modificarlo",
+    fix_lineup_hint: "correggi formazione: scambia {} con il battitore previsto nella formazione iniziale",
+    could_not_fix_lineup: "impossibile correggere la formazione: i giocatori sono stati sostituiti",
+    change_server_hint: "{} => cambia il battitore",
+    expected_server: "battitore previsto",
+    edit_set_setup: "modifica formazione iniziale",
+    setup_conflicts: "conflitti di formazione",
+    setup_conflicts_hint: "questi eventi non sono compatibili con la nuova formazione iniziale: torna indietro e modificala",
+    lineup_templates: "formazioni salvate",
+    save_lineup_template: "salva come formazione",
+    same_as_previous_set: "come il set precedente",
+    previous_set_rotated_by: "set precedente ruotato di {}",
+    template_name: "nome formazione",
+    template_name_cannot_be_empty: "il nome della formazione non può essere vuoto",
+    save_lineup_template_hint: "una formazione con lo stesso nome viene sostituita",
+    no_lineup_templates: "nessuna formazione salvata e nessun set precedente",
+    lineup_template_not_available: "alcuni giocatori di questa formazione non sono più nella squadra",
+    code_line: "riga di codici",
+    code_line_hint: "codici separati da spazi, es. S# 7P+ 12AX1# 4.9BT- 3R15 5CS OE MU",
+    code_line_events: "eventi da aggiungere",
+    code_line_error: "codice {}: {}",
+    invalid_code: "codice non valido: {}",
+    code_missing_evaluation: "valutazione mancante: {}",
+    code_evaluation_not_available: "valutazione non disponibile: {}",
+    code_missing_player: "numero del giocatore mancante: {}",
+    player_not_on_court: "nessun giocatore con il numero {} in campo",
+    player_not_allowed_for_event: "il giocatore {} non può essere scelto per questo evento",
+    player_cannot_be_replaced: "il giocatore {} non può essere sostituito",
+    invalid_replacement: "il giocatore {} non può entrare",
+    opponent_event: "punto / errore avversario",
+    lineup_change: "cambio libero / palleggiatore",
+    point_to_us: "punto a noi",
+    point_to_them: "punto a loro",
+    eval_key: "valutazione {}",
+    event_option: "muro toccato / tipo di battuta",
+    player_slot: "giocatore {}",
+    scouting_keys: "tasti di scouting",
+    key_already_used: "{} è già usato da: {}",
+    key_types_combination: "{} scrive i codici delle combinazioni d'attacco, le valutazioni non possono usare lettere o cifre",
+    redo: "ripristina",
+    redo_available: "{} da ripristinare",
+    set_over_hint: "premi {} per annullare l'ultimo evento o {} per tornare indietro",
+    live_stats: "statistiche live",
+    reception_positive_perc: "ric. pos%",
+    attack_efficiency_perc: "att. eff%",
     #[cfg(feature = "self-update")]
     updated_to_version: "aggiornato alla versione",
     #[cfg(feature = "self-update")]
@@ -20,6 +20,7 @@ use crate::{
     providers::{
         fs::{
             match_reader::FileSystemMatchReader, match_writer::FileSystemMatchWriter,
+            opponent_reader::FileSystemOpponentReader, opponent_writer::FileSystemOpponentWriter,
             path::get_base_path, queue_reader::FileSystemQueueReader,
             queue_writer::FileSystemQueueWriter, set_reader::FileSystemSetReader,
             set_writer::FileSystemSetWriter, settings_reader::FileSystemSettingsReader,
@@ -103,6 +104,8 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let set_reader = FileSystemSetReader::new(&base_dir);
     let match_reader = FileSystemMatchReader::new(&base_dir, Arc::new(set_reader));
     let match_writer = FileSystemMatchWriter::new(&base_dir);
+    let opponent_reader = FileSystemOpponentReader::new(&base_dir);
+    let opponent_writer = FileSystemOpponentWriter::new(&base_dir);
     let set_writer = FileSystemSetWriter::new(&base_dir);
     let queue_reader = FileSystemQueueReader::new(&queue_path);
     let queue_writer = FileSystemQueueWriter::new(&queue_path);
@@ -120,6 +123,8 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let set_writer_arc = Arc::new(set_writer);
     let match_reader_arc = Arc::new(match_reader);
     let match_writer_arc = Arc::new(match_writer);
+    let opponent_reader_arc = Arc::new(opponent_reader);
+    let opponent_writer_arc = Arc::new(opponent_writer);
     let settings_reader_arc = Arc::new(settings_reader);
     let queue_reader_arc = Arc::new(queue_reader);
     let queue_writer_arc = Arc::new(queue_writer);
@@ -155,6 +160,8 @@ async fn main() -> Result<(), Box<dyn Error>> {
             set_writer_arc,
             match_reader_arc,
             match_writer_arc,
+            opponent_reader_arc,
+            opponent_writer_arc,
             settings_reader_arc,
         ),
     )
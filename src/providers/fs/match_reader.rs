@@ -85,6 +85,7 @@ impl FileSystemMatchReader {
             })?
             .into();
         entry.team = team.clone();
+        entry.players = team.season_players(entry.season);
         entry.sets = self.set_reader.read_all(&entry).await?;
         Ok(entry)
     }
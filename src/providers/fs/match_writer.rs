@@ -9,9 +9,10 @@ use chrono::{DateTime, FixedOffset};
 use serde_json::to_vec_pretty;
 use std::path::{Path, PathBuf};
 use tokio::{
-    fs::{try_exists, File},
+    fs::{try_exists, write, File},
     io::AsyncWriteExt,
 };
+use uuid::Uuid;
 
 pub struct FileSystemMatchWriter(PathBuf);
 
@@ -27,6 +28,8 @@ impl MatchWriter for FileSystemMatchWriter {
         &self,
         team: &TeamEntry,
         opponent: String,
+        opponent_id: Option<Uuid>,
+        season: Option<u16>,
         date: DateTime<FixedOffset>,
         home: bool,
     ) -> Result<MatchEntry, AppError> {
@@ -35,11 +38,16 @@ impl MatchWriter for FileSystemMatchWriter {
         let match_id = format!("{}_{}", date_str, opponent_clean);
         let m = MatchEntry {
             opponent,
+            opponent_id,
+            season,
             date,
             id: match_id.clone(),
             team: team.clone(),
+            players: team.season_players(season),
             home,
             sets: vec![],
+            notes: None,
+            tags: vec![],
         };
         let path = get_match_descriptor_file_path(&self.0, &team.id, &match_id)?;
         if try_exists(&path)
@@ -57,4 +65,12 @@ impl MatchWriter for FileSystemMatchWriter {
             .map_err(|e| AppError::IO(IOError::from(e)))?;
         Ok(m)
     }
+
+    async fn update(&self, m: &MatchEntry) -> Result<(), AppError> {
+        let path = get_match_descriptor_file_path(&self.0, &m.team.id, &m.id)?;
+        let json = to_vec_pretty(m).map_err(|e| AppError::IO(IOError::from(e)))?;
+        write(&path, json)
+            .await
+            .map_err(|e| AppError::IO(IOError::from(e)))
+    }
 }
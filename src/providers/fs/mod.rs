@@ -1,5 +1,7 @@
 pub mod match_reader;
 pub mod match_writer;
+pub mod opponent_reader;
+pub mod opponent_writer;
 pub mod path;
 pub mod queue_reader;
 pub mod queue_writer;
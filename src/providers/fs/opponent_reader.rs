@@ -0,0 +1,37 @@
+use crate::{
+    errors::{AppError, IOError},
+    providers::{fs::path::get_opponents_file_path, opponent_reader::OpponentReader},
+    shapes::opponent::OpponentEntry,
+};
+use async_trait::async_trait;
+use serde_json::from_str;
+use std::path::{Path, PathBuf};
+use tokio::fs::{read_to_string, try_exists};
+
+pub struct FileSystemOpponentReader(PathBuf);
+
+impl FileSystemOpponentReader {
+    pub fn new(base_path: &Path) -> Self {
+        Self(base_path.to_path_buf())
+    }
+}
+
+#[async_trait]
+impl OpponentReader for FileSystemOpponentReader {
+    async fn read_all(&self) -> Result<Vec<OpponentEntry>, AppError> {
+        let path = get_opponents_file_path(&self.0);
+        if !try_exists(&path)
+            .await
+            .map_err(|e| AppError::IO(IOError::from(e)))?
+        {
+            return Ok(vec![]);
+        }
+        let content = read_to_string(&path)
+            .await
+            .map_err(|e| AppError::IO(IOError::from(e)))?;
+        let mut opponents: Vec<OpponentEntry> =
+            from_str(&content).map_err(|e| AppError::IO(IOError::from(e)))?;
+        opponents.sort_by_key(|o| o.name.to_lowercase());
+        Ok(opponents)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    errors::{AppError, IOError},
+    providers::{
+        fs::{opponent_reader::FileSystemOpponentReader, path::get_opponents_file_path},
+        opponent_reader::OpponentReader,
+        opponent_writer::{OpponentInput, OpponentWriter},
+    },
+    shapes::opponent::OpponentEntry,
+};
+use async_trait::async_trait;
+use serde_json::to_vec_pretty;
+use std::path::{Path, PathBuf};
+use tokio::fs::write;
+use uuid::Uuid;
+
+pub struct FileSystemOpponentWriter(PathBuf);
+
+impl FileSystemOpponentWriter {
+    pub fn new(base_path: &Path) -> Self {
+        Self(base_path.to_path_buf())
+    }
+}
+
+#[async_trait]
+impl OpponentWriter for FileSystemOpponentWriter {
+    async fn save(&self, input: OpponentInput) -> Result<OpponentEntry, AppError> {
+        let opponent = match input {
+            OpponentInput::New {
+                name,
+                aliases,
+                classification,
+                gender,
+                notes,
+            } => OpponentEntry {
+                id: Uuid::new_v4(),
+                name,
+                aliases,
+                classification,
+                gender,
+                notes,
+            },
+            OpponentInput::Existing(opponent) => opponent,
+        };
+        let mut opponents = FileSystemOpponentReader::new(&self.0).read_all().await?;
+        if let Some(existing) = opponents.iter_mut().find(|o| o.id == opponent.id) {
+            *existing = opponent.clone();
+        } else {
+            opponents.push(opponent.clone());
+        }
+        let json = to_vec_pretty(&opponents).map_err(|e| AppError::IO(IOError::from(e)))?;
+        write(get_opponents_file_path(&self.0), json)
+            .await
+            .map_err(|e| AppError::IO(IOError::from(e)))?;
+        Ok(opponent)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    constants::MATCH_DESCRIPTOR_FILE_NAME,
+    constants::{MATCH_DESCRIPTOR_FILE_NAME, OPPONENTS_FILE_NAME},
     errors::{AppError, IOError},
     localization::current_labels,
 };
@@ -86,3 +86,7 @@ pub fn get_set_events_file_path(
 pub fn get_config_file_path(base_path: &Path) -> PathBuf {
     base_path.join("config.json")
 }
+
+pub fn get_opponents_file_path(base_path: &Path) -> PathBuf {
+    base_path.join(OPPONENTS_FILE_NAME)
+}
@@ -49,7 +49,11 @@ impl FileSystemSetReader {
         let p = path.to_path_buf();
         spawn_blocking(move || -> Result<Vec<EventEntry>, AppError> {
             let file = File::open(&p).map_err(|e| AppError::IO(IOError::from(e)))?;
-            let mut reader = ReaderBuilder::new().has_headers(false).from_reader(file);
+            let mut reader = ReaderBuilder::new()
+                .has_headers(false)
+                // events scouted before newer columns were added have fewer fields
+                .flexible(true)
+                .from_reader(file);
             let events: Vec<EventEntry> = reader
                 .deserialize()
                 .filter_map(|r: Result<EventEntry, csv::Error>| r.ok())
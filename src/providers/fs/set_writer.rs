@@ -77,6 +77,12 @@ impl SetWriter for FileSystemSetWriter {
         Ok(set)
     }
 
+    async fn update(&self, m: &MatchEntry, set: &SetEntry) -> Result<(), AppError> {
+        let descriptor_path =
+            get_set_descriptor_file_path(&self.0, &m.team.id, &m.id, set.set_number)?;
+        Self::save_set_file(set, &descriptor_path).await
+    }
+
     async fn append_event(
         &self,
         m: &MatchEntry,
@@ -111,6 +117,7 @@ impl SetWriter for FileSystemSetWriter {
         spawn_blocking(move || {
             let mut reader = ReaderBuilder::new()
                 .has_headers(false)
+                .flexible(true)
                 .from_path(&path_clone)
                 .map_err(|e| AppError::IO(IOError::from(e)))?;
             let mut records: Vec<EventEntry> = reader
@@ -135,4 +142,49 @@ impl SetWriter for FileSystemSetWriter {
         .await
         .map_err(|e| AppError::IO(IOError::Msg(format!("tokio join error: {}", e))))?
     }
+
+    async fn replace_last_event(
+        &self,
+        m: &MatchEntry,
+        set_number: u8,
+        event: &EventEntry,
+    ) -> Result<(), AppError> {
+        let path = get_set_events_file_path(&self.0, &m.team.id, &m.id, set_number)?;
+        let event = event.clone();
+        spawn_blocking(move || {
+            let mut reader = ReaderBuilder::new()
+                .has_headers(false)
+                .flexible(true)
+                .from_path(&path)
+                .map_err(|e| AppError::IO(IOError::from(e)))?;
+            let mut records: Vec<EventEntry> = reader
+                .deserialize()
+                .collect::<Result<Vec<EventEntry>, _>>()
+                .map_err(|e| AppError::IO(IOError::from(e)))?;
+            let Some(last) = records.last_mut() else {
+                return Err(AppError::IO(IOError::Msg(
+                    current_labels().no_events_yet.to_string(),
+                )));
+            };
+            *last = event;
+            // written aside and renamed, so that a failure leaves the events as they were
+            let temp_path = path.with_extension("csv.tmp");
+            let file = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(&temp_path)
+                .map_err(|e| AppError::IO(IOError::from(e)))?;
+            let mut writer = WriterBuilder::new().has_headers(false).from_writer(file);
+            for rec in &records {
+                writer
+                    .serialize(rec)
+                    .map_err(|e| AppError::IO(IOError::from(e)))?;
+            }
+            writer.flush().map_err(|e| AppError::IO(IOError::from(e)))?;
+            std::fs::rename(&temp_path, &path).map_err(|e| AppError::IO(IOError::from(e)))
+        })
+        .await
+        .map_err(|e| AppError::IO(IOError::Msg(format!("tokio join error: {}", e))))?
+    }
 }
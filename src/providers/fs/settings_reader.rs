@@ -23,8 +23,9 @@ impl SettingsReader for FileSystemSettingsReader {
         let content = read_to_string(&path)
             .await
             .map_err(|e| AppError::IO(IOError::from(e)))?;
-        let settings =
+        let mut settings =
             from_str::<Settings>(&content).map_err(|e| AppError::IO(IOError::from(e)))?;
+        settings.keybindings = settings.keybindings.with_missing_defaults();
         Ok(settings)
     }
 }
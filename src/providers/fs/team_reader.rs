@@ -25,7 +25,7 @@ impl FileSystemTeamReader {
         let content = read_to_string(path.join(TEAM_DESCRIPTOR_FILE_NAME))
             .await
             .map_err(|e| AppError::IO(IOError::from(e)))?;
-        let team: TeamEntry = from_str(&content).map_err(|e| AppError::IO(IOError::from(e)))?;
+        let mut team: TeamEntry = from_str(&content).map_err(|e| AppError::IO(IOError::from(e)))?;
         let uuid = path
             .file_name()
             .and_then(|os| os.to_str())
@@ -36,6 +36,10 @@ impl FileSystemTeamReader {
                 ))
             })?;
 
+        // teams saved before seasons were introduced get their current roster as first season
+        if !team.seasons.iter().any(|s| s.year == team.year) {
+            team.sync_current_season();
+        }
         Ok(TeamEntry { id: uuid, ..team })
     }
 }
@@ -38,7 +38,7 @@ impl FileSystemTeamWriter {
 #[async_trait]
 impl TeamWriter for FileSystemTeamWriter {
     async fn save(&self, input: TeamInput) -> Result<TeamEntry, AppError> {
-        let team = match input {
+        let mut team = match input {
             TeamInput::New {
                 id,
                 name,
@@ -46,6 +46,9 @@ impl TeamWriter for FileSystemTeamWriter {
                 gender,
                 year,
                 players,
+                seasons,
+                combinations,
+                lineups,
             } => TeamEntry {
                 id: if let Some(id) = id {
                     id
@@ -57,9 +60,13 @@ impl TeamWriter for FileSystemTeamWriter {
                 gender,
                 year,
                 players,
+                seasons,
+                combinations,
+                lineups,
             },
             TeamInput::Existing(team) => team,
         };
+        team.sync_current_season();
 
         Self::save_team_file(&team, &self.0).await?;
         Ok(team)
@@ -71,19 +78,42 @@ impl TeamWriter for FileSystemTeamWriter {
         team: &mut TeamEntry,
     ) -> Result<PlayerEntry, AppError> {
         let player = match input {
-            PlayerInput::New { name, role, number } => PlayerEntry {
+            PlayerInput::New {
+                name,
+                role,
+                number,
+                secondary_role,
+                birth_date,
+                height,
+                dominant_hand,
+                captain,
+                serve_type,
+            } => PlayerEntry {
                 name,
                 role: Some(role),
                 number,
+                secondary_role,
+                birth_date,
+                height,
+                dominant_hand,
+                captain,
+                serve_type,
                 ..Default::default()
             },
             PlayerInput::Existing(existing) => existing,
         };
+        // a team has a single captain
+        if player.captain {
+            for other in team.players.iter_mut().filter(|p| p.id != player.id) {
+                other.captain = false;
+            }
+        }
         if let Some(existing) = team.players.iter_mut().find(|p| p.id == player.id) {
             *existing = player.clone();
         } else {
             team.players.push(player.clone());
         }
+        team.sync_current_season();
         Self::save_team_file(team, &self.0).await?;
         Ok(player)
     }
@@ -4,6 +4,7 @@ use crate::{
 };
 use async_trait::async_trait;
 use chrono::{DateTime, FixedOffset};
+use uuid::Uuid;
 
 #[async_trait]
 pub trait MatchWriter {
@@ -11,7 +12,12 @@ pub trait MatchWriter {
         &self,
         team: &TeamEntry,
         opponent: String,
+        opponent_id: Option<Uuid>,
+        season: Option<u16>,
         date: DateTime<FixedOffset>,
         home: bool,
     ) -> Result<MatchEntry, AppError>;
+
+    /// Saves the match descriptor, leaving its sets untouched.
+    async fn update(&self, m: &MatchEntry) -> Result<(), AppError>;
 }
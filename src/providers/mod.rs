@@ -1,6 +1,8 @@
 pub mod fs;
 pub mod match_reader;
 pub mod match_writer;
+pub mod opponent_reader;
+pub mod opponent_writer;
 pub mod queue_reader;
 pub mod queue_writer;
 pub mod set_reader;
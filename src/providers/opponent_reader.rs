@@ -0,0 +1,7 @@
+use crate::{errors::AppError, shapes::opponent::OpponentEntry};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait OpponentReader {
+    async fn read_all(&self) -> Result<Vec<OpponentEntry>, AppError>;
+}
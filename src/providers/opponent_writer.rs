@@ -0,0 +1,24 @@
+use crate::{
+    errors::AppError,
+    shapes::{
+        enums::{GenderEnum, TeamClassificationEnum},
+        opponent::OpponentEntry,
+    },
+};
+use async_trait::async_trait;
+
+pub enum OpponentInput {
+    New {
+        name: String,
+        aliases: Vec<String>,
+        classification: Option<TeamClassificationEnum>,
+        gender: Option<GenderEnum>,
+        notes: Option<String>,
+    },
+    Existing(OpponentEntry),
+}
+
+#[async_trait]
+pub trait OpponentWriter {
+    async fn save(&self, opponent: OpponentInput) -> Result<OpponentEntry, AppError>;
+}
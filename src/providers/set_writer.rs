@@ -19,6 +19,9 @@ pub trait SetWriter {
         events: Vec<EventEntry>,
     ) -> Result<SetEntry, AppError>;
 
+    /// Saves the set descriptor, leaving its events untouched.
+    async fn update(&self, m: &MatchEntry, set: &SetEntry) -> Result<(), AppError>;
+
     async fn append_event(
         &self,
         m: &MatchEntry,
@@ -31,4 +34,12 @@ pub trait SetWriter {
         m: &MatchEntry,
         set_number: u8,
     ) -> Result<Option<EventEntry>, AppError>;
+
+    /// Writes the event in place of the last one, leaving the file untouched on failure.
+    async fn replace_last_event(
+        &self,
+        m: &MatchEntry,
+        set_number: u8,
+        event: &EventEntry,
+    ) -> Result<(), AppError>;
 }
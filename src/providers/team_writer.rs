@@ -1,12 +1,13 @@
 use crate::{
     errors::AppError,
     shapes::{
-        enums::{GenderEnum, RoleEnum, TeamClassificationEnum},
+        enums::{GenderEnum, HandEnum, RoleEnum, ServeTypeEnum, TeamClassificationEnum},
         player::PlayerEntry,
-        team::TeamEntry,
+        team::{AttackCombinationEntry, LineupTemplateEntry, SeasonEntry, TeamEntry},
     },
 };
 use async_trait::async_trait;
+use chrono::NaiveDate;
 use uuid::Uuid;
 
 pub enum TeamInput {
@@ -17,6 +18,9 @@ pub enum TeamInput {
         classification: Option<TeamClassificationEnum>,
         gender: Option<GenderEnum>,
         players: Vec<PlayerEntry>,
+        seasons: Vec<SeasonEntry>,
+        combinations: Vec<AttackCombinationEntry>,
+        lineups: Vec<LineupTemplateEntry>,
     },
     Existing(TeamEntry),
 }
@@ -26,6 +30,12 @@ pub enum PlayerInput {
         name: String,
         role: RoleEnum,
         number: u8,
+        secondary_role: Option<RoleEnum>,
+        birth_date: Option<NaiveDate>,
+        height: Option<u16>,
+        dominant_hand: Option<HandEnum>,
+        captain: bool,
+        serve_type: Option<ServeTypeEnum>,
     },
     Existing(PlayerEntry),
 }
@@ -0,0 +1,41 @@
+use crate::reporting::typst_content::TypstContent;
+
+/// A horizontal bar whose width is a percentage of the enclosing cell.
+pub struct Bar {
+    percentage: f64,
+    height: u8,
+    fill: Option<&'static str>,
+}
+
+impl Bar {
+    pub fn new(percentage: f64) -> Self {
+        Self {
+            percentage: percentage.clamp(0.0, 100.0),
+            height: 6,
+            fill: None,
+        }
+    }
+
+    pub fn height(mut self, h: u8) -> Self {
+        self.height = h;
+        self
+    }
+
+    pub fn fill(mut self, f: &'static str) -> Self {
+        self.fill = Some(f);
+        self
+    }
+}
+
+impl TypstContent for Bar {
+    fn render(&self) -> String {
+        let mut args = vec![
+            format!("width: {:.1}%", self.percentage),
+            format!("height: {}pt", self.height),
+        ];
+        if let Some(f) = self.fill {
+            args.push(format!("fill: rgb(\"{}\")", f));
+        }
+        format!("rect({})", args.join(", "))
+    }
+}
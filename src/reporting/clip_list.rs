@@ -0,0 +1,107 @@
+use crate::{
+    constants::EDL_FRAMES_PER_SECOND,
+    shapes::{enums::ClipListFormatEnum, video::ClipEntry},
+};
+
+/// Renders the clips in a format video editors and analysis tools can import.
+pub fn render_clip_list(title: &str, clips: &[ClipEntry], format: ClipListFormatEnum) -> String {
+    match format {
+        ClipListFormatEnum::Edl => render_edl(title, clips),
+        ClipListFormatEnum::FfmpegConcat => render_ffmpeg_concat(clips),
+        ClipListFormatEnum::Xml => render_xml_timeline(clips),
+    }
+}
+
+/// `hh:mm:ss:ff` timecode, non drop frame.
+pub fn timecode(seconds: f64) -> String {
+    let frames = (seconds.max(0.0) * EDL_FRAMES_PER_SECOND as f64).round() as u64;
+    let fps = EDL_FRAMES_PER_SECOND as u64;
+    let total_seconds = frames / fps;
+    format!(
+        "{:02}:{:02}:{:02}:{:02}",
+        total_seconds / 3600,
+        total_seconds % 3600 / 60,
+        total_seconds % 60,
+        frames % fps
+    )
+}
+
+// CMX 3600 edit decision list, clips placed one after the other on the record side
+fn render_edl(title: &str, clips: &[ClipEntry]) -> String {
+    let mut content = format!("TITLE: {}\nFCM: NON-DROP FRAME\n", title);
+    let mut record = 0.0;
+    for (i, clip) in clips.iter().enumerate() {
+        let file_name = clip
+            .file
+            .file_name()
+            .map(|f| f.to_string_lossy().to_string())
+            .unwrap_or_default();
+        content.push_str(&format!(
+            "\n{:03}  AX       V     C        {} {} {} {}\n* FROM CLIP NAME: {}\n* COMMENT: {}\n",
+            i + 1,
+            timecode(clip.start),
+            timecode(clip.end),
+            timecode(record),
+            timecode(record + clip.duration()),
+            file_name,
+            clip.label
+        ));
+        record += clip.duration();
+    }
+    content
+}
+
+// script for ffmpeg's concat demuxer: `ffmpeg -f concat -safe 0 -i clips.ffconcat out.mp4`
+fn render_ffmpeg_concat(clips: &[ClipEntry]) -> String {
+    let mut content = String::from("ffconcat version 1.0\n");
+    for clip in clips {
+        content.push_str(&format!(
+            "\n# {}\nfile '{}'\ninpoint {:.3}\noutpoint {:.3}\n",
+            clip.label,
+            clip.file.display().to_string().replace('\'', "'\\''"),
+            clip.start,
+            clip.end
+        ));
+    }
+    content
+}
+
+// timeline of instances, as exchanged by Sportscode and Hudl
+fn render_xml_timeline(clips: &[ClipEntry]) -> String {
+    let instances = clips
+        .iter()
+        .enumerate()
+        .map(|(i, clip)| {
+            format!(
+                r#"    <instance>
+      <ID>{}</ID>
+      <start>{:.3}</start>
+      <end>{:.3}</end>
+      <code>{}</code>
+      <label>
+        <group>video</group>
+        <text>{}</text>
+      </label>
+    </instance>"#,
+                i + 1,
+                clip.start,
+                clip.end,
+                escape_xml(&clip.label),
+                escape_xml(&clip.file.display().to_string())
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<file>\n  <ALL_INSTANCES>\n{}\n  </ALL_INSTANCES>\n</file>\n",
+        instances
+    )
+}
+
+fn escape_xml(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
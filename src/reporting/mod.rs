@@ -1,5 +1,7 @@
 pub mod align;
+pub mod bar;
 pub mod circle;
+pub mod clip_list;
 pub mod court;
 pub mod pdf;
 pub mod stack;
@@ -1,6 +1,7 @@
 use crate::errors::AppError;
 use crate::localization::{current_labels, Labels};
 use crate::reporting::align::Align;
+use crate::reporting::bar::Bar;
 use crate::reporting::circle::Circle;
 use crate::reporting::court::render_court;
 use crate::reporting::stack::{Stack, StackDirection};
@@ -8,12 +9,22 @@ use crate::reporting::table_row::{Cell, Row};
 use crate::reporting::text::Text;
 use crate::reporting::typst_content::TypstContent;
 use crate::reporting::util::escape_text;
-use crate::shapes::enums::{ErrorTypeEnum, EvalEnum, EventTypeEnum, PhaseEnum, TeamSideEnum};
+use crate::shapes::enums::{
+    BlockCompositionEnum, ErrorTypeEnum, EvalEnum, EventTypeEnum, FriendlyName, PhaseEnum,
+    RallyLengthEnum, RoleEnum, ServeTypeEnum, TeamSideEnum, ZoneEnum,
+};
+use crate::shapes::opponent::OpponentEntry;
 use crate::shapes::player::PlayerEntry;
 use crate::shapes::r#match::{MatchEntry, MatchStatus};
 use crate::shapes::set::SetEntry;
-use crate::shapes::snapshot::Snapshot;
+use crate::shapes::snapshot::{EventEntry, Snapshot};
 use crate::shapes::stats::{Metric, Stats};
+use crate::shapes::tags::format_tags;
+use crate::shapes::team::{AttackCombinationEntry, TeamEntry};
+use crate::shapes::timing::{format_seconds, SetTimingEntry};
+use crate::util::sanitize_filename;
+use chrono::{DurationRound, Local, TimeDelta};
+use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -31,7 +42,6 @@ pub const ALTERNATE_COLOR: &str = "#efefef";
 
 pub fn open_match_pdf(m: &MatchEntry) -> Result<(), AppError> {
     let mut content = String::new();
-    let created_with = current_labels().created_with;
     let match_status = m.get_status()?;
     let mut aggregated_stats = Stats::new();
     let mut players: HashMap<Uuid, HashMap<u8, bool>> = HashMap::new();
@@ -59,9 +69,227 @@ pub fn open_match_pdf(m: &MatchEntry) -> Result<(), AppError> {
         .collect();
     let mut players: Vec<_> = players
         .iter()
-        .filter_map(|(&id, set_map)| m.team.find_player(id).map(|p| (p, set_map.clone())))
+        .filter_map(|(&id, set_map)| m.find_player(id).map(|p| (p, set_map.clone())))
+        .collect();
+    players.sort_by_key(|(p, _)| p.number);
+    content.push_str(&render_preamble());
+    content.push_str(&render_header(m, &match_status));
+    content.push_str(&render_match_overview(m, &sets));
+    content.push_str(&render_timing_tables(&sets));
+    content.push_str(&render_score_adjustments_table(m));
+    content.push_str(&render_notes_table(m));
+    content.push_str(&render_players_stats_tables(
+        Some(m),
+        &players,
+        &aggregated_stats,
+    ));
+    content.push_str(&render_analysis_tables(
+        &players,
+        &m.team.combinations,
+        &aggregated_stats,
+    ));
+    content.push_str(&render_sets_stats_table(&sets));
+    content.push_str(&render_bottom_stats(&aggregated_stats));
+    compile_and_open(&m.id, content);
+    Ok(())
+}
+
+/// Combined report of all the matches played against an opponent.
+pub fn open_head_to_head_pdf(
+    team: &TeamEntry,
+    opponent: &OpponentEntry,
+    matches: &[MatchEntry],
+) -> Result<(), AppError> {
+    let mut content = String::new();
+    let mut aggregated_stats = Stats::new();
+    // players as they were in the last match they played, with the sets of all the matches
+    // numbered one after the other
+    let mut players: HashMap<Uuid, (Option<&PlayerEntry>, HashMap<u8, bool>)> = HashMap::new();
+    let mut played_sets: u8 = 0;
+    let mut results = vec![];
+    let mut by_date: Vec<&MatchEntry> = matches.iter().collect();
+    by_date.sort_by_key(|m| m.date);
+    for m in by_date {
+        let status = m.get_status()?;
+        let mut set_scores = vec![];
+        for (set, snapshot) in m
+            .sets
+            .iter()
+            .filter_map(|set| set.compute_snapshot().ok().map(|(s, _)| (set, s)))
+        {
+            played_sets = played_sets.saturating_add(1);
+            aggregated_stats.merge(&snapshot.stats);
+            for &p in snapshot.current_lineup.get_involved_players().iter() {
+                let entered_late = if set.libero == p {
+                    false
+                } else {
+                    snapshot.current_lineup.was_player_already_used(&p)
+                };
+                let (player, set_map) = players.entry(p).or_default();
+                if let Some(found) = m.find_player(p) {
+                    *player = Some(found);
+                }
+                set_map.insert(played_sets, entered_late);
+            }
+            set_scores.push(format!("{}-{}", snapshot.score_us, snapshot.score_them));
+        }
+        results.push((m, status, set_scores));
+    }
+    let mut players: Vec<_> = players
+        .into_values()
+        .filter_map(|(player, set_map)| player.map(|p| (p, set_map)))
         .collect();
     players.sort_by_key(|(p, _)| p.number);
+    content.push_str(&render_preamble());
+    content.push_str(&render_head_to_head_results(team, opponent, &results));
+    content.push_str(&render_players_stats_tables(
+        None,
+        &players,
+        &aggregated_stats,
+    ));
+    content.push_str(&render_analysis_tables(
+        &players,
+        &team.combinations,
+        &aggregated_stats,
+    ));
+    content.push_str(&render_bottom_stats(&aggregated_stats));
+    compile_and_open(&sanitize_filename(&opponent.name), content);
+    Ok(())
+}
+
+fn render_analysis_tables(
+    players: &[(&PlayerEntry, HashMap<u8, bool>)],
+    combinations: &[AttackCombinationEntry],
+    aggregated_stats: &Stats,
+) -> String {
+    let mut content = String::new();
+    content.push_str(&render_rotations_stats_table(aggregated_stats));
+    content.push_str(&render_rotation_analysis_table(aggregated_stats));
+    content.push_str(&render_rally_length_table(aggregated_stats));
+    content.push_str(&render_reception_analysis_table(players, aggregated_stats));
+    content.push_str(&render_setter_distribution_table(aggregated_stats));
+    content.push_str(&render_attack_combination_table(
+        combinations,
+        aggregated_stats,
+    ));
+    content.push_str(&render_serve_type_table(players, aggregated_stats));
+    content.push_str(&render_set_and_free_ball_table(players, aggregated_stats));
+    content.push_str(&render_block_table(players, aggregated_stats));
+    content.push_str(&render_plus_minus_table(
+        players,
+        current_labels().lineup_combinations,
+        current_labels().combination,
+        aggregated_stats
+            .combinations_won_lost(None)
+            .into_iter()
+            .map(|(players, won, lost)| (players.to_vec(), won, lost))
+            .collect(),
+    ));
+    content.push_str(&render_plus_minus_table(
+        players,
+        current_labels().pairs,
+        current_labels().pair,
+        aggregated_stats
+            .pairs_won_lost(None)
+            .into_iter()
+            .map(|(players, won, lost)| (players.to_vec(), won, lost))
+            .collect(),
+    ));
+    content.push_str(&render_global_stats_table(aggregated_stats));
+    content
+}
+
+fn render_head_to_head_results(
+    team: &TeamEntry,
+    opponent: &OpponentEntry,
+    results: &[(&MatchEntry, MatchStatus, Vec<String>)],
+) -> String {
+    const TITLE_FONT_SIZE: u8 = 14;
+    let labels = current_labels();
+    let won = results
+        .iter()
+        .filter(|(_, status, _)| status.match_finished && status.us_wins > status.them_wins)
+        .count();
+    let lost = results
+        .iter()
+        .filter(|(_, status, _)| status.match_finished && status.them_wins > status.us_wins)
+        .count();
+    let title = format!(
+        "{} - {} ({} {} / {} {})",
+        team.name, opponent.name, won, labels.won, lost, labels.lost
+    );
+    let title_row = Row::new(vec![Cell::new(
+        Text::new(escape_text(&title))
+            .size(TITLE_FONT_SIZE)
+            .fill(WHITE)
+            .bold(),
+    )
+    .align(Align::Center)
+    .fill(GRAY)
+    .colspan(5)])
+    .render();
+    let header_cell = |text: &str| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+    };
+    let header_row = Row::new(vec![
+        header_cell(labels.date),
+        header_cell(labels.venue),
+        header_cell(labels.finals),
+        header_cell(labels.partials),
+        header_cell(""),
+    ])
+    .render();
+    let rows = results
+        .iter()
+        .enumerate()
+        .map(|(i, (m, status, set_scores))| {
+            let bg_color = if i % 2 == 0 { WHITE } else { ALTERNATE_COLOR };
+            let texts = vec![
+                m.date.format("%a %d %b %Y").to_string(),
+                if m.home { labels.home } else { labels.away }.to_string(),
+                format!("{}-{}", status.us_wins, status.them_wins),
+                set_scores.join(" | "),
+                if status.match_finished {
+                    ""
+                } else {
+                    labels.in_progress
+                }
+                .to_string(),
+            ];
+            let cells = texts
+                .into_iter()
+                .map(|text| {
+                    Cell::new(Text::new(escape_text(&text)))
+                        .align(Align::Center)
+                        .fill(bg_color)
+                })
+                .collect();
+            Row::new(cells).render()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: (2fr, 1fr, 1fr, 4fr, 1fr),
+    inset: 3pt,
+    stroke: none,
+    {title_row}
+    {header_row}
+    {rows}
+  )
+)
+"#
+    )
+}
+
+fn render_preamble() -> String {
+    let mut content = String::new();
+    let created_with = current_labels().created_with;
     content.push_str("#import table: cell, header\n");
     content.push_str(&format!(
         r#"
@@ -99,16 +327,13 @@ pub fn open_match_pdf(m: &MatchEntry) -> Result<(), AppError> {
 )
 "#
     ));
-    content.push_str(&render_header(m, &match_status));
-    content.push_str(&render_match_overview(m, &sets));
-    content.push_str(&render_players_stats_table(m, &players, &aggregated_stats));
-    content.push_str(&render_rotations_stats_table(&aggregated_stats));
-    content.push_str(&render_global_stats_table(&aggregated_stats));
-    content.push_str(&render_sets_stats_table(&sets));
-    content.push_str(&render_bottom_stats(&aggregated_stats));
+    content
+}
+
+fn compile_and_open(file_stem: &str, content: String) {
     let mut path: PathBuf = env::temp_dir();
     let uid = Uuid::new_v4().to_string();
-    path.push(format!("{}_{}.pdf", m.id, uid));
+    path.push(format!("{}_{}.pdf", file_stem, uid));
     let world = TypstWrapperWorld::new("../".to_owned(), content);
     let document = typst::compile(&world)
         .output
@@ -116,7 +341,6 @@ pub fn open_match_pdf(m: &MatchEntry) -> Result<(), AppError> {
     let pdf = typst_pdf::pdf(&document, &PdfOptions::default()).expect("error exporting PDF");
     fs::write(&path, pdf).expect("error writing PDF");
     open_with_system_viewer(&path);
-    Ok(())
 }
 
 fn render_bottom_stats(aggregated_stats: &Stats) -> String {
@@ -244,16 +468,19 @@ fn render_header(m: &MatchEntry, match_status: &MatchStatus) -> String {
 }
 
 fn snapshot_partials(snapshot: &Snapshot, m: &MatchEntry) -> String {
+    let score = |(us, them): &(u8, u8)| {
+        if m.home {
+            format!("{}-{}", us, them)
+        } else {
+            format!("{}-{}", them, us)
+        }
+    };
+    // the rallies before a late start are missing from all the stats
     snapshot
-        .partials
+        .scouted_from
         .iter()
-        .map(|(us, them)| {
-            if m.home {
-                format!("{}-{}", us, them)
-            } else {
-                format!("{}-{}", them, us)
-            }
-        })
+        .map(|from| format!("{} {}", current_labels().scouted_from, score(from)))
+        .chain(snapshot.partials.iter().map(score))
         .collect::<Vec<_>>()
         .join(" | ")
 }
@@ -264,8 +491,8 @@ fn snapshot_substitutions(snapshot: &Snapshot, m: &MatchEntry) -> String {
         .get_substitutions()
         .iter()
         .map(|s| {
-            let replaced = m.team.players.iter().find(|p| p.id == s.replaced);
-            let replacement = m.team.players.iter().find(|p| p.id == s.replacement);
+            let replaced = m.find_player(s.replaced);
+            let replacement = m.find_player(s.replacement);
             if let (Some(replaced), Some(replacement)) = (replaced, replacement) {
                 format!("{}<{}", replaced.number, replacement.number)
             } else {
@@ -378,6 +605,465 @@ fn render_match_overview(m: &MatchEntry, sets: &Vec<(&SetEntry, Snapshot)>) -> S
     )
 }
 
+fn render_timing_tables(sets: &Vec<(&SetEntry, Snapshot)>) -> String {
+    let timings: Vec<SetTimingEntry> = sets
+        .iter()
+        .filter_map(|(set, snapshot)| snapshot.get_timing(set.set_number))
+        .collect();
+    if timings.is_empty() {
+        return String::new();
+    }
+    let mut content = render_timing_table(&timings);
+    content.push_str(&render_time_of_day_table(sets));
+    content
+}
+
+fn render_timing_table(timings: &[SetTimingEntry]) -> String {
+    let labels = current_labels();
+    let clock =
+        |t: &chrono::DateTime<chrono::Utc>| t.with_timezone(&Local).format("%H:%M").to_string();
+    let fmt_avg = |v: Option<f64>| v.map_or("-".to_string(), |v| format!("{:.0}s", v));
+    let cells = |texts: Vec<String>, bg_color: &'static str| -> Vec<Cell> {
+        texts
+            .into_iter()
+            .map(|text| {
+                Cell::new(Text::new(text))
+                    .align(Align::Center)
+                    .fill(bg_color)
+            })
+            .collect()
+    };
+    let mut rows: Vec<String> = timings
+        .iter()
+        .enumerate()
+        .map(|(i, t)| {
+            let bg_color = if i % 2 == 0 { WHITE } else { ALTERNATE_COLOR };
+            let long_breaks = t
+                .long_breaks
+                .iter()
+                .map(|b| {
+                    format!(
+                        "{} ({}-{}, {})",
+                        clock(&b.start),
+                        b.score.0,
+                        b.score.1,
+                        format_seconds(b.seconds)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            Row::new(cells(
+                vec![
+                    t.set_number.to_string(),
+                    clock(&t.start),
+                    clock(&t.end),
+                    format_seconds(t.duration_seconds),
+                    format_seconds(t.playing_seconds),
+                    fmt_avg(t.avg_between_rallies_seconds),
+                    if long_breaks.is_empty() {
+                        "-".to_string()
+                    } else {
+                        long_breaks
+                    },
+                ],
+                bg_color,
+            ))
+            .render()
+        })
+        .collect();
+    // the whole match, including the intervals between the sets
+    let (first, last) = (&timings[0], &timings[timings.len() - 1]);
+    let averages: Vec<f64> = timings
+        .iter()
+        .filter_map(|t| t.avg_between_rallies_seconds)
+        .collect();
+    let avg = (!averages.is_empty()).then(|| averages.iter().sum::<f64>() / averages.len() as f64);
+    rows.push(
+        Row::new(cells(
+            vec![
+                labels.match_word.to_string(),
+                clock(&first.start),
+                clock(&last.end),
+                format_seconds((last.end - first.start).num_seconds()),
+                format_seconds(timings.iter().map(|t| t.playing_seconds).sum()),
+                fmt_avg(avg),
+                timings
+                    .iter()
+                    .map(|t| t.long_breaks.len())
+                    .sum::<usize>()
+                    .to_string(),
+            ],
+            LIGHT_GRAY,
+        ))
+        .render(),
+    );
+    let rows = rows.join("\n");
+    let header_cell = |text: &str| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+    };
+    let title_row = Row::new(vec![Cell::new(
+        Text::new(escape_text(labels.timing)).bold(),
+    )
+    .align(Align::Left)
+    .fill(LIGHT_GRAY)
+    .colspan(7)])
+    .render();
+    let header_row = Row::new(vec![
+        header_cell("set"),
+        header_cell(labels.start_time),
+        header_cell(labels.end_time),
+        header_cell(labels.duration),
+        header_cell(labels.playing_time),
+        header_cell(labels.between_rallies),
+        header_cell(labels.long_breaks),
+    ])
+    .render();
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: (1fr, 1fr, 1fr, 1fr, 1fr, 2fr, 4fr),
+    inset: 3pt,
+    stroke: none,
+    {title_row}
+    {header_row}
+    {rows}
+  )
+)
+"#
+    )
+}
+
+/// Events of a set matching the filter, with the score (us, them) right after each of them.
+fn events_with_score<F>(set: &SetEntry, filter: F) -> Vec<(&EventEntry, (u8, u8))>
+where
+    F: Fn(&EventEntry) -> bool,
+{
+    let mut entries = vec![];
+    if !set.events.iter().any(&filter) {
+        return entries;
+    }
+    // replay the set to know the score when each event was scouted
+    let Ok(mut snapshot) = Snapshot::new(set) else {
+        return entries;
+    };
+    let mut available_options: Vec<EventTypeEnum> = vec![];
+    for event in &set.events {
+        let Ok(options) = snapshot.add_event(event, &available_options) else {
+            break;
+        };
+        available_options = options;
+        if filter(event) {
+            entries.push((event, (snapshot.score_us, snapshot.score_them)));
+        }
+    }
+    entries
+}
+
+/// Points awarded by hand to align the score with the official scoresheet, so that
+/// the differences from the scouted rallies are visible.
+fn render_score_adjustments_table(m: &MatchEntry) -> String {
+    let labels = current_labels();
+    let rows: Vec<String> = m
+        .sets
+        .iter()
+        .flat_map(|set| {
+            events_with_score(set, |e| e.adjusted_side().is_some())
+                .into_iter()
+                .map(move |(event, score)| (set.set_number, event, score))
+        })
+        .enumerate()
+        .map(|(i, (set_number, event, score))| {
+            let bg_color = if i % 2 == 0 { WHITE } else { ALTERNATE_COLOR };
+            let team = match event.adjusted_side() {
+                Some(TeamSideEnum::Us) => m.team.name.as_str(),
+                _ => m.opponent.as_str(),
+            };
+            Row::new(
+                [
+                    set_number.to_string(),
+                    format!("{}-{}", score.0, score.1),
+                    team.to_string(),
+                    event
+                        .timestamp
+                        .with_timezone(&Local)
+                        .format("%H:%M")
+                        .to_string(),
+                    event.notes.clone().unwrap_or_default(),
+                ]
+                .into_iter()
+                .map(|text| {
+                    Cell::new(Text::new(escape_text(&text)))
+                        .align(Align::Center)
+                        .fill(bg_color)
+                })
+                .collect(),
+            )
+            .render()
+        })
+        .collect();
+    if rows.is_empty() {
+        return String::new();
+    }
+    let rows = rows.join("\n");
+    let header_cell = |text: &str| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+    };
+    let title_row = Row::new(vec![Cell::new(
+        Text::new(escape_text(labels.score_adjustments)).bold(),
+    )
+    .align(Align::Left)
+    .fill(LIGHT_GRAY)
+    .colspan(5)])
+    .render();
+    let header_row = Row::new(vec![
+        header_cell("set"),
+        header_cell(labels.score),
+        header_cell(labels.point_awarded_to),
+        header_cell(labels.time),
+        header_cell(labels.notes),
+    ])
+    .render();
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: (1fr, 1fr, 3fr, 1fr, 6fr),
+    inset: 3pt,
+    stroke: none,
+    {title_row}
+    {header_row}
+    {rows}
+  )
+)
+"#
+    )
+}
+
+/// Notes and tags of the match, of its sets and of the single events, in scouting order.
+fn render_notes_table(m: &MatchEntry) -> String {
+    let labels = current_labels();
+    let notes_of = |notes: &Option<String>| notes.clone().unwrap_or_default();
+    // set, score, player, event, notes, tags
+    let mut entries: Vec<[String; 6]> = vec![];
+    if m.notes.is_some() || !m.tags.is_empty() {
+        entries.push([
+            labels.match_word.to_string(),
+            "-".to_string(),
+            "-".to_string(),
+            "-".to_string(),
+            notes_of(&m.notes),
+            format_tags(&m.tags),
+        ]);
+    }
+    for set in &m.sets {
+        if set.notes.is_some() || !set.tags.is_empty() {
+            entries.push([
+                set.set_number.to_string(),
+                "-".to_string(),
+                "-".to_string(),
+                "-".to_string(),
+                notes_of(&set.notes),
+                format_tags(&set.tags),
+            ]);
+        }
+        for (event, score) in events_with_score(set, |e| e.notes.is_some() || !e.tags.is_empty()) {
+            let player = event
+                .player
+                .and_then(|id| m.find_player(id))
+                .map_or("-".to_string(), |p| format!("{} {}", p.number, p.name));
+            entries.push([
+                set.set_number.to_string(),
+                format!("{}-{}", score.0, score.1),
+                player,
+                event.event_type.friendly_name(labels).to_string(),
+                notes_of(&event.notes),
+                format_tags(&event.tags),
+            ]);
+        }
+    }
+    if entries.is_empty() {
+        return String::new();
+    }
+    let rows = entries
+        .into_iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let bg_color = if i % 2 == 0 { WHITE } else { ALTERNATE_COLOR };
+            Row::new(
+                entry
+                    .into_iter()
+                    .enumerate()
+                    .map(|(col, text)| {
+                        // free text is left aligned, the rest is centered
+                        let align = if col >= 4 { Align::Left } else { Align::Center };
+                        Cell::new(Text::new(escape_text(&text)))
+                            .align(align)
+                            .fill(bg_color)
+                    })
+                    .collect(),
+            )
+            .render()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let header_cell = |text: &str| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+    };
+    let title_row = Row::new(vec![Cell::new(Text::new(escape_text(labels.notes)).bold())
+        .align(Align::Left)
+        .fill(LIGHT_GRAY)
+        .colspan(6)])
+    .render();
+    let header_row = Row::new(vec![
+        header_cell("set"),
+        header_cell(labels.score),
+        header_cell(labels.player),
+        header_cell(labels.event_type),
+        header_cell(labels.notes),
+        header_cell(labels.tags),
+    ])
+    .render();
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: (1fr, 1fr, 3fr, 2fr, 8fr, 3fr),
+    inset: 3pt,
+    stroke: none,
+    {title_row}
+    {header_row}
+    {rows}
+  )
+)
+"#
+    )
+}
+
+/// Rallies and points grouped by the time of day they were played, in slots of a few minutes.
+fn render_time_of_day_table(sets: &Vec<(&SetEntry, Snapshot)>) -> String {
+    const SLOT_MINUTES: i64 = 5;
+    let labels = current_labels();
+    // slot start => (sets, rallies, won, lost, playing seconds)
+    let mut slots: Vec<(chrono::DateTime<Local>, Vec<u8>, u32, u32, u32, i64)> = vec![];
+    for (set, snapshot) in sets {
+        for rally in snapshot.rallies.iter() {
+            let Some(start) = rally
+                .events
+                .first()
+                .map(|e| e.timestamp.with_timezone(&Local))
+            else {
+                continue;
+            };
+            let Ok(slot) = start.duration_trunc(TimeDelta::minutes(SLOT_MINUTES)) else {
+                continue;
+            };
+            let index = match slots.iter().position(|s| s.0 == slot) {
+                Some(index) => index,
+                None => {
+                    slots.push((slot, vec![], 0, 0, 0, 0));
+                    slots.len() - 1
+                }
+            };
+            let entry = &mut slots[index];
+            if !entry.1.contains(&set.set_number) {
+                entry.1.push(set.set_number);
+            }
+            entry.2 += 1;
+            match rally.winner {
+                Some(TeamSideEnum::Us) => entry.3 += 1,
+                Some(TeamSideEnum::Them) => entry.4 += 1,
+                None => {}
+            }
+            entry.5 += rally.duration().map_or(0, |d| d.num_seconds());
+        }
+    }
+    if slots.is_empty() {
+        return String::new();
+    }
+    slots.sort_by_key(|s| s.0);
+    let slot_seconds = (SLOT_MINUTES * 60) as f64;
+    let rows = slots
+        .iter()
+        .enumerate()
+        .map(|(i, (slot, set_numbers, rallies, won, lost, playing))| {
+            let bg_color = if i % 2 == 0 { WHITE } else { ALTERNATE_COLOR };
+            let text_cell = |text: String| {
+                Cell::new(Text::new(text))
+                    .align(Align::Center)
+                    .fill(bg_color)
+            };
+            Row::new(vec![
+                text_cell(slot.format("%H:%M").to_string()),
+                text_cell(
+                    set_numbers
+                        .iter()
+                        .map(|n| n.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+                text_cell(rallies.to_string()),
+                text_cell(format!("{}-{}", won, lost)),
+                text_cell(format_seconds(*playing)),
+                Cell::new(
+                    Bar::new(100.0 * *playing as f64 / slot_seconds)
+                        .height(8)
+                        .fill(GRAY),
+                )
+                .align(Align::Left)
+                .fill(bg_color),
+            ])
+            .render()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let header_cell = |text: &str| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+    };
+    let title_row = Row::new(vec![Cell::new(
+        Text::new(escape_text(labels.time_of_day)).bold(),
+    )
+    .align(Align::Left)
+    .fill(LIGHT_GRAY)
+    .colspan(6)])
+    .render();
+    let header_row = Row::new(vec![
+        header_cell(labels.time_of_day),
+        header_cell("set"),
+        header_cell(labels.rallies),
+        header_cell(labels.won_lost),
+        header_cell(labels.playing_time),
+        header_cell(""),
+    ])
+    .render();
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: (1fr, 1fr, 1fr, 1fr, 1fr, 5fr),
+    inset: 3pt,
+    stroke: none,
+    {title_row}
+    {header_row}
+    {rows}
+  )
+)
+"#
+    )
+}
+
 fn set_player_initial_position(
     m: &MatchEntry,
     set_number: u8,
@@ -579,7 +1265,7 @@ impl StatsRow {
     }
 }
 
-fn create_stats_headers(labels: &Labels, include_rotation_headers: bool) -> (String, String) {
+fn create_stats_headers(labels: &Labels) -> (String, String) {
     let header_cell = |text: &str, colspan: u8| {
         Cell::new(Text::new(escape_text(text)).bold())
             .align(Align::Center)
@@ -600,37 +1286,17 @@ fn create_stats_headers(labels: &Labels, include_rotation_headers: bool) -> (Str
         }
         cell
     };
-    let mut header_cells = vec![];
-    let mut sub_header_cells = vec![];
-    if include_rotation_headers {
-        header_cells.push(
-            Cell::new(Text::new(escape_text(labels.rotation)).bold())
-                .align(Align::Center)
-                .fill(LIGHT_GRAY),
-        );
-        header_cells.push(Cell::new(Text::new("")).fill(LIGHT_GRAY).colspan(2));
-
-        sub_header_cells.push(Cell::new(Text::new("")).fill(LIGHT_GRAY));
-        sub_header_cells.push(sub_header(labels.breaks_per_point, false, true));
-        sub_header_cells.push(sub_header(labels.sideouts_per_point, true, true));
-    } else {
-        header_cells.push(Cell::new(Text::new("")).fill(LIGHT_GRAY));
-        header_cells.push(
-            Cell::new(Text::new(escape_text(labels.player)).bold())
-                .align(Align::Left)
-                .fill(LIGHT_GRAY),
-        );
-        header_cells.push(header_cell("set", 5));
-        sub_header_cells.extend([
-            Cell::new(Text::new("")).fill(LIGHT_GRAY),
-            Cell::new(Text::new("")).fill(LIGHT_GRAY),
-            sub_header("1", false, true),
-            sub_header("2", false, true),
-            sub_header("3", false, true),
-            sub_header("4", false, true),
-            sub_header("5", true, true),
-        ]);
-    }
+    let mut header_cells = vec![
+        Cell::new(Text::new(escape_text(labels.rotation)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY),
+        Cell::new(Text::new("")).fill(LIGHT_GRAY).colspan(2),
+    ];
+    let mut sub_header_cells = vec![
+        Cell::new(Text::new("")).fill(LIGHT_GRAY),
+        sub_header(labels.breaks_per_point, false, true),
+        sub_header(labels.sideouts_per_point, true, true),
+    ];
     header_cells.extend([
         header_cell(labels.points, 3),
         header_cell(labels.serve, 3),
@@ -666,79 +1332,321 @@ fn create_stats_headers(labels: &Labels, include_rotation_headers: bool) -> (Str
     )
 }
 
-fn render_players_stats_table(
-    m: &MatchEntry,
+/// A group of columns of the players tables; each role only shows the groups that are
+/// relevant to it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RoleSection {
+    Serve,
+    Reception,
+    Attack,
+    Block,
+    Dig,
+    Distribution,
+    Faults,
+}
+
+impl RoleSection {
+    fn for_role(role: Option<RoleEnum>) -> &'static [RoleSection] {
+        use RoleSection::*;
+        match role {
+            Some(RoleEnum::Setter) => &[Serve, Distribution, Attack, Block, Faults],
+            Some(RoleEnum::OutsideHitter) => &[Serve, Reception, Attack, Block, Faults],
+            Some(RoleEnum::OppositeHitter) => &[Serve, Attack, Block, Faults],
+            Some(RoleEnum::MiddleBlocker) => &[Serve, Block, Attack, Faults],
+            Some(RoleEnum::Libero) => &[Reception, Dig, Faults],
+            None => &[Serve, Reception, Attack, Block, Faults],
+        }
+    }
+
+    fn title(&self, labels: &Labels) -> &'static str {
+        use RoleSection::*;
+        match self {
+            Serve => labels.serve,
+            Reception => labels.reception,
+            Attack => labels.attack,
+            Block => labels.blk,
+            Dig => labels.dig,
+            Distribution => labels.distribution,
+            Faults => labels.flt,
+        }
+    }
+
+    fn sub_headers(&self, labels: &Labels) -> Vec<&'static str> {
+        use RoleSection::*;
+        match self {
+            Serve => vec![labels.tot, labels.err, labels.pt],
+            Reception => vec![
+                labels.tot,
+                labels.err,
+                labels.pos_perc,
+                labels.prf_perc,
+                labels.eff_perc,
+            ],
+            Attack => vec![
+                labels.tot,
+                labels.err,
+                labels.blk,
+                labels.pt,
+                labels.pt_perc,
+                labels.eff_perc,
+            ],
+            Block => vec![labels.pt],
+            Dig => vec![labels.tot, labels.err, labels.pos_perc],
+            Distribution => vec![labels.tot, labels.top_zone, "%", labels.pt_perc],
+            Faults => vec![labels.tot],
+        }
+    }
+
+    fn values(&self, row: &StatsRow, aggregated_stats: &Stats, player_id: Uuid) -> Vec<String> {
+        use RoleSection::*;
+        match self {
+            Serve => vec![
+                row.serve_total.clone(),
+                row.serve_errors.clone(),
+                row.serve_points.clone(),
+            ],
+            Reception => vec![
+                row.reception_total.clone(),
+                row.reception_errors.clone(),
+                row.reception_positiveness.clone(),
+                row.reception_perfect.clone(),
+                row.reception_efficiency.clone(),
+            ],
+            Attack => vec![
+                row.attack_total.clone(),
+                row.attack_errors.clone(),
+                row.attack_blocked.clone(),
+                row.attack_points.clone(),
+                row.attack_perfect.clone(),
+                row.attack_efficiency.clone(),
+            ],
+            Block => vec![row.blocks_total.clone()],
+            Dig => {
+                let event_count = |eval| {
+                    aggregated_stats
+                        .event_count(EventTypeEnum::D, Some(player_id), None, None, None, eval)
+                        .map_or("-".to_string(), |v| v.to_string())
+                };
+                vec![
+                    event_count(None),
+                    event_count(Some(EvalEnum::Error)),
+                    fmt_pct(
+                        aggregated_stats
+                            .event_positiveness(
+                                EventTypeEnum::D,
+                                Some(player_id),
+                                None,
+                                None,
+                                None,
+                                Metric::Positive,
+                            )
+                            .map(|(v, _, _)| v),
+                    ),
+                ]
+            }
+            Distribution => match aggregated_stats.distribution.setter_summary(player_id) {
+                Some((total, zone, share, kill)) => vec![
+                    total.to_string(),
+                    zone.to_string(),
+                    fmt_pct(Some(share)),
+                    fmt_pct(Some(kill)),
+                ],
+                None => vec!["-".to_string(); 4],
+            },
+            Faults => vec![row.faults_total.clone()],
+        }
+    }
+}
+
+/// Renders one players table per role, with the columns relevant to that role. When a match
+/// is given, the starting positions of each set are shown as well.
+fn render_players_stats_tables(
+    m: Option<&MatchEntry>,
     players: &[(&PlayerEntry, HashMap<u8, bool>)],
     aggregated_stats: &Stats,
 ) -> String {
     let labels = current_labels();
-    let stroke_positions = [2, 5, 10, 16, 18];
+    [
+        Some(RoleEnum::Setter),
+        Some(RoleEnum::OutsideHitter),
+        Some(RoleEnum::OppositeHitter),
+        Some(RoleEnum::MiddleBlocker),
+        Some(RoleEnum::Libero),
+        None,
+    ]
+    .into_iter()
+    .filter_map(|role| {
+        let group: Vec<_> = players
+            .iter()
+            .filter(|(player, _)| player.role == role)
+            .collect();
+        if group.is_empty() {
+            return None;
+        }
+        let title = role.map_or(labels.no_role, |r| r.friendly_name(labels));
+        Some(render_role_stats_table(
+            m,
+            title,
+            RoleSection::for_role(role),
+            &group,
+            aggregated_stats,
+        ))
+    })
+    .collect()
+}
+
+fn render_role_stats_table(
+    m: Option<&MatchEntry>,
+    title: &str,
+    sections: &[RoleSection],
+    players: &[&(&PlayerEntry, HashMap<u8, bool>)],
+    aggregated_stats: &Stats,
+) -> String {
+    let labels = current_labels();
+    let dashed = "(right: (thickness: 1pt, dash: \"dashed\"))";
+    let header_cell = |text: &str, colspan: usize| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+            .colspan(colspan as u8)
+    };
+    let sub_header = |text: &str, stroke: bool| {
+        let mut cell = Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY);
+        if stroke {
+            cell = cell.stroke(dashed);
+        }
+        cell
+    };
+    // every group of columns is closed by a dashed stroke
+    let mut groups: Vec<(&str, Vec<&str>)> =
+        vec![(labels.points, vec![labels.tot, "bp", labels.won_lost])];
+    groups.extend(
+        sections
+            .iter()
+            .map(|section| (section.title(labels), section.sub_headers(labels))),
+    );
+    let court_sub_headers = vec![
+        labels.rallies,
+        labels.won,
+        labels.plus_minus,
+        labels.started_entered,
+        labels.lib,
+    ];
+    groups.push((labels.on_court, court_sub_headers));
+
+    let mut header_cells = vec![Cell::new(Text::new(escape_text(title)).bold())
+        .align(Align::Left)
+        .fill(LIGHT_GRAY)
+        .colspan(2)];
+    let mut sub_header_cells = vec![
+        Cell::new(Text::new("")).fill(LIGHT_GRAY),
+        Cell::new(Text::new(escape_text(labels.player)).bold())
+            .align(Align::Left)
+            .fill(LIGHT_GRAY),
+    ];
+    let mut columns = vec!["1fr", "10fr"];
+    if m.is_some() {
+        header_cells.push(header_cell("set", 5));
+        for set in 1..=5 {
+            sub_header_cells.push(sub_header(&set.to_string(), set == 5));
+            columns.push("1fr");
+        }
+    }
+    for (group_title, sub_headers) in &groups {
+        header_cells.push(header_cell(group_title, sub_headers.len()));
+        for (i, text) in sub_headers.iter().enumerate() {
+            sub_header_cells.push(sub_header(text, i == sub_headers.len() - 1));
+            columns.push("2fr");
+        }
+    }
+
     let rows: String = players
         .iter()
         .enumerate()
         .map(|(i, (player, set_substitutions))| {
             let bg_color = if i % 2 == 0 { WHITE } else { ALTERNATE_COLOR };
             let player_id = player.id;
-            let positions: Vec<_> = (1..=5)
-                .map(|set| set_player_initial_position(m, set, &player_id, set_substitutions))
-                .collect();
-            let stats = StatsRow::calculate(aggregated_stats, Some(player_id), None);
+            let name = if player.captain {
+                format!("{} (C)", player.name)
+            } else {
+                player.name.clone()
+            };
             let mut cells = vec![
                 Cell::new(Text::new(player.number.to_string()))
                     .align(Align::Left)
                     .fill(bg_color),
-                Cell::new(Text::new(&player.name))
-                    .align(Align::Left)
-                    .fill(bg_color),
+                Cell::new(Text::new(name)).align(Align::Left).fill(bg_color),
             ];
-            for (idx, pos) in positions.into_iter().enumerate() {
-                let mut cell = Cell::new(pos).align(Align::Center).fill(bg_color);
-                if idx == 4 {
-                    cell = cell.stroke("(right: (thickness: 1pt, dash: \"dashed\"))");
+            if let Some(m) = m {
+                for set in 1..=5 {
+                    let pos = set_player_initial_position(m, set, &player_id, set_substitutions);
+                    let mut cell = Cell::new(pos).align(Align::Center).fill(bg_color);
+                    if set == 5 {
+                        cell = cell.stroke(dashed);
+                    }
+                    cells.push(cell);
                 }
-                cells.push(cell);
             }
-            cells.extend(stats.to_cells(bg_color, &stroke_positions));
+            let row = StatsRow::calculate(aggregated_stats, Some(player_id), None);
+            let mut values = vec![vec![
+                row.total_points.clone(),
+                row.break_points.clone(),
+                row.won_minus_lost.clone(),
+            ]];
+            values.extend(
+                sections
+                    .iter()
+                    .map(|section| section.values(&row, aggregated_stats, player_id)),
+            );
+            let (won, lost) = aggregated_stats
+                .on_court_won_lost(player_id, None, None)
+                .unwrap_or((0, 0));
+            let mut court = vec![
+                (won + lost).to_string(),
+                won.to_string(),
+                format!("{:+}", won as i32 - lost as i32),
+            ];
+            let started = set_substitutions
+                .values()
+                .filter(|entered_late| !**entered_late)
+                .count();
+            court.push(format!("{}/{}", started, set_substitutions.len() - started));
+            court.push(
+                aggregated_stats
+                    .libero_swaps(player_id, None)
+                    .map_or("-".to_string(), |v| v.to_string()),
+            );
+            values.push(court);
+            for group in values {
+                let last = group.len() - 1;
+                cells.extend(group.into_iter().enumerate().map(|(j, text)| {
+                    let cell = Cell::new(Text::new(text))
+                        .align(Align::Center)
+                        .fill(bg_color);
+                    if j == last {
+                        cell.stroke(dashed)
+                    } else {
+                        cell
+                    }
+                }));
+            }
             Row::new(cells).render()
         })
         .collect();
-    let (header_row, sub_header_row) = create_stats_headers(labels, false);
+    let header_row = Row::new(header_cells).render();
+    let sub_header_row = Row::new(sub_header_cells).render();
+    let columns = columns.join(", ");
     format!(
         r#"
 #block(
   stroke: 1pt,
   table(
-    columns: (
-      1fr,
-      10fr,
-      1fr,
-      1fr,
-      1fr,
-      1fr,
-      1fr,
-      2fr,
-      1fr,
-      3fr,
-      2fr,
-      2fr,
-      1fr,
-      2fr,
-      2fr,
-      3fr,
-      2fr,
-      3fr,
-      1fr,
-      2fr,
-      2fr,
-      2fr,
-      3fr,
-      2fr,
-      1fr,
-      2fr,
-    ),
+    columns: ({columns}),
     inset:3pt,
     stroke: none,
-    
+
     // group headers
     {header_row}
 
@@ -792,7 +1700,7 @@ fn render_rotations_stats_table(aggregated_stats: &Stats) -> String {
             Row::new(cells).render()
         })
         .collect();
-    let (header_row, sub_header_row) = create_stats_headers(labels, true);
+    let (header_row, sub_header_row) = create_stats_headers(labels);
     format!(
         r#"
 #block(
@@ -838,6 +1746,1051 @@ fn render_rotations_stats_table(aggregated_stats: &Stats) -> String {
     )
 }
 
+fn render_rotation_analysis_table(aggregated_stats: &Stats) -> String {
+    let labels = current_labels();
+    let analysis_cells = |rotation: Option<u8>, bg_color: &'static str| {
+        let sideout = aggregated_stats
+            .phase_win_percentage(PhaseEnum::SideOut, rotation)
+            .map(|(v, _, _)| v);
+        let break_point = aggregated_stats
+            .phase_win_percentage(PhaseEnum::Break, rotation)
+            .map(|(v, _, _)| v);
+        let first_ball_sideout = aggregated_stats
+            .first_ball_sideout(rotation)
+            .map(|(v, _, _)| v);
+        let passes = aggregated_stats.rotation_passes(rotation);
+        let (won, lost) = aggregated_stats.points_won_lost(None, rotation);
+        let balance = won as i32 - lost as i32;
+        let balance_per_pass = passes.map(|p| balance as f64 / p as f64);
+        [
+            fmt_pct(sideout),
+            fmt_pct(break_point),
+            fmt_pct(first_ball_sideout),
+            passes.map_or("-".to_string(), |v| v.to_string()),
+            won.to_string(),
+            lost.to_string(),
+            format!("{:+}", balance),
+            fmt_rate(balance_per_pass),
+        ]
+        .into_iter()
+        .map(|text| {
+            Cell::new(Text::new(text))
+                .align(Align::Center)
+                .fill(bg_color)
+        })
+        .collect::<Vec<_>>()
+    };
+    let rows: String = (0..6)
+        .map(|rotation| {
+            let bg_color = if rotation % 2 == 0 {
+                WHITE
+            } else {
+                ALTERNATE_COLOR
+            };
+            let mut cells = vec![Cell::new(Text::new(format!(
+                "{}{}",
+                labels.setter_prefix,
+                rotation + 1
+            )))
+            .align(Align::Center)
+            .fill(bg_color)];
+            cells.extend(analysis_cells(Some(rotation), bg_color));
+            Row::new(cells).render()
+        })
+        .collect();
+    let mut total_cells = vec![Cell::new(Text::new(escape_text(labels.global)).bold())
+        .align(Align::Center)
+        .fill(LIGHT_GRAY)];
+    total_cells.extend(analysis_cells(None, LIGHT_GRAY));
+    let total_row = Row::new(total_cells).render();
+    let header_cell = |text: &str| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+    };
+    let header_row = Row::new(vec![
+        Cell::new(Text::new(escape_text(labels.rotation_analysis)).bold())
+            .align(Align::Left)
+            .fill(LIGHT_GRAY)
+            .colspan(9),
+        header_cell(labels.rotation),
+        header_cell(labels.sideout_perc),
+        header_cell(labels.break_point_perc),
+        header_cell(labels.first_ball_sideout_perc),
+        header_cell(labels.rotation_passes),
+        header_cell(labels.won),
+        header_cell(labels.lost),
+        header_cell(labels.balance),
+        header_cell(labels.balance_per_pass),
+    ])
+    .render();
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: (1fr, 1fr, 1fr, 1fr, 1fr, 1fr, 1fr, 1fr, 1fr),
+    inset: 3pt,
+    stroke: none,
+    {header_row}
+    {rows}
+    {total_row}
+  )
+)
+"#
+    )
+}
+
+fn render_reception_analysis_table(
+    players: &[(&PlayerEntry, HashMap<u8, bool>)],
+    aggregated_stats: &Stats,
+) -> String {
+    use EvalEnum::*;
+    let labels = current_labels();
+    let pass_evals = [Perfect, Positive, Exclamative, Negative, Over, Error];
+    let quality_evals = [Perfect, Positive, Exclamative, Negative];
+    let team_total = aggregated_stats.event_count(EventTypeEnum::P, None, None, None, None, None);
+    let reception_cells =
+        |player: Option<Uuid>, rotation: Option<u8>, bg_color: &'static str| -> Vec<Cell> {
+            let total =
+                aggregated_stats.event_count(EventTypeEnum::P, player, None, rotation, None, None);
+            let share = match (total, team_total) {
+                (Some(count), Some(all)) => Some(100.0 * count as f64 / all as f64),
+                _ => None,
+            };
+            let mut texts = vec![
+                total.map_or("-".to_string(), |v| v.to_string()),
+                fmt_pct(share),
+            ];
+            texts.extend(pass_evals.iter().map(|eval| {
+                aggregated_stats
+                    .event_count(EventTypeEnum::P, player, None, rotation, None, Some(*eval))
+                    .map_or("-".to_string(), |v| v.to_string())
+            }));
+            texts.extend(quality_evals.iter().map(|eval| {
+                fmt_pct(
+                    aggregated_stats
+                        .reception_sideout_percentage(player, rotation, Some(*eval))
+                        .map(|(v, _, _)| v),
+                )
+            }));
+            texts.extend(quality_evals.iter().map(|eval| {
+                fmt_pct(
+                    aggregated_stats
+                        .reception_kill_percentage(player, rotation, Some(*eval))
+                        .map(|(v, _, _)| v),
+                )
+            }));
+            texts
+                .into_iter()
+                .enumerate()
+                .map(|(i, text)| {
+                    let mut cell = Cell::new(Text::new(text))
+                        .align(Align::Center)
+                        .fill(bg_color);
+                    if [7, 11].contains(&i) {
+                        cell = cell.stroke("(right: (thickness: 1pt, dash: \"dashed\"))");
+                    }
+                    cell
+                })
+                .collect()
+        };
+    let mut rows: Vec<String> = players
+        .iter()
+        .filter(|(player, _)| {
+            aggregated_stats
+                .event_count(EventTypeEnum::P, Some(player.id), None, None, None, None)
+                .is_some()
+        })
+        .enumerate()
+        .map(|(i, (player, _))| {
+            let bg_color = if i % 2 == 0 { WHITE } else { ALTERNATE_COLOR };
+            let mut cells = vec![
+                Cell::new(Text::new(player.number.to_string()))
+                    .align(Align::Left)
+                    .fill(bg_color),
+                Cell::new(Text::new(&player.name))
+                    .align(Align::Left)
+                    .fill(bg_color),
+            ];
+            cells.extend(reception_cells(Some(player.id), None, bg_color));
+            Row::new(cells).render()
+        })
+        .collect();
+    let mut total_cells = vec![Cell::new(Text::new(escape_text(labels.global)).bold())
+        .align(Align::Left)
+        .fill(LIGHT_GRAY)
+        .colspan(2)];
+    total_cells.extend(reception_cells(None, None, LIGHT_GRAY));
+    rows.push(Row::new(total_cells).render());
+    for rotation in 0..6 {
+        let bg_color = if rotation % 2 == 0 {
+            WHITE
+        } else {
+            ALTERNATE_COLOR
+        };
+        let mut cells = vec![Cell::new(Text::new(format!(
+            "{}{}",
+            labels.setter_prefix,
+            rotation + 1
+        )))
+        .align(Align::Left)
+        .fill(bg_color)
+        .colspan(2)];
+        cells.extend(reception_cells(None, Some(rotation), bg_color));
+        rows.push(Row::new(cells).render());
+    }
+    let rows = rows.join("\n");
+    let header_cell = |text: &str, colspan: u8| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+            .colspan(colspan)
+    };
+    let sub_header = |text: String, stroke: bool| {
+        let mut cell = Cell::new(Text::new(text).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY);
+        if stroke {
+            cell = cell.stroke("(right: (thickness: 1pt, dash: \"dashed\"))");
+        }
+        cell
+    };
+    let header_row = Row::new(vec![
+        Cell::new(Text::new(escape_text(labels.reception_analysis)).bold())
+            .align(Align::Left)
+            .fill(LIGHT_GRAY)
+            .colspan(2),
+        header_cell(labels.reception, 8),
+        header_cell(labels.sideout_after_pass, 4),
+        header_cell(labels.kill_after_pass, 4),
+    ])
+    .render();
+    let mut sub_header_cells = vec![
+        Cell::new(Text::new("")).fill(LIGHT_GRAY),
+        Cell::new(Text::new(escape_text(labels.player)).bold())
+            .align(Align::Left)
+            .fill(LIGHT_GRAY),
+        sub_header(labels.tot.to_string(), false),
+        sub_header(labels.share.to_string(), false),
+    ];
+    sub_header_cells.extend(
+        pass_evals
+            .iter()
+            .enumerate()
+            .map(|(i, eval)| sub_header(eval.to_string(), i == pass_evals.len() - 1)),
+    );
+    sub_header_cells.extend(
+        quality_evals
+            .iter()
+            .enumerate()
+            .map(|(i, eval)| sub_header(eval.to_string(), i == quality_evals.len() - 1)),
+    );
+    sub_header_cells.extend(
+        quality_evals
+            .iter()
+            .map(|eval| sub_header(eval.to_string(), false)),
+    );
+    let sub_header_row = Row::new(sub_header_cells).render();
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: (1fr, 6fr, 2fr, 2fr, 1fr, 1fr, 1fr, 1fr, 1fr, 1fr, 2fr, 2fr, 2fr, 2fr, 2fr, 2fr, 2fr, 2fr),
+    inset: 3pt,
+    stroke: none,
+    {header_row}
+    {sub_header_row}
+    {rows}
+  )
+)
+"#
+    )
+}
+
+fn render_set_and_free_ball_table(
+    players: &[(&PlayerEntry, HashMap<u8, bool>)],
+    aggregated_stats: &Stats,
+) -> String {
+    use EventTypeEnum::*;
+    let labels = current_labels();
+    let cells = |player: Option<Uuid>, bg_color: &'static str| -> Vec<Cell> {
+        let count = |event_type: EventTypeEnum| {
+            aggregated_stats
+                .event_count(event_type, player, None, None, None, None)
+                .map(|v| v.to_string())
+                .unwrap_or("-".to_string())
+        };
+        let errors = |event_type: EventTypeEnum| {
+            aggregated_stats
+                .errors(event_type, None, player, None, None, None)
+                .map(|v| v.to_string())
+                .unwrap_or("-".to_string())
+        };
+        let texts = vec![
+            count(E),
+            errors(E),
+            fmt_pct(
+                aggregated_stats
+                    .event_positiveness(E, player, None, None, None, Metric::Positive)
+                    .map(|(v, _, _)| v),
+            ),
+            fmt_pct(
+                aggregated_stats
+                    .set_kill_percentage(player, None, None, None)
+                    .map(|(v, _, _)| v),
+            ),
+            count(X),
+            errors(X),
+            fmt_pct(
+                aggregated_stats
+                    .freeball_won_percentage(player, None, None, None)
+                    .map(|(v, _, _)| v),
+            ),
+        ];
+        texts
+            .into_iter()
+            .enumerate()
+            .map(|(i, text)| {
+                let mut cell = Cell::new(Text::new(text))
+                    .align(Align::Center)
+                    .fill(bg_color);
+                if i == 3 {
+                    cell = cell.stroke("(right: (thickness: 1pt, dash: \"dashed\"))");
+                }
+                cell
+            })
+            .collect()
+    };
+    let mut rows: Vec<String> = players
+        .iter()
+        .filter(|(player, _)| {
+            [E, X].iter().any(|event_type| {
+                aggregated_stats
+                    .event_count(*event_type, Some(player.id), None, None, None, None)
+                    .is_some()
+            })
+        })
+        .enumerate()
+        .map(|(i, (player, _))| {
+            let bg_color = if i % 2 == 0 { WHITE } else { ALTERNATE_COLOR };
+            let mut row_cells = vec![
+                Cell::new(Text::new(player.number.to_string()))
+                    .align(Align::Left)
+                    .fill(bg_color),
+                Cell::new(Text::new(&player.name))
+                    .align(Align::Left)
+                    .fill(bg_color),
+            ];
+            row_cells.extend(cells(Some(player.id), bg_color));
+            Row::new(row_cells).render()
+        })
+        .collect();
+    if rows.is_empty() {
+        return String::new();
+    }
+    let mut total_cells = vec![Cell::new(Text::new(escape_text(labels.global)).bold())
+        .align(Align::Left)
+        .fill(LIGHT_GRAY)
+        .colspan(2)];
+    total_cells.extend(cells(None, LIGHT_GRAY));
+    rows.push(Row::new(total_cells).render());
+    let rows = rows.join("\n");
+    let header_cell = |text: &str, colspan: u8| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+            .colspan(colspan)
+    };
+    let sub_header = |text: &str| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+    };
+    let header_row = Row::new(vec![
+        Cell::new(Text::new(escape_text(labels.set_and_free_ball)).bold())
+            .align(Align::Left)
+            .fill(LIGHT_GRAY)
+            .colspan(2),
+        header_cell(labels.set_touch, 4),
+        header_cell(labels.free_ball, 3),
+    ])
+    .render();
+    let sub_header_row = Row::new(vec![
+        Cell::new(Text::new("")).fill(LIGHT_GRAY),
+        Cell::new(Text::new(escape_text(labels.player)).bold())
+            .align(Align::Left)
+            .fill(LIGHT_GRAY),
+        sub_header(labels.tot),
+        sub_header(labels.err),
+        sub_header(labels.pos_perc),
+        sub_header(labels.kill_perc).stroke("(right: (thickness: 1pt, dash: \"dashed\"))"),
+        sub_header(labels.tot),
+        sub_header(labels.err),
+        sub_header(labels.won_perc),
+    ])
+    .render();
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: (1fr, 6fr, 2fr, 2fr, 2fr, 2fr, 2fr, 2fr, 2fr),
+    inset: 3pt,
+    stroke: none,
+    {header_row}
+    {sub_header_row}
+    {rows}
+  )
+)
+"#
+    )
+}
+
+fn render_rally_length_table(aggregated_stats: &Stats) -> String {
+    let labels = current_labels();
+    let Some((_, all_rallies, _)) = aggregated_stats.rally_won_perc(None, None, None) else {
+        return String::new();
+    };
+    let dashed = "(right: (thickness: 1pt, dash: \"dashed\"))";
+    let lengths = RallyLengthEnum::ALL.into_iter().map(Some).chain([None]);
+    let rows: Vec<String> = lengths
+        .enumerate()
+        .map(|(i, length)| {
+            let bg_color = if length.is_none() {
+                LIGHT_GRAY
+            } else if i % 2 == 0 {
+                WHITE
+            } else {
+                ALTERNATE_COLOR
+            };
+            let overall = aggregated_stats.rally_won_perc(None, None, length);
+            let mut cells = vec![Cell::new(Text::new(escape_text(
+                length.map_or(labels.global, |l| l.friendly_name(labels)),
+            )))
+            .align(Align::Left)
+            .fill(bg_color)];
+            let mut texts = vec![
+                overall.map_or("-".to_string(), |(_, total, _)| total.to_string()),
+                fmt_pct(overall.map(|(_, total, _)| 100.0 * total as f64 / all_rallies as f64)),
+                fmt_pct(overall.map(|(perc, _, _)| perc)),
+            ];
+            for phase in [PhaseEnum::SideOut, PhaseEnum::Break] {
+                let by_phase = aggregated_stats.rally_won_perc(Some(phase), None, length);
+                texts.push(by_phase.map_or("-".to_string(), |(_, total, _)| total.to_string()));
+                texts.push(fmt_pct(by_phase.map(|(perc, _, _)| perc)));
+            }
+            cells.extend(texts.into_iter().enumerate().map(|(j, text)| {
+                let cell = Cell::new(Text::new(text))
+                    .align(Align::Center)
+                    .fill(bg_color);
+                // the last column of the global and side-out groups
+                if j == 2 || j == 4 {
+                    cell.stroke(dashed)
+                } else {
+                    cell
+                }
+            }));
+            Row::new(cells).render()
+        })
+        .collect();
+    let rows = rows.join("\n");
+    let header_cell = |text: &str, colspan: u8| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+            .colspan(colspan)
+    };
+    let header_row = Row::new(vec![
+        Cell::new(Text::new(escape_text(labels.rally_length)).bold())
+            .align(Align::Left)
+            .fill(LIGHT_GRAY),
+        header_cell(labels.global, 3).stroke(dashed),
+        header_cell(PhaseEnum::SideOut.friendly_name(labels), 2).stroke(dashed),
+        header_cell(PhaseEnum::Break.friendly_name(labels), 2),
+    ])
+    .render();
+    let sub_header_row = Row::new(vec![
+        header_cell("", 1),
+        header_cell(labels.tot, 1),
+        header_cell(labels.share, 1),
+        header_cell(labels.won_perc, 1).stroke(dashed),
+        header_cell(labels.tot, 1),
+        header_cell(labels.won_perc, 1).stroke(dashed),
+        header_cell(labels.tot, 1),
+        header_cell(labels.won_perc, 1),
+    ])
+    .render();
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: (6fr, 2fr, 2fr, 2fr, 2fr, 2fr, 2fr, 2fr),
+    inset: 3pt,
+    stroke: none,
+    {header_row}
+    {sub_header_row}
+    {rows}
+  )
+)
+"#
+    )
+}
+
+fn render_serve_type_table(
+    players: &[(&PlayerEntry, HashMap<u8, bool>)],
+    aggregated_stats: &Stats,
+) -> String {
+    let labels = current_labels();
+    let serve_types: Vec<ServeTypeEnum> = ServeTypeEnum::ALL
+        .into_iter()
+        .filter(|serve_type| {
+            aggregated_stats
+                .serve_type_positiveness(None, Some(*serve_type), Metric::Positive)
+                .is_some()
+        })
+        .collect();
+    if serve_types.is_empty() {
+        return String::new();
+    }
+    let dashed = "(right: (thickness: 1pt, dash: \"dashed\"))";
+    let cells = |player: Option<Uuid>, bg_color: &'static str| -> Vec<Cell> {
+        serve_types
+            .iter()
+            .flat_map(|serve_type| {
+                let positive = aggregated_stats.serve_type_positiveness(
+                    player,
+                    Some(*serve_type),
+                    Metric::Positive,
+                );
+                let efficiency = aggregated_stats.serve_type_positiveness(
+                    player,
+                    Some(*serve_type),
+                    Metric::Efficiency,
+                );
+                [
+                    positive.map_or("-".to_string(), |(_, total, _)| total.to_string()),
+                    fmt_pct(positive.map(|(perc, _, _)| perc)),
+                    fmt_pct(efficiency.map(|(perc, _, _)| perc)),
+                ]
+                .into_iter()
+                .enumerate()
+                .map(|(i, text)| {
+                    let cell = Cell::new(Text::new(text))
+                        .align(Align::Center)
+                        .fill(bg_color);
+                    if i == 2 {
+                        cell.stroke(dashed)
+                    } else {
+                        cell
+                    }
+                })
+                .collect::<Vec<_>>()
+            })
+            .collect()
+    };
+    let mut rows: Vec<String> = players
+        .iter()
+        .filter(|(player, _)| {
+            aggregated_stats
+                .serve_type_positiveness(Some(player.id), None, Metric::Positive)
+                .is_some()
+        })
+        .enumerate()
+        .map(|(i, (player, _))| {
+            let bg_color = if i % 2 == 0 { WHITE } else { ALTERNATE_COLOR };
+            let mut row_cells = vec![
+                Cell::new(Text::new(player.number.to_string()))
+                    .align(Align::Left)
+                    .fill(bg_color),
+                Cell::new(Text::new(&player.name))
+                    .align(Align::Left)
+                    .fill(bg_color),
+            ];
+            row_cells.extend(cells(Some(player.id), bg_color));
+            Row::new(row_cells).render()
+        })
+        .collect();
+    let mut total_cells = vec![Cell::new(Text::new(escape_text(labels.global)).bold())
+        .align(Align::Left)
+        .fill(LIGHT_GRAY)
+        .colspan(2)];
+    total_cells.extend(cells(None, LIGHT_GRAY));
+    rows.push(Row::new(total_cells).render());
+    let rows = rows.join("\n");
+    let mut header_cells = vec![Cell::new(Text::new(escape_text(labels.serve_types)).bold())
+        .align(Align::Left)
+        .fill(LIGHT_GRAY)
+        .colspan(2)];
+    header_cells.extend(serve_types.iter().map(|serve_type| {
+        Cell::new(Text::new(escape_text(serve_type.friendly_name(labels))).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+            .colspan(3)
+    }));
+    let header_row = Row::new(header_cells).render();
+    let sub_header = |text: &str| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+    };
+    let mut sub_header_cells = vec![
+        Cell::new(Text::new("")).fill(LIGHT_GRAY),
+        Cell::new(Text::new(escape_text(labels.server)).bold())
+            .align(Align::Left)
+            .fill(LIGHT_GRAY),
+    ];
+    for _ in &serve_types {
+        sub_header_cells.push(sub_header(labels.tot));
+        sub_header_cells.push(sub_header(labels.pos_perc));
+        sub_header_cells.push(sub_header(labels.eff_perc).stroke(dashed));
+    }
+    let sub_header_row = Row::new(sub_header_cells).render();
+    let columns = std::iter::once("1fr, 6fr".to_string())
+        .chain(serve_types.iter().map(|_| "2fr, 2fr, 2fr".to_string()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: ({columns}),
+    inset: 3pt,
+    stroke: none,
+    {header_row}
+    {sub_header_row}
+    {rows}
+  )
+)
+"#
+    )
+}
+
+fn render_block_table(
+    players: &[(&PlayerEntry, HashMap<u8, bool>)],
+    aggregated_stats: &Stats,
+) -> String {
+    let labels = current_labels();
+    if aggregated_stats
+        .block_count(None, None, None, None, None)
+        .is_none()
+    {
+        return String::new();
+    }
+    let cells = |player: Option<Uuid>,
+                 rotation: Option<u8>,
+                 zone: Option<ZoneEnum>,
+                 bg_color: &'static str|
+     -> Vec<Cell> {
+        let fmt_count = |v: Option<u32>| v.map(|v| v.to_string()).unwrap_or("-".to_string());
+        let mut texts = vec![fmt_count(
+            aggregated_stats.block_count(player, rotation, zone, None, None),
+        )];
+        texts.extend(BlockCompositionEnum::ALL.iter().map(|composition| {
+            fmt_count(aggregated_stats.block_count(
+                player,
+                rotation,
+                zone,
+                Some(*composition),
+                None,
+            ))
+        }));
+        texts.push(fmt_count(aggregated_stats.block_count(
+            player,
+            rotation,
+            zone,
+            None,
+            Some(EvalEnum::Perfect),
+        )));
+        texts.push(fmt_count(
+            aggregated_stats.block_touch_count(player, rotation, zone),
+        ));
+        texts
+            .into_iter()
+            .map(|text| {
+                Cell::new(Text::new(text))
+                    .align(Align::Center)
+                    .fill(bg_color)
+            })
+            .collect()
+    };
+    let label_cells = |text: String, bg_color: &'static str| {
+        vec![Cell::new(Text::new(escape_text(&text)))
+            .align(Align::Left)
+            .fill(bg_color)
+            .colspan(2)]
+    };
+    let mut rows: Vec<String> = players
+        .iter()
+        .filter(|(player, _)| {
+            aggregated_stats
+                .block_count(Some(player.id), None, None, None, None)
+                .is_some()
+        })
+        .enumerate()
+        .map(|(i, (player, _))| {
+            let bg_color = if i % 2 == 0 { WHITE } else { ALTERNATE_COLOR };
+            let mut row_cells = vec![
+                Cell::new(Text::new(player.number.to_string()))
+                    .align(Align::Left)
+                    .fill(bg_color),
+                Cell::new(Text::new(&player.name))
+                    .align(Align::Left)
+                    .fill(bg_color),
+            ];
+            row_cells.extend(cells(Some(player.id), None, None, bg_color));
+            Row::new(row_cells).render()
+        })
+        .collect();
+    let mut total_cells = vec![Cell::new(Text::new(escape_text(labels.global)).bold())
+        .align(Align::Left)
+        .fill(LIGHT_GRAY)
+        .colspan(2)];
+    total_cells.extend(cells(None, None, None, LIGHT_GRAY));
+    rows.push(Row::new(total_cells).render());
+    for rotation in 0..6 {
+        let bg_color = if rotation % 2 == 0 {
+            WHITE
+        } else {
+            ALTERNATE_COLOR
+        };
+        let mut row_cells = label_cells(
+            format!("{}{}", labels.setter_prefix, rotation + 1),
+            bg_color,
+        );
+        row_cells.extend(cells(None, Some(rotation), None, bg_color));
+        rows.push(Row::new(row_cells).render());
+    }
+    for (i, zone) in [ZoneEnum::Four, ZoneEnum::Three, ZoneEnum::Two]
+        .into_iter()
+        .enumerate()
+    {
+        let bg_color = if i % 2 == 0 { WHITE } else { ALTERNATE_COLOR };
+        let mut row_cells = label_cells(
+            format!("{} {}", labels.opponent_attack_zone, zone),
+            bg_color,
+        );
+        row_cells.extend(cells(None, None, Some(zone), bg_color));
+        rows.push(Row::new(row_cells).render());
+    }
+    let rows = rows.join("\n");
+    let sub_header = |text: &str| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+    };
+    let mut header_cells = vec![
+        Cell::new(Text::new(escape_text(labels.block_composition)).bold())
+            .align(Align::Left)
+            .fill(LIGHT_GRAY)
+            .colspan(2),
+        sub_header(labels.tot),
+    ];
+    header_cells.extend(
+        BlockCompositionEnum::ALL
+            .iter()
+            .map(|composition| sub_header(composition.friendly_name(labels))),
+    );
+    header_cells.push(sub_header(labels.points));
+    header_cells.push(sub_header(labels.touched));
+    let header_row = Row::new(header_cells).render();
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: (1fr, 6fr, 2fr, 2fr, 2fr, 2fr, 2fr, 2fr),
+    inset: 3pt,
+    stroke: none,
+    {header_row}
+    {rows}
+  )
+)
+"#
+    )
+}
+
+fn render_setter_distribution_table(aggregated_stats: &Stats) -> String {
+    use EvalEnum::*;
+    use ZoneEnum::*;
+    let labels = current_labels();
+    let zones = [Four, Three, Two, Eight, Nine];
+    let pass_evals = [Perfect, Positive, Exclamative, Negative];
+    let rotations = (0..6).map(Some).chain(std::iter::once(None));
+    let mut rows = vec![];
+    for (i, rotation) in rotations.enumerate() {
+        let bg_color = if rotation.is_none() {
+            LIGHT_GRAY
+        } else if i % 2 == 0 {
+            WHITE
+        } else {
+            ALTERNATE_COLOR
+        };
+        let rotation_name = match rotation {
+            Some(r) => format!("{}{}", labels.setter_prefix, r + 1),
+            None => labels.global.to_string(),
+        };
+        for (j, eval) in pass_evals.iter().enumerate() {
+            let top_zone =
+                aggregated_stats
+                    .distribution
+                    .top_zone(None, rotation, None, Some(*eval));
+            let mut texts = vec![
+                if j == 0 {
+                    rotation_name.clone()
+                } else {
+                    "".to_string()
+                },
+                eval.to_string(),
+                top_zone.map_or("-".to_string(), |(_, _, total)| total.to_string()),
+            ];
+            texts.extend(zones.iter().map(|zone| {
+                aggregated_stats
+                    .distribution
+                    .zone_stats(*zone, None, rotation, None, Some(*eval))
+                    .map_or("-".to_string(), |(share, kill)| {
+                        format!("{:.0}% / {:.0}%", share, kill)
+                    })
+            }));
+            texts.push(top_zone.map_or("-".to_string(), |(zone, _, _)| zone.to_string()));
+            texts.push(fmt_pct(top_zone.map(|(_, share, _)| share)));
+            let cells = texts
+                .into_iter()
+                .map(|text| {
+                    Cell::new(Text::new(text))
+                        .align(Align::Center)
+                        .fill(bg_color)
+                })
+                .collect();
+            rows.push(Row::new(cells).render());
+        }
+    }
+    let rows = rows.join("\n");
+    let header_cell = |text: String, colspan: u8| {
+        Cell::new(Text::new(escape_text(&text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+            .colspan(colspan)
+    };
+    let header_row = Row::new(vec![
+        Cell::new(Text::new(escape_text(labels.setter_distribution)).bold())
+            .align(Align::Left)
+            .fill(LIGHT_GRAY)
+            .colspan(3),
+        header_cell(format!("{} ({})", labels.zone, labels.distribution_kill), 5),
+        header_cell("".to_string(), 2),
+    ])
+    .render();
+    let mut sub_header_cells = vec![
+        header_cell(labels.rotation.to_string(), 1),
+        header_cell(labels.pass_quality.to_string(), 1),
+        header_cell(labels.tot.to_string(), 1),
+    ];
+    sub_header_cells.extend(zones.iter().map(|zone| header_cell(zone.to_string(), 1)));
+    sub_header_cells.push(header_cell(labels.top_zone.to_string(), 1));
+    sub_header_cells.push(header_cell(labels.predictability.to_string(), 1));
+    let sub_header_row = Row::new(sub_header_cells).render();
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: (2fr, 2fr, 1fr, 3fr, 3fr, 3fr, 3fr, 3fr, 2fr, 2fr),
+    inset: 3pt,
+    stroke: none,
+    {header_row}
+    {sub_header_row}
+    {rows}
+  )
+)
+"#
+    )
+}
+
+fn render_attack_combination_table(
+    combinations: &[AttackCombinationEntry],
+    aggregated_stats: &Stats,
+) -> String {
+    use EvalEnum::*;
+    let labels = current_labels();
+    let pass_evals = [Perfect, Positive, Exclamative, Negative];
+    // codes removed from the catalogue are still reported, without tempo and zone
+    let rows: Vec<String> = aggregated_stats
+        .attack_combinations
+        .codes()
+        .iter()
+        .enumerate()
+        .map(|(i, code)| {
+            let bg_color = if i % 2 == 0 { WHITE } else { ALTERNATE_COLOR };
+            let entry = combinations.iter().find(|c| &c.code == code);
+            let summary = aggregated_stats.attack_combination_summary(Some(code), None, None);
+            let mut texts = vec![
+                code.clone(),
+                entry.map_or("-".to_string(), |c| {
+                    c.tempo.friendly_name(labels).to_string()
+                }),
+                entry.map_or("-".to_string(), |c| c.zone.to_string()),
+                summary.map_or("-".to_string(), |(total, _, _)| total.to_string()),
+                fmt_pct(summary.map(|(_, kill, _)| kill)),
+                fmt_pct(summary.map(|(_, _, efficiency)| efficiency)),
+            ];
+            texts.extend(pass_evals.iter().map(|eval| {
+                aggregated_stats
+                    .attack_combination_summary(Some(code), None, Some(*eval))
+                    .map_or("-".to_string(), |(total, kill, efficiency)| {
+                        format!("{} - {:.0}% / {:.0}%", total, kill, efficiency)
+                    })
+            }));
+            let cells = texts
+                .into_iter()
+                .map(|text| {
+                    Cell::new(Text::new(escape_text(&text)))
+                        .align(Align::Center)
+                        .fill(bg_color)
+                })
+                .collect();
+            Row::new(cells).render()
+        })
+        .collect();
+    if rows.is_empty() {
+        return String::new();
+    }
+    let rows = rows.join("\n");
+    let header_cell = |text: String, colspan: u8| {
+        Cell::new(Text::new(escape_text(&text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+            .colspan(colspan)
+    };
+    let header_row = Row::new(vec![
+        Cell::new(Text::new(escape_text(labels.attack_combinations)).bold())
+            .align(Align::Left)
+            .fill(LIGHT_GRAY)
+            .colspan(6),
+        header_cell(
+            format!(
+                "{} ({} - {} / {})",
+                labels.pass_quality, labels.tot, labels.kill_perc, labels.eff_perc
+            ),
+            4,
+        ),
+    ])
+    .render();
+    let mut sub_header_cells = vec![
+        header_cell(labels.code.to_string(), 1),
+        header_cell(labels.tempo.to_string(), 1),
+        header_cell(labels.zone.to_string(), 1),
+        header_cell(labels.tot.to_string(), 1),
+        header_cell(labels.kill_perc.to_string(), 1),
+        header_cell(labels.eff_perc.to_string(), 1),
+    ];
+    sub_header_cells.extend(
+        pass_evals
+            .iter()
+            .map(|eval| header_cell(eval.to_string(), 1)),
+    );
+    let sub_header_row = Row::new(sub_header_cells).render();
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: (2fr, 2fr, 1fr, 1fr, 2fr, 2fr, 3fr, 3fr, 3fr, 3fr),
+    inset: 3pt,
+    stroke: none,
+    {header_row}
+    {sub_header_row}
+    {rows}
+  )
+)
+"#
+    )
+}
+
+fn render_plus_minus_table(
+    players: &[(&PlayerEntry, HashMap<u8, bool>)],
+    title: &str,
+    first_column: &str,
+    mut entries: Vec<(Vec<Uuid>, u32, u32)>,
+) -> String {
+    let labels = current_labels();
+    // sorted by point differential, then by rallies played
+    entries.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+    entries
+        .sort_by_key(|(_, won, lost)| (Reverse(*won as i32 - *lost as i32), Reverse(won + lost)));
+    let mut rows = vec![];
+    for (i, (ids, won, lost)) in entries.into_iter().enumerate() {
+        let bg_color = if i % 2 == 0 { WHITE } else { ALTERNATE_COLOR };
+        let mut numbers: Vec<u8> = ids
+            .iter()
+            .filter_map(|id| {
+                players
+                    .iter()
+                    .find(|(p, _)| p.id == *id)
+                    .map(|(p, _)| p.number)
+            })
+            .collect();
+        numbers.sort();
+        let texts = vec![
+            numbers
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join(" - "),
+            (won + lost).to_string(),
+            won.to_string(),
+            lost.to_string(),
+            format!("{:+}", won as i32 - lost as i32),
+        ];
+        let cells = texts
+            .into_iter()
+            .map(|text| {
+                Cell::new(Text::new(text))
+                    .align(Align::Center)
+                    .fill(bg_color)
+            })
+            .collect();
+        rows.push(Row::new(cells).render());
+    }
+    let rows = rows.join("\n");
+    let header_cell = |text: &str| {
+        Cell::new(Text::new(escape_text(text)).bold())
+            .align(Align::Center)
+            .fill(LIGHT_GRAY)
+    };
+    let header_row = Row::new(vec![Cell::new(Text::new(escape_text(title)).bold())
+        .align(Align::Left)
+        .fill(LIGHT_GRAY)
+        .colspan(5)])
+    .render();
+    let sub_header_row = Row::new(vec![
+        header_cell(first_column),
+        header_cell(labels.rallies),
+        header_cell(labels.won),
+        header_cell(labels.lost),
+        header_cell(labels.plus_minus),
+    ])
+    .render();
+    format!(
+        r#"
+#block(
+  stroke: 1pt,
+  table(
+    columns: (4fr, 1fr, 1fr, 1fr, 1fr),
+    inset: 3pt,
+    stroke: none,
+    {header_row}
+    {sub_header_row}
+    {rows}
+  )
+)
+"#
+    )
+}
+
 fn render_global_stats_table(aggregated_stats: &Stats) -> String {
     let points_label = escape_text(current_labels().points);
     let serve_label = escape_text(current_labels().serve);
@@ -1,8 +1,16 @@
 use std::sync::Arc;
 
 use crate::{
+    errors::AppError,
     localization::current_labels,
-    providers::{match_writer::MatchWriter, set_writer::SetWriter},
+    providers::{
+        match_writer::MatchWriter,
+        opponent_reader::OpponentReader,
+        opponent_writer::{OpponentInput, OpponentWriter},
+        set_writer::SetWriter,
+        team_reader::TeamReader,
+        team_writer::TeamWriter,
+    },
     screens::{
         components::{
             checkbox::CheckBox, date_picker::DatePicker, navigation_footer::NavigationFooter,
@@ -12,7 +20,8 @@ use crate::{
         start_set_screen::StartSetScreen,
     },
     shapes::{
-        enums::ScreenActionEnum, keybinding::ScreenKeyBindings, settings::Settings, team::TeamEntry,
+        enums::ScreenActionEnum, keybinding::ScreenKeyBindings, opponent::OpponentEntry,
+        settings::Settings, team::TeamEntry,
     },
 };
 use async_trait::async_trait;
@@ -24,7 +33,14 @@ use ratatui::{
 };
 
 #[derive(Debug)]
-pub struct AddMatchScreen<MW: MatchWriter + Send + Sync, SSW: SetWriter + Send + Sync> {
+pub struct AddMatchScreen<
+    MW: MatchWriter + Send + Sync,
+    OR: OpponentReader + Send + Sync,
+    OW: OpponentWriter + Send + Sync,
+    SSW: SetWriter + Send + Sync,
+    TR: TeamReader + Send + Sync,
+    TW: TeamWriter + Send + Sync,
+> {
     settings: Settings,
     team: TeamEntry,
     opponent: TextBox, // field 0
@@ -36,12 +52,22 @@ pub struct AddMatchScreen<MW: MatchWriter + Send + Sync, SSW: SetWriter + Send +
     footer: NavigationFooter,
     footer_entries: Vec<(String, String)>,
     match_writer: Arc<MW>,
+    opponent_reader: Arc<OR>,
+    opponent_writer: Arc<OW>,
     set_writer: Arc<SSW>,
+    team_reader: Arc<TR>,
+    team_writer: Arc<TW>,
     screen_key_bindings: ScreenKeyBindings,
 }
 
-impl<MW: MatchWriter + Send + Sync + 'static, SSW: SetWriter + Send + Sync + 'static> Renderable
-    for AddMatchScreen<MW, SSW>
+impl<
+        MW: MatchWriter + Send + Sync + 'static,
+        OR: OpponentReader + Send + Sync + 'static,
+        OW: OpponentWriter + Send + Sync + 'static,
+        SSW: SetWriter + Send + Sync + 'static,
+        TR: TeamReader + Send + Sync + 'static,
+        TW: TeamWriter + Send + Sync + 'static,
+    > Renderable for AddMatchScreen<MW, OR, OW, SSW, TR, TW>
 {
     fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
         let container = Layout::default()
@@ -71,8 +97,14 @@ impl<MW: MatchWriter + Send + Sync + 'static, SSW: SetWriter + Send + Sync + 'st
 }
 
 #[async_trait]
-impl<MW: MatchWriter + Send + Sync + 'static, SSW: SetWriter + Send + Sync + 'static> ScreenAsync
-    for AddMatchScreen<MW, SSW>
+impl<
+        MW: MatchWriter + Send + Sync + 'static,
+        OR: OpponentReader + Send + Sync + 'static,
+        OW: OpponentWriter + Send + Sync + 'static,
+        SSW: SetWriter + Send + Sync + 'static,
+        TR: TeamReader + Send + Sync + 'static,
+        TW: TeamWriter + Send + Sync + 'static,
+    > ScreenAsync for AddMatchScreen<MW, OR, OW, SSW, TR, TW>
 {
     async fn handle_key(&mut self, key: KeyEvent) -> AppAction {
         if let Some(key_combination) = self.screen_key_bindings.transform(key) {
@@ -101,14 +133,24 @@ impl<MW: MatchWriter + Send + Sync + 'static, SSW: SetWriter + Send + Sync + 'st
     async fn refresh_data(&mut self) {}
 }
 
-impl<MW: MatchWriter + Send + Sync + 'static, SSW: SetWriter + Send + Sync + 'static>
-    AddMatchScreen<MW, SSW>
+impl<
+        MW: MatchWriter + Send + Sync + 'static,
+        OR: OpponentReader + Send + Sync + 'static,
+        OW: OpponentWriter + Send + Sync + 'static,
+        SSW: SetWriter + Send + Sync + 'static,
+        TR: TeamReader + Send + Sync + 'static,
+        TW: TeamWriter + Send + Sync + 'static,
+    > AddMatchScreen<MW, OR, OW, SSW, TR, TW>
 {
     pub fn new(
         settings: Settings,
         team: TeamEntry,
         match_writer: Arc<MW>,
+        opponent_reader: Arc<OR>,
+        opponent_writer: Arc<OW>,
         set_writer: Arc<SSW>,
+        team_reader: Arc<TR>,
+        team_writer: Arc<TW>,
     ) -> Self {
         let opponent = TextBox::new(current_labels().opponent.to_owned(), true, None);
         let home = CheckBox::new(current_labels().home.to_owned(), false, false);
@@ -135,7 +177,11 @@ impl<MW: MatchWriter + Send + Sync + 'static, SSW: SetWriter + Send + Sync + 'st
             footer: NavigationFooter::new(),
             footer_entries,
             match_writer,
+            opponent_reader,
+            opponent_writer,
             set_writer,
+            team_reader,
+            team_writer,
             screen_key_bindings,
         }
     }
@@ -150,7 +196,9 @@ impl<MW: MatchWriter + Send + Sync + 'static, SSW: SetWriter + Send + Sync + 'st
     async fn handle_confirm(&mut self) -> AppAction {
         match (
             self.date.get_selected_value(),
-            self.opponent.get_selected_value(),
+            self.opponent
+                .get_selected_value()
+                .filter(|o| !o.trim().is_empty()),
         ) {
             (_, None) => {
                 self.notify_message
@@ -158,9 +206,24 @@ impl<MW: MatchWriter + Send + Sync + 'static, SSW: SetWriter + Send + Sync + 'st
                 AppAction::None
             }
             (Ok(date), Some(opponent)) => {
+                let opponent = match self.resolve_opponent(opponent).await {
+                    Ok(opponent) => opponent,
+                    Err(_) => {
+                        self.notify_message
+                            .set_error(current_labels().could_not_save_opponent.to_string());
+                        return AppAction::None;
+                    }
+                };
                 match self
                     .match_writer
-                    .create(&self.team, opponent, date, self.home.get_selected_value())
+                    .create(
+                        &self.team,
+                        opponent.name,
+                        Some(opponent.id),
+                        Some(self.team.year),
+                        date,
+                        self.home.get_selected_value(),
+                    )
                     .await
                 {
                     Ok(m) => AppAction::SwitchScreen(Box::new(StartSetScreen::new(
@@ -169,7 +232,10 @@ impl<MW: MatchWriter + Send + Sync + 'static, SSW: SetWriter + Send + Sync + 'st
                         1,
                         None,
                         Some(2),
+                        self.match_writer.clone(),
                         self.set_writer.clone(),
+                        self.team_reader.clone(),
+                        self.team_writer.clone(),
                     ))),
                     Err(_) => {
                         self.notify_message
@@ -186,6 +252,25 @@ impl<MW: MatchWriter + Send + Sync + 'static, SSW: SetWriter + Send + Sync + 'st
         }
     }
 
+    /// Looks the opponent up in the registry by name or alias, registering it when unknown.
+    async fn resolve_opponent(&self, name: String) -> Result<OpponentEntry, AppError> {
+        let opponents = self.opponent_reader.read_all().await?;
+        match opponents.into_iter().find(|o| o.matches(&name)) {
+            Some(opponent) => Ok(opponent),
+            None => {
+                self.opponent_writer
+                    .save(OpponentInput::New {
+                        name,
+                        aliases: vec![],
+                        classification: None,
+                        gender: None,
+                        notes: None,
+                    })
+                    .await
+            }
+        }
+    }
+
     fn handle_backspace(&mut self) -> AppAction {
         self.opponent.handle_backspace();
         self.date.handle_backspace();
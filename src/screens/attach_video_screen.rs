@@ -0,0 +1,71 @@
+use crate::{
+    errors::AppError,
+    providers::set_writer::SetWriter,
+    screens::{file_system_screen::FileSystemAction, screen::AppAction},
+    shapes::{r#match::MatchEntry, set::SetEntry, video::VideoEntry},
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use hf::is_hidden;
+use std::{path::Path, sync::Arc};
+
+const VIDEO_EXTENSIONS: [&str; 7] = ["mp4", "mov", "mkv", "avi", "m4v", "mts", "webm"];
+
+/// Links a video file to a set.
+pub struct AttachVideoAction<SSW: SetWriter + Send + Sync> {
+    current_match: MatchEntry,
+    set: SetEntry,
+    set_writer: Arc<SSW>,
+}
+
+impl<SSW: SetWriter + Send + Sync> AttachVideoAction<SSW> {
+    pub fn new(current_match: MatchEntry, set: SetEntry, set_writer: Arc<SSW>) -> Self {
+        Self {
+            current_match,
+            set,
+            set_writer,
+        }
+    }
+
+    fn is_video(path: &Path) -> bool {
+        path.extension()
+            .and_then(|s| s.to_str())
+            .map(|e| VIDEO_EXTENSIONS.iter().any(|v| e.eq_ignore_ascii_case(v)))
+            .unwrap_or(false)
+    }
+}
+
+#[async_trait]
+impl<SSW: SetWriter + Send + Sync> FileSystemAction for AttachVideoAction<SSW> {
+    fn is_selectable(&self, path: &Path) -> bool {
+        !is_hidden(path).unwrap_or_default() && Self::is_video(path)
+    }
+
+    fn is_visible(&self, path: &Path) -> bool {
+        !is_hidden(path).unwrap_or_default() && (path.is_dir() || Self::is_video(path))
+    }
+
+    fn success_message_suffix(&self) -> Option<String> {
+        self.set
+            .video
+            .as_ref()
+            .map(|v| v.file.display().to_string())
+    }
+
+    async fn on_selected(&mut self, path: &Path) -> Result<AppAction, AppError> {
+        // keep the synchronization when the same recording is moved or re-encoded; until the
+        // video is synced, assume the recording started with the first event of the set
+        let started_at = self
+            .set
+            .video
+            .as_ref()
+            .map(|v| v.started_at)
+            .or_else(|| self.set.events.first().map(|e| e.timestamp))
+            .unwrap_or_else(Utc::now);
+        self.set.video = Some(VideoEntry::new(path.to_path_buf(), started_at));
+        self.set_writer
+            .update(&self.current_match, &self.set)
+            .await?;
+        Ok(AppAction::Back(true, Some(1)))
+    }
+}
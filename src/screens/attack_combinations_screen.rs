@@ -0,0 +1,301 @@
+use std::sync::Arc;
+
+use crate::{
+    localization::current_labels,
+    providers::{
+        team_reader::TeamReader,
+        team_writer::{TeamInput, TeamWriter},
+    },
+    screens::{
+        components::{
+            navigation_footer::NavigationFooter, notify_dialogue::NotifyDialogue,
+            team_header::TeamHeader,
+        },
+        edit_attack_combination_screen::EditAttackCombinationScreen,
+        screen::{get_keybinding_actions, AppAction, Renderable, Sba, ScreenAsync},
+    },
+    shapes::{
+        enums::{FriendlyName, ScreenActionEnum},
+        keybinding::{KeyBindings, ScreenKeyBindings},
+        settings::Settings,
+        team::{AttackCombinationEntry, TeamEntry},
+    },
+};
+use async_trait::async_trait;
+use crokey::crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, ListState, Paragraph, Row, Table},
+    Frame,
+};
+
+/// The team's catalogue of attack combination codes.
+#[derive(Debug)]
+pub struct AttackCombinationsScreen<TR: TeamReader + Send + Sync, TW: TeamWriter + Send + Sync> {
+    settings: Settings,
+    list_state: ListState,
+    team: TeamEntry,
+    notifier: NotifyDialogue<AttackCombinationEntry>,
+    header: TeamHeader,
+    footer: NavigationFooter,
+    team_reader: Arc<TR>,
+    team_writer: Arc<TW>,
+    screen_key_bindings: ScreenKeyBindings,
+}
+
+impl<TR: TeamReader + Send + Sync + 'static, TW: TeamWriter + Send + Sync + 'static> Renderable
+    for AttackCombinationsScreen<TR, TW>
+{
+    fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
+        let container = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(5), Constraint::Min(1)])
+            .split(body);
+        self.header.render(f, container[0], Some(&self.team));
+        if self.team.combinations.is_empty() {
+            self.render_no_combinations_yet(f, container[1]);
+        } else {
+            self.render_combinations(f, container[1]);
+        }
+        self.notifier.render(f, footer_right);
+        let kb: &KeyBindings = &self.settings.keybindings;
+        let screen_actions = &self.screen_actions();
+        let footer_entries = get_keybinding_actions(kb, screen_actions);
+        self.footer.render(f, footer_left, footer_entries);
+        self.screen_key_bindings = kb.slice(Sba::keys(screen_actions));
+    }
+}
+
+#[async_trait]
+impl<TR: TeamReader + Send + Sync + 'static, TW: TeamWriter + Send + Sync + 'static> ScreenAsync
+    for AttackCombinationsScreen<TR, TW>
+{
+    async fn handle_key(&mut self, key: KeyEvent) -> AppAction {
+        if let Some(key_combination) = self.screen_key_bindings.transform(key) {
+            match (
+                self.screen_key_bindings.get(key_combination),
+                key.code,
+                &self.notifier.banner.has_value(),
+                &self.notifier.has_value(),
+            ) {
+                (_, _, true, false) => {
+                    self.notifier.banner.reset();
+                    AppAction::None
+                }
+                // dialog exits (y|n) have higher priority
+                (_, KeyCode::Char(x), _, true) => {
+                    let selected = self.notifier.entry.to_owned();
+                    self.notifier.reset();
+                    match selected {
+                        Some(combination) if x == *current_labels().y => {
+                            self.remove(combination).await
+                        }
+                        _ => AppAction::None,
+                    }
+                }
+                (Some(ScreenActionEnum::Next), _, _, _) => self.next_combination(),
+                (Some(ScreenActionEnum::Previous), _, _, _) => self.previous_combination(),
+                (Some(ScreenActionEnum::New), _, _, _) => {
+                    AppAction::SwitchScreen(Box::new(EditAttackCombinationScreen::new(
+                        self.settings.clone(),
+                        self.team.clone(),
+                        self.team_writer.clone(),
+                    )))
+                }
+                (Some(ScreenActionEnum::Edit), _, _, _) => match self.get_selected_combination() {
+                    Some(combination) => {
+                        AppAction::SwitchScreen(Box::new(EditAttackCombinationScreen::edit(
+                            self.settings.clone(),
+                            self.team.clone(),
+                            combination.clone(),
+                            self.team_writer.clone(),
+                        )))
+                    }
+                    None => AppAction::None,
+                },
+                (Some(ScreenActionEnum::Delete), _, _, _) => {
+                    if let Some(combination) = self.get_selected_combination().cloned() {
+                        let message = current_labels()
+                            .remove_attack_combination_confirmation
+                            .replace("{}", &combination.code);
+                        self.notifier.set(combination).banner.set_warning(message);
+                    }
+                    AppAction::None
+                }
+                (Some(ScreenActionEnum::Back), _, _, _) => AppAction::Back(true, Some(1)),
+                (Some(ScreenActionEnum::Quit), _, _, _) => AppAction::Quit(Ok(())),
+                _ => AppAction::None,
+            }
+        } else {
+            AppAction::None
+        }
+    }
+
+    async fn refresh_data(&mut self) {
+        match self.team_reader.read_single(&self.team.id).await {
+            Ok(team) => {
+                self.team = team;
+                let len = self.team.combinations.len();
+                match self.list_state.selected() {
+                    _ if len == 0 => self.list_state.select(None),
+                    Some(selected) if selected >= len => self.list_state.select(Some(len - 1)),
+                    None => self.list_state.select(Some(0)),
+                    _ => {}
+                }
+            }
+            Err(e) => {
+                self.notifier.banner.set_error(format!(
+                    "{}: {}",
+                    current_labels().could_not_load_teams,
+                    e
+                ));
+            }
+        }
+    }
+}
+
+impl<TR: TeamReader + Send + Sync + 'static, TW: TeamWriter + Send + Sync + 'static>
+    AttackCombinationsScreen<TR, TW>
+{
+    pub fn new(
+        settings: Settings,
+        team: TeamEntry,
+        team_reader: Arc<TR>,
+        team_writer: Arc<TW>,
+    ) -> Self {
+        let mut list_state = ListState::default();
+        if !team.combinations.is_empty() {
+            list_state.select(Some(0));
+        }
+        AttackCombinationsScreen {
+            settings,
+            list_state,
+            team,
+            notifier: NotifyDialogue::new(),
+            header: TeamHeader::default(),
+            footer: NavigationFooter::new(),
+            team_reader,
+            team_writer,
+            screen_key_bindings: ScreenKeyBindings::empty(),
+        }
+    }
+
+    fn get_selected_combination(&self) -> Option<&AttackCombinationEntry> {
+        self.list_state
+            .selected()
+            .and_then(|i| self.team.combinations.get(i))
+    }
+
+    fn next_combination(&mut self) -> AppAction {
+        if let Some(selected) = self.list_state.selected() {
+            let new_selected = (selected + 1).min(self.team.combinations.len() - 1);
+            self.list_state.select(Some(new_selected));
+        };
+        AppAction::None
+    }
+
+    fn previous_combination(&mut self) -> AppAction {
+        if let Some(selected) = self.list_state.selected() {
+            let new_selected = if selected == 0 { 0 } else { selected - 1 };
+            self.list_state.select(Some(new_selected));
+        };
+        AppAction::None
+    }
+
+    async fn remove(&mut self, combination: AttackCombinationEntry) -> AppAction {
+        let mut team = self.team.clone();
+        team.combinations.retain(|c| c.code != combination.code);
+        match self.team_writer.save(TeamInput::Existing(team)).await {
+            Ok(_) => {
+                self.notifier
+                    .banner
+                    .set_info(current_labels().operation_successful.to_string());
+                self.refresh_data().await;
+            }
+            Err(_) => {
+                self.notifier
+                    .banner
+                    .set_error(current_labels().could_not_save_team.to_string());
+            }
+        }
+        AppAction::None
+    }
+
+    fn screen_actions(&self) -> Vec<Sba> {
+        let mut actions = Vec::new();
+        if !self.team.combinations.is_empty() {
+            actions.push(Sba::Simple(ScreenActionEnum::Next));
+            actions.push(Sba::Simple(ScreenActionEnum::Previous));
+            actions.push(Sba::Simple(ScreenActionEnum::Edit));
+            actions.push(Sba::Simple(ScreenActionEnum::Delete));
+        }
+        actions.push(Sba::Simple(ScreenActionEnum::New));
+        actions.push(Sba::Simple(ScreenActionEnum::Back));
+        actions.push(Sba::Simple(ScreenActionEnum::Quit));
+        actions
+    }
+
+    fn render_combinations(&self, f: &mut Frame, area: Rect) {
+        let labels = current_labels();
+        let selected = self.list_state.selected();
+        let rows: Vec<Row> = self
+            .team
+            .combinations
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let row = Row::new(vec![
+                    c.code.clone(),
+                    c.tempo.friendly_name(labels).to_string(),
+                    c.zone.to_string(),
+                    c.role.friendly_name(labels).to_string(),
+                ]);
+                if selected == Some(i) {
+                    row.style(Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD))
+                } else {
+                    row
+                }
+            })
+            .collect();
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(10),
+                Constraint::Length(12),
+                Constraint::Length(8),
+                Constraint::Length(25),
+            ],
+        )
+        .header(
+            Row::new(vec![
+                labels.code,
+                labels.tempo,
+                labels.zone,
+                labels.expected_role,
+            ])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(labels.attack_combinations),
+        );
+        f.render_widget(table, area);
+    }
+
+    fn render_no_combinations_yet(&self, f: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(40),
+                Constraint::Length(3),
+                Constraint::Percentage(40),
+            ])
+            .split(area);
+        let paragraph = Paragraph::new(current_labels().no_attack_combinations_yet)
+            .block(Block::default().borders(Borders::NONE))
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, chunks[1]);
+    }
+}
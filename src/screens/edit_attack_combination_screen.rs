@@ -0,0 +1,287 @@
+use std::sync::Arc;
+
+use crate::{
+    localization::current_labels,
+    providers::team_writer::{TeamInput, TeamWriter},
+    screens::{
+        components::{
+            navigation_footer::NavigationFooter, notify_banner::NotifyBanner, select::Select,
+            text_box::TextBox,
+        },
+        screen::{get_keybinding_actions, AppAction, Renderable, Sba, ScreenAsync},
+    },
+    shapes::{
+        enums::{RoleEnum, ScreenActionEnum, TempoEnum, ZoneEnum},
+        keybinding::ScreenKeyBindings,
+        settings::Settings,
+        team::{AttackCombinationEntry, TeamEntry},
+    },
+};
+use async_trait::async_trait;
+use crokey::crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::{Block, Borders},
+    Frame,
+};
+
+#[derive(Debug)]
+pub struct EditAttackCombinationScreen<TW: TeamWriter + Send + Sync> {
+    code: TextBox,
+    tempo: Select<TempoEnum>,
+    zone: Select<ZoneEnum>,
+    role: Select<Option<RoleEnum>>,
+    field: usize,
+    notify_message: NotifyBanner,
+    team: TeamEntry,
+    existing_combination: Option<AttackCombinationEntry>,
+    back: bool,
+    footer: NavigationFooter,
+    footer_entries: Vec<(String, String)>,
+    screen_key_bindings: ScreenKeyBindings,
+    team_writer: Arc<TW>,
+}
+
+impl<TW: TeamWriter + Send + Sync> Renderable for EditAttackCombinationScreen<TW> {
+    fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
+        let area = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3), // 0: code
+                Constraint::Length(3), // 1: tempo
+                Constraint::Length(3), // 2: zone
+                Constraint::Length(3), // 3: expected attacker role
+                Constraint::Min(1),
+            ])
+            .split(body);
+        self.notify_message.render(f, footer_right);
+        self.render_header(f, body);
+        self.code.render(f, area[0]);
+        self.tempo.render(f, area[1]);
+        self.zone.render(f, area[2]);
+        self.role.render(f, area[3]);
+        self.footer
+            .render(f, footer_left, self.footer_entries.clone());
+    }
+}
+
+#[async_trait]
+impl<TW: TeamWriter + Send + Sync> ScreenAsync for EditAttackCombinationScreen<TW> {
+    async fn handle_key(&mut self, key: KeyEvent) -> AppAction {
+        if let Some(key_combination) = self.screen_key_bindings.transform(key) {
+            match (
+                self.screen_key_bindings.get(key_combination),
+                key.code,
+                &self.notify_message.has_value(),
+            ) {
+                (_, _, true) => self.handle_error_reset(),
+                (_, KeyCode::Char(c), _) => self.handle_char(c),
+                (_, KeyCode::Backspace, _) => self.handle_backspace(),
+                (_, KeyCode::Up, _) => self.handle_up(),
+                (_, KeyCode::Down, _) => self.handle_down(),
+                (Some(ScreenActionEnum::Next), _, _) => self.handle_tab(),
+                (Some(ScreenActionEnum::Previous), _, _) => self.handle_backtab(),
+                (Some(ScreenActionEnum::Back), _, _) => AppAction::Back(true, Some(1)),
+                (Some(ScreenActionEnum::Confirm), _, _) => self.handle_enter().await,
+                _ => AppAction::None,
+            }
+        } else {
+            AppAction::None
+        }
+    }
+
+    async fn refresh_data(&mut self) {}
+}
+
+impl<TW: TeamWriter + Send + Sync> EditAttackCombinationScreen<TW> {
+    pub fn new(settings: Settings, team: TeamEntry, team_writer: Arc<TW>) -> Self {
+        Self::build(settings, team, None, team_writer)
+    }
+
+    pub fn edit(
+        settings: Settings,
+        team: TeamEntry,
+        combination: AttackCombinationEntry,
+        team_writer: Arc<TW>,
+    ) -> Self {
+        Self::build(settings, team, Some(combination), team_writer)
+    }
+
+    fn build(
+        settings: Settings,
+        team: TeamEntry,
+        existing_combination: Option<AttackCombinationEntry>,
+        team_writer: Arc<TW>,
+    ) -> Self {
+        let code = TextBox::with_validator(
+            current_labels().code.to_owned(),
+            true,
+            existing_combination.as_ref().map(|c| c.code.as_str()),
+            |current: &str, c: char| current.len() < 4 && c.is_ascii_alphanumeric(),
+        );
+        let tempo = Select::new(
+            current_labels().tempo.to_owned(),
+            TempoEnum::ALL.to_vec(),
+            existing_combination.as_ref().map(|c| c.tempo),
+            false,
+        );
+        let zone = Select::new(
+            current_labels().zone.to_owned(),
+            ZoneEnum::ALL.to_vec(),
+            existing_combination.as_ref().map(|c| c.zone),
+            false,
+        );
+        let role = Select::new(
+            current_labels().expected_role.to_owned(),
+            std::iter::once(None)
+                .chain(RoleEnum::ALL.into_iter().map(Some))
+                .collect(),
+            existing_combination.as_ref().map(|c| c.role),
+            false,
+        );
+
+        let actions = &[
+            Sba::Simple(ScreenActionEnum::Next),
+            Sba::Simple(ScreenActionEnum::Previous),
+            Sba::Simple(ScreenActionEnum::Confirm),
+            Sba::Simple(ScreenActionEnum::Back),
+        ];
+
+        let kb = &settings.keybindings.clone();
+        let footer_entries = get_keybinding_actions(kb, actions);
+        let screen_key_bindings = kb.slice(Sba::keys(actions));
+
+        EditAttackCombinationScreen {
+            code,
+            tempo,
+            zone,
+            role,
+            field: 0,
+            notify_message: NotifyBanner::new(),
+            team,
+            existing_combination,
+            back: false,
+            footer: NavigationFooter::new(),
+            footer_entries,
+            screen_key_bindings,
+            team_writer,
+        }
+    }
+
+    fn handle_error_reset(&mut self) -> AppAction {
+        self.notify_message.reset();
+        if self.back {
+            AppAction::Back(true, Some(1))
+        } else {
+            AppAction::None
+        }
+    }
+
+    fn handle_tab(&mut self) -> AppAction {
+        self.field = (self.field + 1) % 4;
+        self.update_writing_modes();
+        AppAction::None
+    }
+
+    fn handle_backtab(&mut self) -> AppAction {
+        self.field = (self.field + 3) % 4;
+        self.update_writing_modes();
+        AppAction::None
+    }
+
+    fn update_writing_modes(&mut self) {
+        self.code.writing_mode = self.field == 0;
+        self.tempo.writing_mode = self.field == 1;
+        self.zone.writing_mode = self.field == 2;
+        self.role.writing_mode = self.field == 3;
+    }
+
+    fn handle_up(&mut self) -> AppAction {
+        self.tempo.handle_up();
+        self.zone.handle_up();
+        self.role.handle_up();
+        AppAction::None
+    }
+
+    fn handle_down(&mut self) -> AppAction {
+        self.tempo.handle_down();
+        self.zone.handle_down();
+        self.role.handle_down();
+        AppAction::None
+    }
+
+    fn handle_backspace(&mut self) -> AppAction {
+        self.code.handle_backspace();
+        AppAction::None
+    }
+
+    fn handle_char(&mut self, c: char) -> AppAction {
+        self.code.handle_char(c);
+        AppAction::None
+    }
+
+    async fn handle_enter(&mut self) -> AppAction {
+        let Some(code) = self
+            .code
+            .get_selected_value()
+            .map(|c| c.trim().to_uppercase())
+            .filter(|c| !c.is_empty())
+        else {
+            self.notify_message
+                .set_error(current_labels().code_cannot_be_empty.to_string());
+            return AppAction::None;
+        };
+        let previous_code = self.existing_combination.as_ref().map(|c| c.code.as_str());
+        if self
+            .team
+            .find_combination(&code)
+            .is_some_and(|c| Some(c.code.as_str()) != previous_code)
+        {
+            self.notify_message
+                .set_error(current_labels().code_already_in_use.to_string());
+            return AppAction::None;
+        }
+        let (Some(tempo), Some(zone), Some(role)) = (
+            self.tempo.get_selected_value(),
+            self.zone.get_selected_value(),
+            self.role.get_selected_value(),
+        ) else {
+            return AppAction::None;
+        };
+        let combination = AttackCombinationEntry {
+            code,
+            tempo,
+            zone,
+            role,
+        };
+        let mut team = self.team.clone();
+        match previous_code.and_then(|p| team.combinations.iter().position(|c| c.code == p)) {
+            Some(index) => team.combinations[index] = combination,
+            None => team.combinations.push(combination),
+        }
+        match self.team_writer.save(TeamInput::Existing(team)).await {
+            Ok(_) => {
+                self.notify_message
+                    .set_info(current_labels().operation_successful.to_string());
+                self.back = true;
+                AppAction::None
+            }
+            Err(_) => {
+                self.notify_message
+                    .set_error(current_labels().could_not_save_team.to_string());
+                AppAction::None
+            }
+        }
+    }
+
+    fn render_header(&self, f: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(match self.existing_combination {
+                Some(_) => current_labels().edit_attack_combination,
+                None => current_labels().new_attack_combination,
+            });
+        f.render_widget(block, area);
+    }
+}
@@ -0,0 +1,318 @@
+use std::sync::Arc;
+
+use crate::{
+    localization::current_labels,
+    providers::opponent_writer::{OpponentInput, OpponentWriter},
+    screens::{
+        components::{
+            navigation_footer::NavigationFooter, notify_banner::NotifyBanner, select::Select,
+            text_box::TextBox,
+        },
+        screen::{get_keybinding_actions, AppAction, Renderable, Sba, ScreenAsync},
+    },
+    shapes::{
+        enums::{GenderEnum, ScreenActionEnum, TeamClassificationEnum},
+        keybinding::ScreenKeyBindings,
+        opponent::OpponentEntry,
+        settings::Settings,
+    },
+};
+use async_trait::async_trait;
+use crokey::crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::{Block, Borders},
+    Frame,
+};
+
+#[derive(Debug)]
+pub struct EditOpponentScreen<OW: OpponentWriter + Send + Sync> {
+    name: TextBox,
+    aliases: TextBox,
+    classification: Select<TeamClassificationEnum>,
+    gender: Select<GenderEnum>,
+    notes: TextBox,
+    field: usize,
+    notify_message: NotifyBanner,
+    existing_opponent: Option<OpponentEntry>,
+    opponents: Vec<OpponentEntry>,
+    back: bool,
+    footer: NavigationFooter,
+    footer_entries: Vec<(String, String)>,
+    screen_key_bindings: ScreenKeyBindings,
+    opponent_writer: Arc<OW>,
+}
+
+impl<OW: OpponentWriter + Send + Sync> Renderable for EditOpponentScreen<OW> {
+    fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
+        let area = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3), // 0: name
+                Constraint::Length(3), // 1: aliases
+                Constraint::Length(3), // 2: classification
+                Constraint::Length(3), // 3: gender
+                Constraint::Length(5), // 4: notes
+                Constraint::Min(1),
+            ])
+            .split(body);
+        self.notify_message.render(f, footer_right);
+        self.render_header(f, body);
+        self.name.render(f, area[0]);
+        self.aliases.render(f, area[1]);
+        self.classification.render(f, area[2]);
+        self.gender.render(f, area[3]);
+        self.notes.render(f, area[4]);
+        self.footer
+            .render(f, footer_left, self.footer_entries.clone());
+    }
+}
+
+#[async_trait]
+impl<OW: OpponentWriter + Send + Sync> ScreenAsync for EditOpponentScreen<OW> {
+    async fn handle_key(&mut self, key: KeyEvent) -> AppAction {
+        if let Some(key_combination) = self.screen_key_bindings.transform(key) {
+            match (
+                self.screen_key_bindings.get(key_combination),
+                key.code,
+                &self.notify_message.has_value(),
+            ) {
+                (_, _, true) => self.handle_error_reset(),
+                (_, KeyCode::Char(c), _) => self.handle_char(c),
+                (_, KeyCode::Backspace, _) => self.handle_backspace(),
+                (_, KeyCode::Up, _) => self.handle_up(),
+                (_, KeyCode::Down, _) => self.handle_down(),
+                (Some(ScreenActionEnum::Next), _, _) => self.handle_tab(),
+                (Some(ScreenActionEnum::Previous), _, _) => self.handle_backtab(),
+                (Some(ScreenActionEnum::Back), _, _) => AppAction::Back(true, Some(1)),
+                (Some(ScreenActionEnum::Confirm), _, _) => self.handle_enter().await,
+                _ => AppAction::None,
+            }
+        } else {
+            AppAction::None
+        }
+    }
+
+    async fn refresh_data(&mut self) {}
+}
+
+impl<OW: OpponentWriter + Send + Sync> EditOpponentScreen<OW> {
+    pub fn new(
+        settings: Settings,
+        opponents: Vec<OpponentEntry>,
+        opponent_writer: Arc<OW>,
+    ) -> Self {
+        Self::build(settings, None, opponents, opponent_writer)
+    }
+
+    pub fn edit(
+        settings: Settings,
+        opponent: &OpponentEntry,
+        opponents: Vec<OpponentEntry>,
+        opponent_writer: Arc<OW>,
+    ) -> Self {
+        Self::build(settings, Some(opponent.clone()), opponents, opponent_writer)
+    }
+
+    fn build(
+        settings: Settings,
+        existing_opponent: Option<OpponentEntry>,
+        opponents: Vec<OpponentEntry>,
+        opponent_writer: Arc<OW>,
+    ) -> Self {
+        let name = TextBox::new(
+            current_labels().name.to_owned(),
+            true,
+            existing_opponent.as_ref().map(|o| o.name.as_str()),
+        );
+        let aliases = TextBox::new(
+            current_labels().aliases.to_owned(),
+            false,
+            existing_opponent
+                .as_ref()
+                .map(|o| o.aliases.join(", "))
+                .as_deref(),
+        );
+        let classification = Select::new(
+            current_labels().team_classification.to_owned(),
+            TeamClassificationEnum::ALL.to_vec(),
+            existing_opponent.as_ref().and_then(|o| o.classification),
+            false,
+        );
+        let gender = Select::new(
+            current_labels().gender.to_owned(),
+            GenderEnum::ALL.to_vec(),
+            existing_opponent.as_ref().and_then(|o| o.gender),
+            false,
+        );
+        let notes = TextBox::new(
+            current_labels().notes.to_owned(),
+            false,
+            existing_opponent.as_ref().and_then(|o| o.notes.as_deref()),
+        )
+        .enable_multiline(true);
+
+        let actions = &[
+            Sba::Simple(ScreenActionEnum::Next),
+            Sba::Simple(ScreenActionEnum::Previous),
+            Sba::Simple(ScreenActionEnum::Confirm),
+            Sba::Simple(ScreenActionEnum::Back),
+        ];
+
+        let kb = &settings.keybindings.clone();
+        let footer_entries = get_keybinding_actions(kb, actions);
+        let screen_key_bindings = kb.slice(Sba::keys(actions));
+
+        EditOpponentScreen {
+            name,
+            aliases,
+            classification,
+            gender,
+            notes,
+            field: 0,
+            notify_message: NotifyBanner::new(),
+            existing_opponent,
+            opponents,
+            back: false,
+            footer: NavigationFooter::new(),
+            footer_entries,
+            screen_key_bindings,
+            opponent_writer,
+        }
+    }
+
+    fn handle_error_reset(&mut self) -> AppAction {
+        self.notify_message.reset();
+        if self.back {
+            AppAction::Back(true, Some(1))
+        } else {
+            AppAction::None
+        }
+    }
+
+    fn handle_tab(&mut self) -> AppAction {
+        self.field = (self.field + 1) % 5;
+        self.update_writing_modes();
+        AppAction::None
+    }
+
+    fn handle_backtab(&mut self) -> AppAction {
+        self.field = (self.field + 4) % 5;
+        self.update_writing_modes();
+        AppAction::None
+    }
+
+    fn update_writing_modes(&mut self) {
+        self.name.writing_mode = self.field == 0;
+        self.aliases.writing_mode = self.field == 1;
+        self.classification.writing_mode = self.field == 2;
+        self.gender.writing_mode = self.field == 3;
+        self.notes.writing_mode = self.field == 4;
+    }
+
+    fn handle_up(&mut self) -> AppAction {
+        self.classification.handle_up();
+        self.gender.handle_up();
+        AppAction::None
+    }
+
+    fn handle_down(&mut self) -> AppAction {
+        self.classification.handle_down();
+        self.gender.handle_down();
+        AppAction::None
+    }
+
+    fn handle_backspace(&mut self) -> AppAction {
+        self.name.handle_backspace();
+        self.aliases.handle_backspace();
+        self.notes.handle_backspace();
+        AppAction::None
+    }
+
+    fn handle_char(&mut self, c: char) -> AppAction {
+        self.name.handle_char(c);
+        self.aliases.handle_char(c);
+        self.notes.handle_char(c);
+        AppAction::None
+    }
+
+    /// Whether the name or one of the aliases already identifies a different opponent.
+    fn is_name_in_use(&self, names: &[&String]) -> bool {
+        let current_id = self.existing_opponent.as_ref().map(|o| o.id);
+        self.opponents
+            .iter()
+            .filter(|o| Some(o.id) != current_id)
+            .any(|o| names.iter().any(|n| o.matches(n)))
+    }
+
+    async fn handle_enter(&mut self) -> AppAction {
+        let Some(name) = self
+            .name
+            .get_selected_value()
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty())
+        else {
+            self.notify_message
+                .set_error(current_labels().name_cannot_be_empty.to_string());
+            return AppAction::None;
+        };
+        let aliases: Vec<String> = self
+            .aliases
+            .get_selected_value()
+            .unwrap_or_default()
+            .split(',')
+            .map(|a| a.trim().to_string())
+            .filter(|a| !a.is_empty())
+            .collect();
+        let names: Vec<&String> = std::iter::once(&name).chain(aliases.iter()).collect();
+        if self.is_name_in_use(&names) {
+            self.notify_message
+                .set_error(current_labels().opponent_name_already_in_use.to_string());
+            return AppAction::None;
+        }
+        let classification = self.classification.get_selected_value();
+        let gender = self.gender.get_selected_value();
+        let notes = self.notes.get_selected_value().filter(|n| !n.is_empty());
+        let input = match &self.existing_opponent {
+            Some(opponent) => OpponentInput::Existing(OpponentEntry {
+                name,
+                aliases,
+                classification,
+                gender,
+                notes,
+                ..opponent.clone()
+            }),
+            None => OpponentInput::New {
+                name,
+                aliases,
+                classification,
+                gender,
+                notes,
+            },
+        };
+        match self.opponent_writer.save(input).await {
+            Ok(_) => {
+                self.notify_message
+                    .set_info(current_labels().operation_successful.to_string());
+                self.back = true;
+                AppAction::None
+            }
+            Err(_) => {
+                self.notify_message
+                    .set_error(current_labels().could_not_save_opponent.to_string());
+                AppAction::None
+            }
+        }
+    }
+
+    fn render_header(&self, f: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(match self.existing_opponent {
+                Some(_) => current_labels().edit_opponent,
+                None => current_labels().new_opponent,
+            });
+        f.render_widget(block, area);
+    }
+}
@@ -5,13 +5,13 @@ use crate::{
     providers::team_writer::{PlayerInput, TeamWriter},
     screens::{
         components::{
-            navigation_footer::NavigationFooter, notify_banner::NotifyBanner, select::Select,
-            team_header::TeamHeader, text_box::TextBox,
+            checkbox::CheckBox, navigation_footer::NavigationFooter, notify_banner::NotifyBanner,
+            select::Select, team_header::TeamHeader, text_box::TextBox,
         },
         screen::{get_keybinding_actions, AppAction, Renderable, Sba, ScreenAsync},
     },
     shapes::{
-        enums::{RoleEnum, ScreenActionEnum},
+        enums::{HandEnum, RoleEnum, ScreenActionEnum, ServeTypeEnum},
         keybinding::ScreenKeyBindings,
         player::PlayerEntry,
         settings::Settings,
@@ -19,6 +19,7 @@ use crate::{
     },
 };
 use async_trait::async_trait;
+use chrono::NaiveDate;
 use crokey::crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -30,11 +31,25 @@ fn validate_player_number(current: &str, c: char) -> bool {
     c.is_ascii_digit() && current.len() < 2 && !(current.is_empty() && c == '0')
 }
 
+fn validate_birth_date(current: &str, c: char) -> bool {
+    (c.is_ascii_digit() || c == '-') && current.len() < 10
+}
+
+fn validate_height(current: &str, c: char) -> bool {
+    c.is_ascii_digit() && current.len() < 3 && !(current.is_empty() && c == '0')
+}
+
 pub struct EditPlayerScreen<TW: TeamWriter + Send + Sync> {
     team: TeamEntry,
     name: TextBox,
     number: TextBox,
     role: Select<RoleEnum>,
+    secondary_role: Select<Option<RoleEnum>>,
+    birth_date: TextBox,
+    height: TextBox,
+    dominant_hand: Select<Option<HandEnum>>,
+    serve_type: Select<Option<ServeTypeEnum>>,
+    captain: CheckBox,
     field: usize,
     notify_message: NotifyBanner,
     existing_player: Option<PlayerEntry>,
@@ -59,6 +74,12 @@ impl<TW: TeamWriter + Send + Sync> Renderable for EditPlayerScreen<TW> {
                 Constraint::Length(3), // name
                 Constraint::Length(3), // role
                 Constraint::Length(3), // number
+                Constraint::Length(3), // secondary role
+                Constraint::Length(3), // birth date
+                Constraint::Length(3), // height
+                Constraint::Length(3), // dominant hand
+                Constraint::Length(3), // serve type
+                Constraint::Length(3), // captain
                 Constraint::Min(1),
             ])
             .split(container[1]);
@@ -67,6 +88,12 @@ impl<TW: TeamWriter + Send + Sync> Renderable for EditPlayerScreen<TW> {
         self.name.render(f, area[0]);
         self.role.render(f, area[1]);
         self.number.render(f, area[2]);
+        self.secondary_role.render(f, area[3]);
+        self.birth_date.render(f, area[4]);
+        self.height.render(f, area[5]);
+        self.dominant_hand.render(f, area[6]);
+        self.serve_type.render(f, area[7]);
+        self.captain.render(f, area[8]);
         self.header.render(f, container[0], Some(&self.team));
         self.footer
             .render(f, footer_left, self.footer_entries.clone());
@@ -103,45 +130,7 @@ impl<TW: TeamWriter + Send + Sync> ScreenAsync for EditPlayerScreen<TW> {
 
 impl<TW: TeamWriter + Send + Sync> EditPlayerScreen<TW> {
     pub fn new(settings: Settings, team: TeamEntry, team_writer: Arc<TW>) -> Self {
-        let role = Select::new(
-            current_labels().role.to_owned(),
-            RoleEnum::ALL.to_vec(),
-            None,
-            false,
-        );
-        let name = TextBox::new(current_labels().name.to_owned(), true, None);
-        let number = TextBox::with_validator(
-            current_labels().number.to_owned(),
-            false,
-            None,
-            validate_player_number,
-        );
-
-        let actions = &[
-            Sba::Simple(ScreenActionEnum::Next),
-            Sba::Simple(ScreenActionEnum::Previous),
-            Sba::Simple(ScreenActionEnum::Confirm),
-            Sba::Simple(ScreenActionEnum::Back),
-        ];
-        let kb = &settings.keybindings.clone();
-        let footer_entries = get_keybinding_actions(kb, actions);
-        let screen_key_bindings = kb.slice(Sba::keys(actions));
-
-        EditPlayerScreen {
-            team,
-            name,
-            number,
-            role,
-            field: 0,
-            notify_message: NotifyBanner::new(),
-            existing_player: None,
-            back: false,
-            header: TeamHeader::default(),
-            footer: NavigationFooter::new(),
-            footer_entries,
-            team_writer,
-            screen_key_bindings,
-        }
+        Self::build(settings, team, None, team_writer)
     }
 
     pub fn edit(
@@ -150,19 +139,80 @@ impl<TW: TeamWriter + Send + Sync> EditPlayerScreen<TW> {
         player: PlayerEntry,
         team_writer: Arc<TW>,
     ) -> Self {
+        Self::build(settings, team, Some(player), team_writer)
+    }
+
+    fn build(
+        settings: Settings,
+        team: TeamEntry,
+        existing_player: Option<PlayerEntry>,
+        team_writer: Arc<TW>,
+    ) -> Self {
+        let player = existing_player.as_ref();
         let role = Select::new(
             current_labels().role.to_owned(),
             RoleEnum::ALL.to_vec(),
-            player.role,
+            player.and_then(|p| p.role),
             false,
         );
-        let name = TextBox::new(current_labels().name.to_owned(), true, Some(&player.name));
+        let name = TextBox::new(
+            current_labels().name.to_owned(),
+            true,
+            player.map(|p| p.name.as_str()),
+        );
         let number = TextBox::with_validator(
             current_labels().number.to_owned(),
             false,
-            Some(&player.number.to_string()),
+            player.map(|p| p.number.to_string()).as_deref(),
             validate_player_number,
         );
+        let secondary_role = Select::new(
+            current_labels().secondary_role.to_owned(),
+            std::iter::once(None)
+                .chain(RoleEnum::ALL.into_iter().map(Some))
+                .collect(),
+            player.map(|p| p.secondary_role),
+            false,
+        );
+        let birth_date = TextBox::with_validator(
+            format!("{} (yyyy-mm-dd)", current_labels().birth_date),
+            false,
+            player
+                .and_then(|p| p.birth_date)
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .as_deref(),
+            validate_birth_date,
+        );
+        let height = TextBox::with_validator(
+            current_labels().height_cm.to_owned(),
+            false,
+            player
+                .and_then(|p| p.height)
+                .map(|h| h.to_string())
+                .as_deref(),
+            validate_height,
+        );
+        let dominant_hand = Select::new(
+            current_labels().dominant_hand.to_owned(),
+            std::iter::once(None)
+                .chain(HandEnum::ALL.into_iter().map(Some))
+                .collect(),
+            player.map(|p| p.dominant_hand),
+            false,
+        );
+        let serve_type = Select::new(
+            current_labels().serve_type.to_owned(),
+            std::iter::once(None)
+                .chain(ServeTypeEnum::ALL.into_iter().map(Some))
+                .collect(),
+            player.map(|p| p.serve_type),
+            false,
+        );
+        let captain = CheckBox::new(
+            current_labels().captain.to_owned(),
+            false,
+            player.is_some_and(|p| p.captain),
+        );
 
         let actions = &[
             Sba::Simple(ScreenActionEnum::Next),
@@ -179,9 +229,15 @@ impl<TW: TeamWriter + Send + Sync> EditPlayerScreen<TW> {
             name,
             number,
             role,
+            secondary_role,
+            birth_date,
+            height,
+            dominant_hand,
+            serve_type,
+            captain,
             field: 0,
             notify_message: NotifyBanner::new(),
-            existing_player: Some(player),
+            existing_player,
             back: false,
             header: TeamHeader::default(),
             footer: NavigationFooter::new(),
@@ -203,6 +259,9 @@ impl<TW: TeamWriter + Send + Sync> EditPlayerScreen<TW> {
     fn handle_char(&mut self, c: char) -> AppAction {
         self.name.handle_char(c);
         self.number.handle_char(c);
+        self.birth_date.handle_char(c);
+        self.height.handle_char(c);
+        self.captain.handle_char(c);
         AppAction::None
     }
 
@@ -216,6 +275,29 @@ impl<TW: TeamWriter + Send + Sync> EditPlayerScreen<TW> {
     }
 
     async fn handle_confirm(&mut self) -> AppAction {
+        let birth_date = match self
+            .birth_date
+            .get_selected_value()
+            .filter(|d| !d.is_empty())
+        {
+            None => None,
+            Some(date) => match NaiveDate::parse_from_str(&date, "%Y-%m-%d") {
+                Ok(date) => Some(date),
+                Err(_) => {
+                    self.notify_message
+                        .set_error(current_labels().invalid_birth_date.to_string());
+                    return AppAction::None;
+                }
+            },
+        };
+        let height = self
+            .height
+            .get_selected_value()
+            .and_then(|h| h.parse::<u16>().ok());
+        let secondary_role = self.secondary_role.get_selected_value().flatten();
+        let dominant_hand = self.dominant_hand.get_selected_value().flatten();
+        let serve_type = self.serve_type.get_selected_value().flatten();
+        let captain = self.captain.get_selected_value();
         match (
             self.name.get_selected_value(),
             self.role.get_selected_value(),
@@ -241,9 +323,25 @@ impl<TW: TeamWriter + Send + Sync> EditPlayerScreen<TW> {
                             updated.name = name;
                             updated.role = Some(role);
                             updated.number = number;
+                            updated.secondary_role = secondary_role;
+                            updated.birth_date = birth_date;
+                            updated.height = height;
+                            updated.dominant_hand = dominant_hand;
+                            updated.serve_type = serve_type;
+                            updated.captain = captain;
                             PlayerInput::Existing(updated)
                         }
-                        None => PlayerInput::New { name, role, number },
+                        None => PlayerInput::New {
+                            name,
+                            role,
+                            number,
+                            secondary_role,
+                            birth_date,
+                            height,
+                            dominant_hand,
+                            captain,
+                            serve_type,
+                        },
                     };
                     match self.team_writer.save_player(input, &mut self.team).await {
                         Ok(_) => {
@@ -273,13 +371,13 @@ impl<TW: TeamWriter + Send + Sync> EditPlayerScreen<TW> {
     }
 
     fn handle_next(&mut self) -> AppAction {
-        self.field = (self.field + 1) % 3;
+        self.field = (self.field + 1) % 9;
         self.update_writing_modes();
         AppAction::None
     }
 
     fn handle_previous(&mut self) -> AppAction {
-        self.field = (self.field + 2) % 3;
+        self.field = (self.field + 8) % 9;
         self.update_writing_modes();
         AppAction::None
     }
@@ -288,21 +386,35 @@ impl<TW: TeamWriter + Send + Sync> EditPlayerScreen<TW> {
         self.name.writing_mode = self.field == 0;
         self.role.writing_mode = self.field == 1;
         self.number.writing_mode = self.field == 2;
+        self.secondary_role.writing_mode = self.field == 3;
+        self.birth_date.writing_mode = self.field == 4;
+        self.height.writing_mode = self.field == 5;
+        self.dominant_hand.writing_mode = self.field == 6;
+        self.serve_type.writing_mode = self.field == 7;
+        self.captain.writing_mode = self.field == 8;
     }
 
     fn handle_up(&mut self) -> AppAction {
         self.role.handle_up();
+        self.secondary_role.handle_up();
+        self.dominant_hand.handle_up();
+        self.serve_type.handle_up();
         AppAction::None
     }
 
     fn handle_down(&mut self) -> AppAction {
         self.role.handle_down();
+        self.secondary_role.handle_down();
+        self.dominant_hand.handle_down();
+        self.serve_type.handle_down();
         AppAction::None
     }
 
     fn handle_backspace(&mut self) -> AppAction {
         self.name.handle_backspace();
         self.number.handle_backspace();
+        self.birth_date.handle_backspace();
+        self.height.handle_backspace();
         AppAction::None
     }
 
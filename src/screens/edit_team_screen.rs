@@ -280,6 +280,9 @@ impl<TW: TeamWriter + Send + Sync> EditTeamScreen<TW> {
                         classification: Some(classification),
                         gender: Some(gender),
                         players: vec![],
+                        seasons: vec![],
+                        combinations: vec![],
+                        lineups: vec![],
                     },
                 };
                 match self.team_writer.save(input).await {
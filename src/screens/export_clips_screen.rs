@@ -0,0 +1,361 @@
+use crate::{
+    errors::{AppError, IOError},
+    localization::current_labels,
+    providers::{settings_reader::SettingsReader, settings_writer::SettingsWriter},
+    reporting::clip_list::render_clip_list,
+    screens::{
+        components::{
+            navigation_footer::NavigationFooter, notify_banner::NotifyBanner, select::Select,
+            text_box::TextBox,
+        },
+        file_system_screen::{FileSystemAction, FileSystemScreen},
+        screen::{get_keybinding_actions, AppAction, Renderable, Sba, ScreenAsync},
+    },
+    shapes::{
+        enums::{ClipListFormatEnum, EvalEnum, EventTypeEnum, ScreenActionEnum},
+        keybinding::ScreenKeyBindings,
+        r#match::MatchEntry,
+        settings::Settings,
+        video::{build_clips, ClipFilter},
+    },
+};
+use async_trait::async_trait;
+use crokey::crossterm::event::{KeyCode, KeyEvent};
+use dirs::home_dir;
+use hf::is_hidden;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::{Block, Borders},
+    Frame,
+};
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+};
+
+const FIELDS: usize = 4;
+
+/// Exports the video clips of the events matching a filter as a clip list.
+#[derive(Debug)]
+pub struct ExportClipsScreen<SR: SettingsReader + Send + Sync, SW: SettingsWriter + Send + Sync> {
+    settings: Settings,
+    player: TextBox,
+    event_type: Select<Option<EventTypeEnum>>,
+    eval: TextBox,
+    format: Select<ClipListFormatEnum>,
+    field: usize,
+    current_match: MatchEntry,
+    notify_message: NotifyBanner,
+    footer: NavigationFooter,
+    footer_entries: Vec<(String, String)>,
+    screen_key_bindings: ScreenKeyBindings,
+    settings_reader: Arc<SR>,
+    settings_writer: Arc<SW>,
+}
+
+impl<SR: SettingsReader + Send + Sync, SW: SettingsWriter + Send + Sync> Renderable
+    for ExportClipsScreen<SR, SW>
+{
+    fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
+        let area = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3), // 0: player number
+                Constraint::Length(3), // 1: event type
+                Constraint::Length(3), // 2: eval
+                Constraint::Length(3), // 3: format
+                Constraint::Min(1),
+            ])
+            .split(body);
+        self.notify_message.render(f, footer_right);
+        f.render_widget(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(current_labels().export_clips),
+            body,
+        );
+        self.player.render(f, area[0]);
+        self.event_type.render(f, area[1]);
+        self.eval.render(f, area[2]);
+        self.format.render(f, area[3]);
+        self.footer
+            .render(f, footer_left, self.footer_entries.clone());
+    }
+}
+
+#[async_trait]
+impl<SR: SettingsReader + Send + Sync + 'static, SW: SettingsWriter + Send + Sync + 'static>
+    ScreenAsync for ExportClipsScreen<SR, SW>
+{
+    async fn handle_key(&mut self, key: KeyEvent) -> AppAction {
+        if let Some(key_combination) = self.screen_key_bindings.transform(key) {
+            match (
+                self.screen_key_bindings.get(key_combination),
+                key.code,
+                &self.notify_message.has_value(),
+            ) {
+                (_, _, true) => {
+                    self.notify_message.reset();
+                    AppAction::None
+                }
+                (_, KeyCode::Char(c), _) => self.handle_char(c),
+                (_, KeyCode::Backspace, _) => self.handle_backspace(),
+                (_, KeyCode::Up, _) => self.handle_up(),
+                (_, KeyCode::Down, _) => self.handle_down(),
+                (Some(ScreenActionEnum::Next), _, _) => self.handle_tab(),
+                (Some(ScreenActionEnum::Previous), _, _) => self.handle_backtab(),
+                (Some(ScreenActionEnum::Back), _, _) => AppAction::Back(true, Some(1)),
+                (Some(ScreenActionEnum::Confirm), _, _) => self.handle_enter(),
+                _ => AppAction::None,
+            }
+        } else {
+            AppAction::None
+        }
+    }
+
+    async fn refresh_data(&mut self) {}
+}
+
+impl<SR: SettingsReader + Send + Sync + 'static, SW: SettingsWriter + Send + Sync + 'static>
+    ExportClipsScreen<SR, SW>
+{
+    pub fn new(
+        settings: Settings,
+        current_match: MatchEntry,
+        settings_reader: Arc<SR>,
+        settings_writer: Arc<SW>,
+    ) -> Self {
+        let labels = current_labels();
+        let player = TextBox::with_validator(
+            labels.number.to_owned(),
+            true,
+            None,
+            |current: &str, c: char| current.len() < 2 && c.is_ascii_digit(),
+        );
+        let event_type = Select::new(
+            labels.event_type.to_owned(),
+            std::iter::once(None)
+                .chain(
+                    [
+                        EventTypeEnum::S,
+                        EventTypeEnum::P,
+                        EventTypeEnum::E,
+                        EventTypeEnum::A,
+                        EventTypeEnum::B,
+                        EventTypeEnum::D,
+                        EventTypeEnum::X,
+                        EventTypeEnum::F,
+                    ]
+                    .into_iter()
+                    .map(Some),
+                )
+                .collect(),
+            None,
+            false,
+        );
+        let eval = TextBox::with_validator(
+            labels.evaluation.to_owned(),
+            false,
+            None,
+            |current: &str, c: char| current.is_empty() && "#+!/=-".contains(c),
+        );
+        let format = Select::new(
+            labels.clip_list_format.to_owned(),
+            ClipListFormatEnum::ALL.to_vec(),
+            None,
+            false,
+        );
+        let actions = &[
+            Sba::Simple(ScreenActionEnum::Next),
+            Sba::Simple(ScreenActionEnum::Previous),
+            Sba::Simple(ScreenActionEnum::Confirm),
+            Sba::Simple(ScreenActionEnum::Back),
+        ];
+        let kb = &settings.keybindings;
+        let footer_entries = get_keybinding_actions(kb, actions);
+        let screen_key_bindings = kb.slice(Sba::keys(actions));
+        ExportClipsScreen {
+            settings,
+            player,
+            event_type,
+            eval,
+            format,
+            field: 0,
+            current_match,
+            notify_message: NotifyBanner::new(),
+            footer: NavigationFooter::new(),
+            footer_entries,
+            screen_key_bindings,
+            settings_reader,
+            settings_writer,
+        }
+    }
+
+    fn handle_tab(&mut self) -> AppAction {
+        self.field = (self.field + 1) % FIELDS;
+        self.update_writing_modes();
+        AppAction::None
+    }
+
+    fn handle_backtab(&mut self) -> AppAction {
+        self.field = (self.field + FIELDS - 1) % FIELDS;
+        self.update_writing_modes();
+        AppAction::None
+    }
+
+    fn update_writing_modes(&mut self) {
+        self.player.writing_mode = self.field == 0;
+        self.event_type.writing_mode = self.field == 1;
+        self.eval.writing_mode = self.field == 2;
+        self.format.writing_mode = self.field == 3;
+    }
+
+    fn handle_up(&mut self) -> AppAction {
+        match self.field {
+            1 => self.event_type.handle_up(),
+            3 => self.format.handle_up(),
+            _ => {}
+        }
+        AppAction::None
+    }
+
+    fn handle_down(&mut self) -> AppAction {
+        match self.field {
+            1 => self.event_type.handle_down(),
+            3 => self.format.handle_down(),
+            _ => {}
+        }
+        AppAction::None
+    }
+
+    fn handle_char(&mut self, c: char) -> AppAction {
+        match self.field {
+            0 => self.player.handle_char(c),
+            2 => self.eval.handle_char(c),
+            _ => {}
+        }
+        AppAction::None
+    }
+
+    fn handle_backspace(&mut self) -> AppAction {
+        match self.field {
+            0 => self.player.handle_backspace(),
+            2 => self.eval.handle_backspace(),
+            _ => {}
+        }
+        AppAction::None
+    }
+
+    fn handle_enter(&mut self) -> AppAction {
+        let labels = current_labels();
+        let player = match self
+            .player
+            .get_selected_value()
+            .filter(|n| !n.is_empty())
+            .map(|n| {
+                n.parse::<u8>()
+                    .ok()
+                    .and_then(|n| self.current_match.players.iter().find(|p| p.number == n))
+            }) {
+            None => None,
+            Some(Some(p)) => Some(p.id),
+            Some(None) => {
+                self.notify_message
+                    .set_error(labels.player_not_found.to_string());
+                return AppAction::None;
+            }
+        };
+        let filter = ClipFilter {
+            player,
+            event_type: self.event_type.get_selected_value().flatten(),
+            eval: self
+                .eval
+                .get_selected_value()
+                .and_then(|e| EvalEnum::from_str(&e).ok()),
+        };
+        let Some(format) = self.format.get_selected_value() else {
+            return AppAction::None;
+        };
+        if self.current_match.sets.iter().all(|s| s.video.is_none()) {
+            self.notify_message.set_error(labels.no_video.to_string());
+            return AppAction::None;
+        }
+        let clips = build_clips(&self.current_match, &filter);
+        if clips.is_empty() {
+            self.notify_message
+                .set_error(labels.no_clips_found.to_string());
+            return AppAction::None;
+        }
+        let title = format!(
+            "{} - {}",
+            self.current_match.team.name, self.current_match.opponent
+        );
+        let content = render_clip_list(&title, &clips, format);
+        let file_name = format!("{}_clips.{}", self.current_match.id, format.extension());
+        match home_dir() {
+            Some(path) => AppAction::SwitchScreen(Box::new(FileSystemScreen::new(
+                self.settings.clone(),
+                path,
+                labels.export_clips,
+                ExportClipsAction::new(file_name, content),
+                self.settings_reader.clone(),
+                self.settings_writer.clone(),
+            ))),
+            None => {
+                self.notify_message
+                    .set_error(labels.could_not_recognize_home_directory.to_string());
+                AppAction::None
+            }
+        }
+    }
+}
+
+/// Writes an exported clip list into the selected folder.
+pub struct ExportClipsAction {
+    file_name: String,
+    content: String,
+    exported_file_path: Option<PathBuf>,
+}
+
+impl ExportClipsAction {
+    pub fn new(file_name: String, content: String) -> Self {
+        Self {
+            file_name,
+            content,
+            exported_file_path: None,
+        }
+    }
+}
+
+#[async_trait]
+impl FileSystemAction for ExportClipsAction {
+    fn is_selectable(&self, path: &Path) -> bool {
+        !is_hidden(path).unwrap_or_default() && path.is_dir()
+    }
+
+    fn is_visible(&self, path: &Path) -> bool {
+        !is_hidden(path).unwrap_or_default() && path.is_dir()
+    }
+
+    fn success_message_suffix(&self) -> Option<String> {
+        self.exported_file_path
+            .as_ref()
+            .map(|p| p.display().to_string())
+    }
+
+    async fn on_selected(&mut self, path: &Path) -> Result<AppAction, AppError> {
+        let file_path = path.join(&self.file_name);
+        if file_path.exists() {
+            return Err(AppError::IO(IOError::Msg(
+                current_labels().file_already_exists.to_string(),
+            )));
+        }
+        tokio::fs::write(&file_path, &self.content)
+            .await
+            .map_err(|e| AppError::IO(IOError::from(e)))?;
+        self.exported_file_path = Some(file_path);
+        Ok(AppAction::Back(true, Some(1)))
+    }
+}
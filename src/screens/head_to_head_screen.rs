@@ -0,0 +1,295 @@
+use crate::{
+    localization::current_labels,
+    reporting::pdf::open_head_to_head_pdf,
+    screens::{
+        components::{
+            navigation_footer::NavigationFooter, notify_banner::NotifyBanner,
+            team_header::TeamHeader,
+        },
+        match_stats_screen::MatchStatsScreen,
+        screen::{get_keybinding_actions, AppAction, Renderable, Sba, ScreenAsync},
+    },
+    shapes::{
+        enums::{FriendlyName, ScreenActionEnum},
+        keybinding::ScreenKeyBindings,
+        opponent::OpponentEntry,
+        r#match::{MatchEntry, MatchStatus},
+        settings::Settings,
+        team::TeamEntry,
+    },
+};
+use async_trait::async_trait;
+use crokey::crossterm::event::KeyEvent;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, ListState, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+/// All the matches played against an opponent, with their results and combined stats.
+#[derive(Debug)]
+pub struct HeadToHeadScreen {
+    settings: Settings,
+    list_state: ListState,
+    team: TeamEntry,
+    opponent: OpponentEntry,
+    matches: Vec<(MatchEntry, MatchStatus, Vec<(u8, u8)>)>,
+    notify_message: NotifyBanner,
+    header: TeamHeader,
+    footer: NavigationFooter,
+    footer_entries: Vec<(String, String)>,
+    screen_key_bindings: ScreenKeyBindings,
+}
+
+impl Renderable for HeadToHeadScreen {
+    fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
+        let container = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(5),
+                Constraint::Length(5),
+                Constraint::Min(1),
+            ])
+            .split(body);
+        self.header.render(f, container[0], Some(&self.team));
+        self.render_summary(f, container[1]);
+        self.render_matches(f, container[2]);
+        self.notify_message.render(f, footer_right);
+        self.footer
+            .render(f, footer_left, self.footer_entries.clone());
+    }
+}
+
+#[async_trait]
+impl ScreenAsync for HeadToHeadScreen {
+    async fn handle_key(&mut self, key: KeyEvent) -> AppAction {
+        if let Some(key_combination) = self.screen_key_bindings.transform(key) {
+            match (
+                self.screen_key_bindings.get(key_combination),
+                &self.notify_message.has_value(),
+            ) {
+                (_, true) => {
+                    self.notify_message.reset();
+                    AppAction::None
+                }
+                (Some(ScreenActionEnum::Next), _) => self.next_match(),
+                (Some(ScreenActionEnum::Previous), _) => self.previous_match(),
+                (Some(ScreenActionEnum::MatchStats), _) => self.handle_stats(),
+                (Some(ScreenActionEnum::PrintReport), _) => self.handle_print(),
+                (Some(ScreenActionEnum::Back), _) => AppAction::Back(true, Some(1)),
+                (Some(ScreenActionEnum::Quit), _) => AppAction::Quit(Ok(())),
+                _ => AppAction::None,
+            }
+        } else {
+            AppAction::None
+        }
+    }
+
+    async fn refresh_data(&mut self) {}
+}
+
+impl HeadToHeadScreen {
+    pub fn new(
+        settings: Settings,
+        team: TeamEntry,
+        opponent: OpponentEntry,
+        matches: Vec<MatchEntry>,
+    ) -> Self {
+        let matches: Vec<_> = matches
+            .into_iter()
+            .filter_map(|m| {
+                let status = m.get_status().ok()?;
+                let scores = m
+                    .sets
+                    .iter()
+                    .filter_map(|set| set.compute_snapshot().ok())
+                    .map(|(snapshot, _)| (snapshot.score_us, snapshot.score_them))
+                    .collect();
+                Some((m, status, scores))
+            })
+            .collect();
+        let mut list_state = ListState::default();
+        if !matches.is_empty() {
+            list_state.select(Some(0));
+        }
+        let mut screen_actions = vec![];
+        if !matches.is_empty() {
+            screen_actions.extend([
+                Sba::Simple(ScreenActionEnum::Next),
+                Sba::Simple(ScreenActionEnum::Previous),
+                Sba::Simple(ScreenActionEnum::MatchStats),
+                Sba::Simple(ScreenActionEnum::PrintReport),
+            ]);
+        }
+        screen_actions.extend([
+            Sba::Simple(ScreenActionEnum::Back),
+            Sba::Simple(ScreenActionEnum::Quit),
+        ]);
+        let kb = &settings.keybindings;
+        let footer_entries = get_keybinding_actions(kb, &screen_actions);
+        let screen_key_bindings = kb.slice(Sba::keys(&screen_actions));
+        HeadToHeadScreen {
+            settings,
+            list_state,
+            team,
+            opponent,
+            matches,
+            notify_message: NotifyBanner::new(),
+            header: TeamHeader::default(),
+            footer: NavigationFooter::new(),
+            footer_entries,
+            screen_key_bindings,
+        }
+    }
+
+    fn render_summary(&self, f: &mut Frame, area: Rect) {
+        let labels = current_labels();
+        let finished = || self.matches.iter().filter(|(_, s, _)| s.match_finished);
+        let won = finished()
+            .filter(|(_, s, _)| s.us_wins > s.them_wins)
+            .count();
+        let lost = finished()
+            .filter(|(_, s, _)| s.them_wins > s.us_wins)
+            .count();
+        let sets_won: u32 = self.matches.iter().map(|(_, s, _)| s.us_wins as u32).sum();
+        let sets_lost: u32 = self
+            .matches
+            .iter()
+            .map(|(_, s, _)| s.them_wins as u32)
+            .sum();
+        let (points_won, points_lost) = self
+            .matches
+            .iter()
+            .flat_map(|(_, _, scores)| scores)
+            .fold((0u32, 0u32), |(w, l), (us, them)| {
+                (w + *us as u32, l + *them as u32)
+            });
+        let mut lines = vec![format!(
+            "{}: {}-{}  |  set: {}-{}  |  {}: {}-{}",
+            labels.matches, won, lost, sets_won, sets_lost, labels.points, points_won, points_lost
+        )];
+        let details: Vec<String> = [
+            self.opponent
+                .classification
+                .map(|c| c.friendly_name(labels).to_string()),
+            self.opponent
+                .gender
+                .map(|g| g.friendly_name(labels).to_string()),
+            self.opponent.notes.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !details.is_empty() {
+            lines.push(details.join("  |  "));
+        }
+        let paragraph = Paragraph::new(lines.join("\n"))
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("{} - {}", labels.head_to_head, self.opponent.name)),
+            );
+        f.render_widget(paragraph, area);
+    }
+
+    fn render_matches(&self, f: &mut Frame, area: Rect) {
+        let labels = current_labels();
+        let selected = self.list_state.selected();
+        let rows: Vec<Row> = self
+            .matches
+            .iter()
+            .enumerate()
+            .map(|(i, (m, status, scores))| {
+                let row = Row::new(vec![
+                    m.date.format("%a %b %d, %Y").to_string(),
+                    if m.home { labels.home } else { labels.away }.to_string(),
+                    format!("{}-{}", status.us_wins, status.them_wins),
+                    scores
+                        .iter()
+                        .map(|(us, them)| format!("{}-{}", us, them))
+                        .collect::<Vec<_>>()
+                        .join(" | "),
+                    if status.match_finished {
+                        ""
+                    } else {
+                        labels.in_progress
+                    }
+                    .to_string(),
+                ]);
+                let mut style = match (status.match_finished, status.us_wins > status.them_wins) {
+                    (true, true) => Style::default().fg(Color::LightGreen),
+                    (true, false) => Style::default().fg(Color::Red),
+                    _ => Style::default().fg(Color::White),
+                };
+                if selected == Some(i) {
+                    style = style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
+                }
+                row.style(style)
+            })
+            .collect();
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(17),
+                Constraint::Length(10),
+                Constraint::Length(6),
+                Constraint::Length(40),
+                Constraint::Length(20),
+            ],
+        )
+        .header(
+            Row::new(vec![labels.date, labels.venue, "set", labels.partials, ""])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(labels.match_list),
+        );
+        f.render_widget(table, area);
+    }
+
+    fn next_match(&mut self) -> AppAction {
+        if let Some(selected) = self.list_state.selected() {
+            let new_selected = (selected + 1).min(self.matches.len() - 1);
+            self.list_state.select(Some(new_selected));
+        };
+        AppAction::None
+    }
+
+    fn previous_match(&mut self) -> AppAction {
+        if let Some(selected) = self.list_state.selected() {
+            let new_selected = if selected == 0 { 0 } else { selected - 1 };
+            self.list_state.select(Some(new_selected));
+        };
+        AppAction::None
+    }
+
+    fn get_matches(&self) -> Vec<MatchEntry> {
+        self.matches.iter().map(|(m, _, _)| m.clone()).collect()
+    }
+
+    fn handle_stats(&mut self) -> AppAction {
+        match MatchStatsScreen::for_matches(self.settings.clone(), &self.get_matches()) {
+            Ok(screen) => AppAction::SwitchScreen(Box::new(screen)),
+            Err(_) => {
+                self.notify_message
+                    .set_error(current_labels().could_not_open_match_stats.to_string());
+                AppAction::None
+            }
+        }
+    }
+
+    fn handle_print(&mut self) -> AppAction {
+        match open_head_to_head_pdf(&self.team, &self.opponent, &self.get_matches()) {
+            Ok(_) => AppAction::None,
+            Err(_) => {
+                self.notify_message
+                    .set_error(current_labels().could_not_open_pdf.to_string());
+                AppAction::None
+            }
+        }
+    }
+}
@@ -93,6 +93,8 @@ impl<
             .create(
                 &match_entry.team,
                 match_entry.opponent,
+                match_entry.opponent_id,
+                match_entry.season,
                 match_entry.date,
                 match_entry.home,
             )
@@ -147,6 +149,7 @@ impl<
     fn parse_set_csv(content: String) -> Option<(String, Vec<EventEntry>)> {
         let mut rdr = ReaderBuilder::new()
             .has_headers(false)
+            .flexible(true)
             .from_reader(content.as_bytes());
         let mut events = Vec::new();
         for result in rdr.deserialize() {
@@ -234,6 +237,7 @@ impl<
         })?;
         entry.id = match_id.to_string();
         entry.team = team.clone();
+        entry.players = team.season_players(entry.season);
         Ok(entry)
     }
 }
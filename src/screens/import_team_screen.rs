@@ -76,6 +76,9 @@ impl<TR: TeamReader + Send + Sync, TW: TeamWriter + Send + Sync> FileSystemActio
                 classification: team.classification,
                 gender: team.gender,
                 players: team.players,
+                seasons: team.seasons,
+                combinations: team.combinations,
+                lineups: team.lineups,
             })
             .await?;
         Ok(AppAction::Back(true, Some(1)))
@@ -11,7 +11,7 @@ use crate::{
     },
     shapes::{
         enums::ScreenActionEnum,
-        keybinding::ScreenKeyBindings,
+        keybinding::{types_combination_code, ScreenKeyBindings},
         settings::{current_settings, set_settings, Settings},
     },
 };
@@ -132,6 +132,31 @@ impl<SW: SettingsWriter + Send + Sync> AddKeyBindings<SW> {
                     Ok(kc) => {
                         let mut settings = self.settings.clone();
                         let mut keybindings = settings.keybindings.clone();
+                        if self.action.eval().is_some() && types_combination_code(&kc) {
+                            self.notify_message.set_error(
+                                current_labels().key_types_combination.replacen(
+                                    "{}",
+                                    &self.fmt.to_string(kc),
+                                    1,
+                                ),
+                            );
+                            return AppAction::None;
+                        }
+                        let conflicts = keybindings.conflicts(&self.action, &kc);
+                        if !conflicts.is_empty() {
+                            let actions = conflicts
+                                .iter()
+                                .map(|a| a.with_desc().1)
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            self.notify_message.set_error(
+                                current_labels()
+                                    .key_already_used
+                                    .replacen("{}", &self.fmt.to_string(kc), 1)
+                                    .replacen("{}", &actions, 1),
+                            );
+                            return AppAction::None;
+                        }
                         if keybindings.set(self.action, kc) {
                             settings.keybindings = keybindings.clone();
                             match self.settings_writer.save(settings).await {
@@ -26,8 +26,8 @@ use crokey::{
 use crossterm::event::KeyEvent;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Modifier, Style},
-    widgets::{Block, Borders, ListState, Row, Table},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, ListState, Row, Table, TableState},
     Frame,
 };
 
@@ -271,35 +271,39 @@ impl<SW: SettingsWriter + Send + Sync + 'static, SR: SettingsReader + Send + Syn
 
     fn get_rows(&self, selected_action: usize) -> Vec<Row<'_>> {
         let actions_bindings_map = self.settings.keybindings.reverse_map();
-        let items = ScreenActionEnum::ALL
-            .iter()
-            .map(|r| {
-                let s = actions_bindings_map
-                    .get(r)
-                    .map(|f| {
-                        f.iter()
-                            .map(|q| self.format.to_string(*q))
-                            .collect::<Vec<_>>()
-                            .join(", ")
-                    })
-                    .unwrap_or(current_labels().unassigned.to_string())
-                    .to_string();
-                (r.with_desc().1, s)
-            })
-            .enumerate()
-            .map(|(index, (action, keybindings))| {
-                let mut row = Row::new(vec![action, keybindings]);
-                if index == selected_action {
-                    row = row.style(
-                        Style::default()
-                            .add_modifier(Modifier::REVERSED)
-                            .add_modifier(Modifier::BOLD),
-                    );
-                }
-                row
-            })
-            .collect();
-        items
+        let mut rows: Vec<Row> = vec![];
+        for (index, action) in ScreenActionEnum::ALL.iter().enumerate() {
+            if Some(index) == first_scouting_action() {
+                rows.push(
+                    Row::new(vec![current_labels().scouting_keys.to_string()])
+                        .style(Style::default().add_modifier(Modifier::BOLD)),
+                );
+            }
+            let keybindings = actions_bindings_map
+                .get(action)
+                .filter(|f| !f.is_empty())
+                .map(|f| {
+                    f.iter()
+                        .map(|q| self.format.to_string(*q))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .unwrap_or(current_labels().unassigned.to_string());
+            let mut row = Row::new(vec![action.with_desc().1, keybindings]);
+            // keys shared with other actions while scouting, from settings edited by hand
+            if self.settings.keybindings.has_conflicts(action) {
+                row = row.style(Style::default().fg(Color::Red));
+            }
+            if index == selected_action {
+                row = row.style(
+                    Style::default()
+                        .add_modifier(Modifier::REVERSED)
+                        .add_modifier(Modifier::BOLD),
+                );
+            }
+            rows.push(row);
+        }
+        rows
     }
 
     fn render_key_bindings_list(&mut self, f: &mut Frame, area: Rect) {
@@ -310,6 +314,11 @@ impl<SW: SettingsWriter + Send + Sync + 'static, SR: SettingsReader + Send + Syn
             }
             Some(p) => p,
         };
+        // the section title takes a row before the scouting keys
+        let selected_row = match first_scouting_action() {
+            Some(first) if selected_action >= first => selected_action + 1,
+            _ => selected_action,
+        };
         let table = Table::new(
             self.get_rows(selected_action),
             vec![Constraint::Length(20), Constraint::Length(30)],
@@ -323,9 +332,9 @@ impl<SW: SettingsWriter + Send + Sync + 'static, SR: SettingsReader + Send + Syn
                 .borders(Borders::ALL)
                 .title(current_labels().keybinding_settings),
         )
-        .widths([Constraint::Length(20), Constraint::Length(30)]);
-
-        f.render_widget(table, area);
+        .widths([Constraint::Length(30), Constraint::Length(30)]);
+        let mut state = TableState::default().with_selected(Some(selected_row));
+        f.render_stateful_widget(table, area, &mut state);
     }
 
     async fn reset_settings(&mut self, default_settings: Settings) -> AppAction {
@@ -348,6 +357,10 @@ impl<SW: SettingsWriter + Send + Sync + 'static, SR: SettingsReader + Send + Syn
     }
 }
 
+fn first_scouting_action() -> Option<usize> {
+    ScreenActionEnum::ALL.iter().position(|a| a.is_scouting())
+}
+
 fn get_context_menu(settings: &Settings) -> (Vec<(String, String)>, ScreenKeyBindings) {
     let screen_actions = &[
         Sba::Simple(ScreenActionEnum::Previous),
@@ -4,8 +4,9 @@ use crate::{
     errors::AppError,
     localization::current_labels,
     providers::{
-        match_reader::MatchReader, match_writer::MatchWriter, set_writer::SetWriter,
-        settings_reader::SettingsReader, settings_writer::SettingsWriter,
+        match_reader::MatchReader, match_writer::MatchWriter, opponent_reader::OpponentReader,
+        opponent_writer::OpponentWriter, set_writer::SetWriter, settings_reader::SettingsReader,
+        settings_writer::SettingsWriter, team_reader::TeamReader, team_writer::TeamWriter,
     },
     reporting::pdf::open_match_pdf,
     screens::{
@@ -18,6 +19,8 @@ use crate::{
         file_system_screen::FileSystemScreen,
         import_match_screen::ImportMatchAction,
         match_stats_screen::MatchStatsScreen,
+        opponent_list_screen::OpponentListScreen,
+        rallies_screen::RalliesScreen,
         scouting_screen::ScoutingScreen,
         screen::{get_keybinding_actions, AppAction, Renderable, Sba, ScreenAsync},
         start_set_screen::StartSetScreen,
@@ -47,8 +50,12 @@ use ratatui::{
 
 #[derive(Debug)]
 pub struct MatchListScreen<
+    TR: TeamReader + Send + Sync,
+    TW: TeamWriter + Send + Sync,
     MR: MatchReader + Send + Sync,
     MW: MatchWriter + Send + Sync,
+    OR: OpponentReader + Send + Sync,
+    OW: OpponentWriter + Send + Sync,
     SSW: SetWriter + Send + Sync,
     SR: SettingsReader + Send + Sync,
     SW: SettingsWriter + Send + Sync,
@@ -61,8 +68,12 @@ pub struct MatchListScreen<
     header: TeamHeader,
     footer: NavigationFooter,
     base_path: PathBuf,
+    team_reader: Arc<TR>,
+    team_writer: Arc<TW>,
     match_reader: Arc<MR>,
     match_writer: Arc<MW>,
+    opponent_reader: Arc<OR>,
+    opponent_writer: Arc<OW>,
     set_writer: Arc<SSW>,
     settings_reader: Arc<SR>,
     settings_writer: Arc<SW>,
@@ -70,12 +81,16 @@ pub struct MatchListScreen<
 }
 
 impl<
+        TR: TeamReader + Send + Sync + 'static,
+        TW: TeamWriter + Send + Sync + 'static,
         MR: MatchReader + Send + Sync + 'static,
         MW: MatchWriter + Send + Sync + 'static,
+        OR: OpponentReader + Send + Sync + 'static,
+        OW: OpponentWriter + Send + Sync + 'static,
         SSW: SetWriter + Send + Sync + 'static,
         SR: SettingsReader + Send + Sync + 'static,
         SW: SettingsWriter + Send + Sync + 'static,
-    > Renderable for MatchListScreen<MR, MW, SSW, SR, SW>
+    > Renderable for MatchListScreen<TR, TW, MR, MW, OR, OW, SSW, SR, SW>
 {
     fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
         let container = Layout::default()
@@ -140,12 +155,16 @@ impl<
 
 #[async_trait]
 impl<
+        TR: TeamReader + Send + Sync + 'static,
+        TW: TeamWriter + Send + Sync + 'static,
         MR: MatchReader + Send + Sync + 'static,
         MW: MatchWriter + Send + Sync + 'static,
+        OR: OpponentReader + Send + Sync + 'static,
+        OW: OpponentWriter + Send + Sync + 'static,
         SSW: SetWriter + Send + Sync + 'static,
         SR: SettingsReader + Send + Sync + 'static,
         SW: SettingsWriter + Send + Sync + 'static,
-    > ScreenAsync for MatchListScreen<MR, MW, SSW, SR, SW>
+    > ScreenAsync for MatchListScreen<TR, TW, MR, MW, OR, OW, SSW, SR, SW>
 {
     async fn handle_key(&mut self, key: KeyEvent) -> AppAction {
         if let Some(key_combination) = self.screen_key_bindings.transform(key) {
@@ -163,6 +182,8 @@ impl<
                 (Some(ScreenActionEnum::Select), _, _) => self.handle_enter_key(),
                 (Some(ScreenActionEnum::PrintReport), _, _) => self.handle_print(),
                 (Some(ScreenActionEnum::MatchStats), _, _) => self.handle_space_key(),
+                (Some(ScreenActionEnum::HeadToHead), _, _) => self.handle_head_to_head().await,
+                (Some(ScreenActionEnum::Rallies), _, _) => self.handle_rallies(),
                 (Some(ScreenActionEnum::Back), _, _) => AppAction::Back(true, Some(1)),
                 (Some(ScreenActionEnum::Quit), _, _) => AppAction::Quit(Ok(())),
                 (Some(ScreenActionEnum::New), _, _) => {
@@ -171,7 +192,11 @@ impl<
                             self.settings.clone(),
                             self.team.clone(),
                             self.match_writer.clone(),
+                            self.opponent_reader.clone(),
+                            self.opponent_writer.clone(),
                             self.set_writer.clone(),
+                            self.team_reader.clone(),
+                            self.team_writer.clone(),
                         )))
                     } else {
                         AppAction::None
@@ -239,6 +264,10 @@ impl<
         }
     }
     async fn refresh_data(&mut self) {
+        // lineup templates might have been saved while starting a set
+        if let Ok(team) = self.team_reader.read_single(&self.team.id).await {
+            self.team = team;
+        }
         match self.match_reader.read_all(&self.team).await {
             Ok(matches) => {
                 let matches = matches
@@ -265,20 +294,28 @@ impl<
 }
 
 impl<
+        TR: TeamReader + Send + Sync + 'static,
+        TW: TeamWriter + Send + Sync + 'static,
         MR: MatchReader + Send + Sync + 'static,
         MW: MatchWriter + Send + Sync + 'static,
+        OR: OpponentReader + Send + Sync + 'static,
+        OW: OpponentWriter + Send + Sync + 'static,
         SSW: SetWriter + Send + Sync + 'static,
         SR: SettingsReader + Send + Sync + 'static,
         SW: SettingsWriter + Send + Sync + 'static,
-    > MatchListScreen<MR, MW, SSW, SR, SW>
+    > MatchListScreen<TR, TW, MR, MW, OR, OW, SSW, SR, SW>
 {
     pub fn new(
         settings: Settings,
         team: TeamEntry,
         matches: Vec<MatchEntry>,
         base_path: PathBuf,
+        team_reader: Arc<TR>,
+        team_writer: Arc<TW>,
         match_reader: Arc<MR>,
         match_writer: Arc<MW>,
+        opponent_reader: Arc<OR>,
+        opponent_writer: Arc<OW>,
         set_writer: Arc<SSW>,
         settings_reader: Arc<SR>,
         settings_writer: Arc<SW>,
@@ -296,8 +333,12 @@ impl<
             notify_message: NotifyBanner::new(),
             header: TeamHeader::default(),
             footer: NavigationFooter::new(),
+            team_reader,
+            team_writer,
             match_reader,
             match_writer,
+            opponent_reader,
+            opponent_writer,
             set_writer,
             settings_reader,
             settings_writer,
@@ -374,7 +415,10 @@ impl<
                 (_, Some(TeamSideEnum::Us)) => Some(TeamSideEnum::Them),
             },
             Some(1),
+            self.match_writer.clone(),
             self.set_writer.clone(),
+            self.team_reader.clone(),
+            self.team_writer.clone(),
         )))
     }
 
@@ -388,6 +432,7 @@ impl<
                     snapshot,
                     available_options,
                     Some(1),
+                    self.match_writer.clone(),
                     self.set_writer.clone(),
                 )))
             }
@@ -431,11 +476,13 @@ impl<
             }
             actions.push(Sba::Simple(ScreenActionEnum::Export));
             actions.push(Sba::Simple(ScreenActionEnum::MatchStats));
+            actions.push(Sba::Simple(ScreenActionEnum::Rallies));
             actions.push(Sba::Simple(ScreenActionEnum::PrintReport));
         }
         if self.team.players.len() >= 6 {
             actions.push(Sba::Simple(ScreenActionEnum::New));
         };
+        actions.push(Sba::Simple(ScreenActionEnum::HeadToHead));
         actions.push(Sba::Simple(ScreenActionEnum::Back));
         actions.push(Sba::Simple(ScreenActionEnum::Quit));
         actions
@@ -474,6 +521,45 @@ impl<
         }
     }
 
+    fn handle_rallies(&mut self) -> AppAction {
+        match self.get_selected_match() {
+            Some((m, _)) => AppAction::SwitchScreen(Box::new(RalliesScreen::new(
+                self.settings.clone(),
+                m.clone(),
+                self.match_reader.clone(),
+                self.match_writer.clone(),
+                self.set_writer.clone(),
+                self.team_reader.clone(),
+                self.team_writer.clone(),
+                self.settings_reader.clone(),
+                self.settings_writer.clone(),
+            ))),
+            None => {
+                self.notify_message
+                    .set_error(current_labels().no_match_selected.to_string());
+                AppAction::None
+            }
+        }
+    }
+
+    async fn handle_head_to_head(&mut self) -> AppAction {
+        match self.opponent_reader.read_all().await {
+            Ok(opponents) => AppAction::SwitchScreen(Box::new(OpponentListScreen::new(
+                self.settings.clone(),
+                self.team.clone(),
+                self.matches.iter().map(|(m, _)| m.clone()).collect(),
+                opponents,
+                self.opponent_reader.clone(),
+                self.opponent_writer.clone(),
+            ))),
+            Err(_) => {
+                self.notify_message
+                    .set_error(current_labels().could_not_load_opponents.to_string());
+                AppAction::None
+            }
+        }
+    }
+
     fn handle_enter_key(&mut self) -> AppAction {
         let selected = self.get_selected_match().map(|(m, s)| (m.clone(), s));
         if let Some((match_entry, status)) = selected {
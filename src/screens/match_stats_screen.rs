@@ -31,7 +31,12 @@ use ratatui::{
     },
     Frame,
 };
-use std::{collections::HashSet, fmt::Display, iter::once};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::Display,
+    iter::once,
+};
 use uuid::Uuid;
 
 struct Selection<T>
@@ -146,8 +151,23 @@ where
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StatsView {
+    Event(EventTypeEnum),
+    RotationAnalysis,
+    ReceptionAnalysis,
+    SetterDistribution,
+    PlusMinus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlusMinusSort {
+    Balance,
+    Rallies,
+}
+
 pub struct EventSelection {
-    event_type: EventTypeEnum,
+    view: StatsView,
     label: String,
 }
 
@@ -165,7 +185,11 @@ pub struct MatchStatsScreen {
     phase_filter: Selection<PhaseEnum>,
     event_filter: Selection<EventSelection>,
     player_filter: Selection<PlayerEntry>,
+    tag_filter: Selection<String>,
+    plus_minus_sort: PlusMinusSort,
     sets: Vec<(SetEntry, Snapshot)>,
+    /// per tag, the stats of each entry of `sets` restricted to that tag
+    tag_stats: HashMap<String, Vec<Stats>>,
     footer: NavigationFooter,
     footer_entries: Vec<(String, String)>,
     screen_key_bindings: ScreenKeyBindings,
@@ -190,6 +214,7 @@ impl ScreenAsync for MatchStatsScreen {
                     self.phase_filter.previous();
                     self.event_filter.previous();
                     self.player_filter.previous();
+                    self.tag_filter.previous();
                     AppAction::None
                 }
                 (Some(ScreenActionEnum::ScrollUp), _, _) => {
@@ -198,25 +223,35 @@ impl ScreenAsync for MatchStatsScreen {
                     self.phase_filter.next();
                     self.event_filter.next();
                     self.player_filter.next();
+                    self.tag_filter.next();
                     AppAction::None
                 }
                 (Some(ScreenActionEnum::Back), KeyCode::Esc, _) => AppAction::Back(true, Some(1)),
+                (Some(ScreenActionEnum::Sort), _, _) => {
+                    self.plus_minus_sort = match self.plus_minus_sort {
+                        PlusMinusSort::Balance => PlusMinusSort::Rallies,
+                        PlusMinusSort::Rallies => PlusMinusSort::Balance,
+                    };
+                    AppAction::None
+                }
                 (Some(ScreenActionEnum::Next), _, _) => {
                     match self.state.selected() {
                         Some(i) => {
-                            let next_index = if i >= 4 { 0 } else { i + 1 };
+                            let next_index = if i >= 5 { 0 } else { i + 1 };
                             self.state.select(Some(next_index));
                             self.set_filter.disable_writing_mode();
                             self.rotation_filter.disable_writing_mode();
                             self.phase_filter.disable_writing_mode();
                             self.event_filter.disable_writing_mode();
                             self.player_filter.disable_writing_mode();
+                            self.tag_filter.disable_writing_mode();
                             match next_index {
                                 0 => self.set_filter.enable_writing_mode(),
                                 1 => self.rotation_filter.enable_writing_mode(),
                                 2 => self.phase_filter.enable_writing_mode(),
                                 3 => self.event_filter.enable_writing_mode(),
                                 4 => self.player_filter.enable_writing_mode(),
+                                5 => self.tag_filter.enable_writing_mode(),
                                 _ => {}
                             }
                         }
@@ -229,19 +264,21 @@ impl ScreenAsync for MatchStatsScreen {
                 (Some(ScreenActionEnum::Previous), _, _) => {
                     match self.state.selected() {
                         Some(i) => {
-                            let prev_index = if i == 0 { 4 } else { i - 1 };
+                            let prev_index = if i == 0 { 5 } else { i - 1 };
                             self.state.select(Some(prev_index));
                             self.set_filter.disable_writing_mode();
                             self.rotation_filter.disable_writing_mode();
                             self.phase_filter.disable_writing_mode();
                             self.event_filter.disable_writing_mode();
                             self.player_filter.disable_writing_mode();
+                            self.tag_filter.disable_writing_mode();
                             match prev_index {
                                 0 => self.set_filter.enable_writing_mode(),
                                 1 => self.rotation_filter.enable_writing_mode(),
                                 2 => self.phase_filter.enable_writing_mode(),
                                 3 => self.event_filter.enable_writing_mode(),
                                 4 => self.player_filter.enable_writing_mode(),
+                                5 => self.tag_filter.enable_writing_mode(),
                                 _ => {}
                             }
                         }
@@ -263,20 +300,38 @@ impl ScreenAsync for MatchStatsScreen {
 
 impl Renderable for MatchStatsScreen {
     fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, _: Rect) {
-        let chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(33),
-                Constraint::Percentage(33),
-                Constraint::Percentage(34),
-            ])
-            .split(body);
-        let left_col = chunks[0];
-        let center_col = chunks[1];
-        let right_col = chunks[2];
-        self.render_left(f, left_col);
-        self.render_center(f, center_col);
-        self.render_right(f, right_col);
+        let view = self.event_filter.selected().map(|selection| selection.view);
+        if let Some(StatsView::Event(event_type)) = view {
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(33),
+                    Constraint::Percentage(33),
+                    Constraint::Percentage(34),
+                ])
+                .split(body);
+            let left_col = chunks[0];
+            let center_col = chunks[1];
+            let right_col = chunks[2];
+            self.render_left(f, left_col);
+            self.render_event_stats(f, event_type, center_col);
+            self.render_right(f, event_type, right_col);
+        } else {
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(33), Constraint::Percentage(67)])
+                .split(body);
+            self.render_left(f, chunks[0]);
+            match view {
+                Some(StatsView::RotationAnalysis) => self.render_rotation_analysis(f, chunks[1]),
+                Some(StatsView::ReceptionAnalysis) => self.render_reception_analysis(f, chunks[1]),
+                Some(StatsView::SetterDistribution) => {
+                    self.render_setter_distribution(f, chunks[1])
+                }
+                Some(StatsView::PlusMinus) => self.render_plus_minus(f, chunks[1]),
+                _ => {}
+            }
+        }
         self.footer
             .render(f, footer_left, self.footer_entries.clone());
     }
@@ -284,18 +339,60 @@ impl Renderable for MatchStatsScreen {
 
 impl MatchStatsScreen {
     pub fn new(settings: Settings, current_match: MatchEntry) -> Result<Self, AppError> {
+        Self::for_matches(settings, &[current_match])
+    }
+
+    /// Stats aggregated over several matches of the same team; filtering by set number
+    /// merges that set across all the matches.
+    pub fn for_matches(settings: Settings, matches: &[MatchEntry]) -> Result<Self, AppError> {
         use EventTypeEnum::*;
         let mut state = ListState::default();
         state.select(Some(0));
         let mut sets: Vec<(SetEntry, Snapshot)> = Vec::new();
-        for set in &current_match.sets {
-            let (snapshot, _) = set.compute_snapshot()?;
-            sets.push((set.clone(), snapshot));
+        let mut tags: BTreeSet<String> = BTreeSet::new();
+        for m in matches {
+            tags.extend(m.tags.iter().cloned());
+            for set in &m.sets {
+                tags.extend(set.tags.iter().cloned());
+                tags.extend(set.events.iter().flat_map(|e| e.tags.iter().cloned()));
+            }
         }
-        let set_numbers = sets.iter().map(|(set, _)| set.set_number);
+        let mut tag_stats: HashMap<String, Vec<Stats>> = HashMap::new();
+        for m in matches {
+            for set in &m.sets {
+                let (snapshot, _) = set.compute_snapshot()?;
+                for tag in &tags {
+                    // a tag on the match or on the set covers all of its events
+                    let stats = if m.tags.contains(tag) || set.tags.contains(tag) {
+                        snapshot.stats.clone()
+                    } else {
+                        set.compute_tagged_stats(tag)?
+                    };
+                    tag_stats.entry(tag.clone()).or_default().push(stats);
+                }
+                sets.push((set.clone(), snapshot));
+            }
+        }
+        let tag_filter = Selection::new(
+            current_labels().tags.to_string(),
+            once(None).chain(tags.into_iter().map(Some)).collect(),
+        );
+        let mut set_numbers: Vec<u8> = sets.iter().map(|(set, _)| set.set_number).collect();
+        set_numbers.sort();
+        set_numbers.dedup();
+        // sets joined late miss the rallies played before
+        let partially_scouted = sets
+            .iter()
+            .any(|(_, snapshot)| snapshot.scouted_from.is_some());
         let mut set_filter = Selection::new(
-            "set".to_string(),
-            once(None).chain(set_numbers.map(Some)).collect(),
+            if partially_scouted {
+                format!("set ({})", current_labels().partially_scouted)
+            } else {
+                "set".to_string()
+            },
+            once(None)
+                .chain(set_numbers.into_iter().map(Some))
+                .collect(),
         );
         set_filter.enable_writing_mode();
         let rotation_filter = Selection::new(
@@ -314,25 +411,49 @@ impl MatchStatsScreen {
             "stat".to_string(),
             vec![
                 EventSelection {
-                    event_type: S,
+                    view: StatsView::Event(S),
                     label: S.friendly_name(current_labels()).to_string(),
                 },
                 EventSelection {
-                    event_type: P,
+                    view: StatsView::Event(P),
                     label: P.friendly_name(current_labels()).to_string(),
                 },
                 EventSelection {
-                    event_type: D,
+                    view: StatsView::Event(D),
                     label: D.friendly_name(current_labels()).to_string(),
                 },
                 EventSelection {
-                    event_type: B,
+                    view: StatsView::Event(B),
                     label: B.friendly_name(current_labels()).to_string(),
                 },
                 EventSelection {
-                    event_type: A,
+                    view: StatsView::Event(A),
                     label: A.friendly_name(current_labels()).to_string(),
                 },
+                EventSelection {
+                    view: StatsView::Event(E),
+                    label: E.friendly_name(current_labels()).to_string(),
+                },
+                EventSelection {
+                    view: StatsView::Event(X),
+                    label: X.friendly_name(current_labels()).to_string(),
+                },
+                EventSelection {
+                    view: StatsView::RotationAnalysis,
+                    label: current_labels().rotation_analysis.to_string(),
+                },
+                EventSelection {
+                    view: StatsView::ReceptionAnalysis,
+                    label: current_labels().reception_analysis.to_string(),
+                },
+                EventSelection {
+                    view: StatsView::SetterDistribution,
+                    label: current_labels().setter_distribution.to_string(),
+                },
+                EventSelection {
+                    view: StatsView::PlusMinus,
+                    label: current_labels().lineup_combinations.to_string(),
+                },
             ]
             .into_iter()
             .map(Some)
@@ -351,13 +472,14 @@ impl MatchStatsScreen {
         let players = once(None).chain(
             players
                 .iter()
-                .map(|p| current_match.team.find_player(*p).cloned()),
+                .map(|p| matches.first().and_then(|m| m.find_player(*p)).cloned()),
         );
         let screen_actions = &[
             Sba::Simple(ScreenActionEnum::Next),
             Sba::Simple(ScreenActionEnum::Previous),
             Sba::Simple(ScreenActionEnum::ScrollUp),
             Sba::Simple(ScreenActionEnum::ScrollDown),
+            Sba::Simple(ScreenActionEnum::Sort),
             Sba::Simple(ScreenActionEnum::Back),
             Sba::Simple(ScreenActionEnum::Quit),
         ];
@@ -373,8 +495,11 @@ impl MatchStatsScreen {
             phase_filter,
             event_filter,
             player_filter,
+            tag_filter,
+            plus_minus_sort: PlusMinusSort::Balance,
             state,
             sets,
+            tag_stats,
             footer: NavigationFooter::new(),
             footer_entries,
             screen_key_bindings,
@@ -382,23 +507,25 @@ impl MatchStatsScreen {
     }
 
     fn get_current_stats(&self, set_number: Option<u8>) -> Option<Stats> {
-        match set_number {
-            None => {
-                let mut aggregated_stats = Stats::new();
-                for (_, snapshot) in &self.sets {
-                    aggregated_stats.merge(&snapshot.stats);
-                }
-                Some(aggregated_stats)
+        let tagged = self
+            .tag_filter
+            .selected()
+            .and_then(|tag| self.tag_stats.get(tag));
+        let mut aggregated_stats: Option<Stats> = None;
+        for (i, (set, snapshot)) in self.sets.iter().enumerate() {
+            if set_number.is_some_and(|num| num != set.set_number) {
+                continue;
             }
-            Some(num) => self
-                .sets
-                .iter()
-                .find(|(set, _)| set.set_number == num)
-                .map(|(_, snapshot)| snapshot.stats.clone()),
+            let stats = tagged.map_or(&snapshot.stats, |tagged| &tagged[i]);
+            aggregated_stats.get_or_insert_with(Stats::new).merge(stats);
+        }
+        match set_number {
+            None => Some(aggregated_stats.unwrap_or_else(Stats::new)),
+            Some(_) => aggregated_stats,
         }
     }
 
-    fn render_event_stats(&self, f: &mut Frame, selection: &EventSelection, area: Rect) {
+    fn render_event_stats(&self, f: &mut Frame, event_type: EventTypeEnum, area: Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -408,25 +535,65 @@ impl MatchStatsScreen {
                 Constraint::Length(3),
             ])
             .split(area);
-        self.render_evals_bars(f, selection, chunks[0]);
-        self.render_summary_table(f, selection, chunks[1]);
-        self.render_efficiency_bars(f, selection, chunks[2]);
-        self.render_positiveness_bars(f, selection, chunks[3]);
+        self.render_evals_bars(f, event_type, chunks[0]);
+        self.render_summary_table(f, event_type, chunks[1]);
+        self.render_efficiency_bars(f, event_type, chunks[2]);
+        self.render_positiveness_bars(f, event_type, chunks[3]);
     }
 
-    fn render_right(&mut self, f: &mut Frame, area: Rect) {
+    fn render_right(&mut self, f: &mut Frame, event_type: EventTypeEnum, area: Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
             .split(area);
-        if let Some(EventTypeEnum::A) = self
-            .event_filter
-            .selected()
-            .map(|selection| selection.event_type)
-        {
+        if event_type == EventTypeEnum::A {
             self.render_court_canvas(f, chunks[0], current_labels().distribution, |(p, _)| p);
             self.render_court_canvas(f, chunks[1], current_labels().conversion_rate, |(_, s)| s);
         }
+        if matches!(event_type, EventTypeEnum::E | EventTypeEnum::X) {
+            self.render_outcome_table(f, event_type, chunks[0]);
+        }
+    }
+
+    /// Per evaluation, how often the attack after a set was a kill or a free ball led to a point.
+    fn render_outcome_table(&self, f: &mut Frame, event_type: EventTypeEnum, area: Rect) {
+        let labels = current_labels();
+        let set = self.set_filter.selected().copied();
+        let rotation = self.rotation_filter.selected().cloned().map(|r| r as u8);
+        let phase = self.phase_filter.selected().cloned();
+        let player = self.player_filter.selected().map(|p| p.id);
+        let Some(stats) = self.get_current_stats(set) else {
+            return;
+        };
+        let (title, header) = match event_type {
+            EventTypeEnum::E => (labels.after_set, labels.kill_perc),
+            _ => (labels.free_ball, labels.won_perc),
+        };
+        let rows: Vec<Row> = event_type
+            .available_evals()
+            .iter()
+            .map(|eval| {
+                let value = match event_type {
+                    EventTypeEnum::E => {
+                        stats.set_kill_percentage(player, phase, rotation, Some(*eval))
+                    }
+                    _ => stats.freeball_won_percentage(player, phase, rotation, Some(*eval)),
+                };
+                Row::new(vec![
+                    eval.to_string(),
+                    value
+                        .map(|(p, t, c)| format!("{:.1}% ({}/{})", p, c, t))
+                        .unwrap_or("-".to_string()),
+                ])
+            })
+            .collect();
+        let table = Table::new(rows, [Constraint::Length(8), Constraint::Min(10)])
+            .header(
+                Row::new(vec![labels.evaluations, header])
+                    .style(Style::default().add_modifier(Modifier::BOLD)),
+            )
+            .block(Block::default().borders(Borders::ALL).title(title));
+        f.render_widget(table, area);
     }
 
     pub fn render_court_canvas<F>(&self, f: &mut Frame, area: Rect, label: &str, select_value: F)
@@ -483,8 +650,7 @@ impl MatchStatsScreen {
         }
     }
 
-    fn render_evals_bars(&self, f: &mut Frame, selection: &EventSelection, area: Rect) {
-        let event_type = selection.event_type;
+    fn render_evals_bars(&self, f: &mut Frame, event_type: EventTypeEnum, area: Rect) {
         let set = self.set_filter.selected().copied();
         let rotation = self.rotation_filter.selected().cloned().map(|r| r as u8);
         let phase = self.phase_filter.selected().cloned();
@@ -528,6 +694,17 @@ impl MatchStatsScreen {
                 (EvalEnum::Over, Color::Red),
                 (EvalEnum::Error, Color::Red),
             ],
+            EventTypeEnum::E => vec![
+                (EvalEnum::Perfect, Color::Green),
+                (EvalEnum::Positive, Color::LightGreen),
+                (EvalEnum::Negative, Color::Yellow),
+                (EvalEnum::Error, Color::Red),
+            ],
+            EventTypeEnum::X => vec![
+                (EvalEnum::Positive, Color::LightGreen),
+                (EvalEnum::Negative, Color::Yellow),
+                (EvalEnum::Error, Color::Red),
+            ],
             _ => vec![],
         };
         let mut bars: Vec<Bar> = Vec::new();
@@ -562,8 +739,7 @@ impl MatchStatsScreen {
         f.render_widget(evals_barchart, area);
     }
 
-    fn render_efficiency_bars(&self, f: &mut Frame, selection: &EventSelection, area: Rect) {
-        let event_type = selection.event_type;
+    fn render_efficiency_bars(&self, f: &mut Frame, event_type: EventTypeEnum, area: Rect) {
         let set = self.set_filter.selected().copied();
         let rotation = self.rotation_filter.selected().cloned().map(|r| r as u8);
         let phase = self.phase_filter.selected().cloned();
@@ -609,8 +785,7 @@ impl MatchStatsScreen {
         }
     }
 
-    fn render_positiveness_bars(&self, f: &mut Frame, selection: &EventSelection, area: Rect) {
-        let event_type = selection.event_type;
+    fn render_positiveness_bars(&self, f: &mut Frame, event_type: EventTypeEnum, area: Rect) {
         let set = self.set_filter.selected().copied();
         let rotation = self.rotation_filter.selected().cloned().map(|r| r as u8);
         let phase = self.phase_filter.selected().cloned();
@@ -656,8 +831,7 @@ impl MatchStatsScreen {
         }
     }
 
-    fn render_summary_table(&self, f: &mut Frame, selection: &EventSelection, area: Rect) {
-        let event_type = selection.event_type;
+    fn render_summary_table(&self, f: &mut Frame, event_type: EventTypeEnum, area: Rect) {
         let set = self.set_filter.selected().copied();
         let rotation = self.rotation_filter.selected().cloned().map(|r| r as u8);
         let phase = self.phase_filter.selected().cloned();
@@ -733,10 +907,393 @@ impl MatchStatsScreen {
         }
     }
 
-    fn render_center(&mut self, f: &mut Frame, area: Rect) {
-        if let Some(selection) = self.event_filter.selected() {
-            self.render_event_stats(f, selection, area)
-        }
+    fn render_rotation_analysis(&self, f: &mut Frame, area: Rect) {
+        let labels = current_labels();
+        let set = self.set_filter.selected().copied();
+        let selected_rotation = self.rotation_filter.selected().cloned().map(|r| r as u8);
+        let Some(stats) = self.get_current_stats(set) else {
+            return;
+        };
+        let analysis_row = |label: String, rotation: Option<u8>| {
+            let pct = |v: Option<(f64, u32, u32)>| {
+                v.map(|(p, t, c)| format!("{:.1}% ({}/{})", p, c, t))
+                    .unwrap_or("-".to_string())
+            };
+            let passes = stats.rotation_passes(rotation);
+            let (won, lost) = stats.points_won_lost(None, rotation);
+            let balance = won as i32 - lost as i32;
+            Row::new(vec![
+                Cell::from(label),
+                Cell::from(pct(stats.phase_win_percentage(PhaseEnum::SideOut, rotation))),
+                Cell::from(pct(stats.phase_win_percentage(PhaseEnum::Break, rotation))),
+                Cell::from(pct(stats.first_ball_sideout(rotation))),
+                Cell::from(passes.map_or("-".to_string(), |v| v.to_string())),
+                Cell::from(format!("{}-{}", won, lost)),
+                Cell::from(format!("{:+}", balance)),
+                Cell::from(
+                    passes
+                        .map(|p| format!("{:+.2}", balance as f64 / p as f64))
+                        .unwrap_or("-".to_string()),
+                ),
+            ])
+        };
+        let mut rows: Vec<Row> = RotationEnum::ALL
+            .iter()
+            .map(|r| {
+                let rotation = *r as u8;
+                let row = analysis_row(r.to_string(), Some(rotation));
+                if selected_rotation == Some(rotation) {
+                    row.style(Style::default().add_modifier(Modifier::REVERSED))
+                } else {
+                    row
+                }
+            })
+            .collect();
+        rows.push(
+            analysis_row(labels.global.to_string(), None)
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        );
+        let header = Row::new(vec![
+            labels.rotation,
+            labels.sideout_perc,
+            labels.break_point_perc,
+            labels.first_ball_sideout_perc,
+            labels.rotation_passes,
+            labels.won_lost,
+            labels.balance,
+            labels.balance_per_pass,
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(10),
+                Constraint::Length(18),
+                Constraint::Length(18),
+                Constraint::Length(18),
+                Constraint::Length(8),
+                Constraint::Length(8),
+                Constraint::Length(8),
+                Constraint::Length(10),
+            ],
+        )
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(labels.rotation_analysis),
+        );
+        f.render_widget(table, area);
+    }
+
+    fn render_reception_analysis(&self, f: &mut Frame, area: Rect) {
+        use EvalEnum::*;
+        let labels = current_labels();
+        let set = self.set_filter.selected().copied();
+        let rotation = self.rotation_filter.selected().cloned().map(|r| r as u8);
+        let selected_player = self.player_filter.selected().map(|p| p.id);
+        let Some(stats) = self.get_current_stats(set) else {
+            return;
+        };
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(area);
+        let pass_evals = [Perfect, Positive, Exclamative, Negative, Over, Error];
+        let count = |player: Option<Uuid>, eval: Option<EvalEnum>| {
+            stats.event_count(EventTypeEnum::P, player, None, rotation, None, eval)
+        };
+        let pct = |v: Option<(f64, u32, u32)>| {
+            v.map(|(p, _, _)| format!("{:.1}%", p))
+                .unwrap_or("-".to_string())
+        };
+        let team_total = count(None, None);
+        let mut receivers: Vec<&PlayerEntry> = self
+            .player_filter
+            .items
+            .iter()
+            .flatten()
+            .filter(|p| count(Some(p.id), None).is_some())
+            .collect();
+        receivers.sort_by_key(|p| p.number);
+        let rows: Vec<Row> = receivers
+            .iter()
+            .map(|player| {
+                let total = count(Some(player.id), None);
+                let share = match (total, team_total) {
+                    (Some(c), Some(all)) => format!("{:.1}%", 100.0 * c as f64 / all as f64),
+                    _ => "-".to_string(),
+                };
+                let mut cells = vec![
+                    Cell::from(player.to_string()),
+                    Cell::from(total.map_or("-".to_string(), |v| v.to_string())),
+                    Cell::from(share),
+                ];
+                cells.extend(pass_evals.iter().map(|eval| {
+                    Cell::from(
+                        count(Some(player.id), Some(*eval))
+                            .map_or("-".to_string(), |v| v.to_string()),
+                    )
+                }));
+                cells.push(Cell::from(pct(stats.reception_sideout_percentage(
+                    Some(player.id),
+                    rotation,
+                    None,
+                ))));
+                cells.push(Cell::from(pct(stats.reception_kill_percentage(
+                    Some(player.id),
+                    rotation,
+                    None,
+                ))));
+                let row = Row::new(cells);
+                if selected_player == Some(player.id) {
+                    row.style(Style::default().add_modifier(Modifier::REVERSED))
+                } else {
+                    row
+                }
+            })
+            .collect();
+        let mut header = vec![
+            labels.player.to_string(),
+            labels.tot.to_string(),
+            labels.share.to_string(),
+        ];
+        header.extend(pass_evals.iter().map(|eval| eval.to_string()));
+        header.push(labels.sideout_perc.to_string());
+        header.push(labels.pt_perc.to_string());
+        let mut widths = vec![
+            Constraint::Length(24),
+            Constraint::Length(5),
+            Constraint::Length(7),
+        ];
+        widths.extend(pass_evals.iter().map(|_| Constraint::Length(4)));
+        widths.extend([Constraint::Length(8), Constraint::Length(8)]);
+        let receivers_table = Table::new(rows, widths)
+            .header(Row::new(header).style(Style::default().add_modifier(Modifier::BOLD)))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(labels.reception_analysis),
+            );
+        f.render_widget(receivers_table, chunks[0]);
+        let quality_rows: Vec<Row> = [Perfect, Positive, Exclamative, Negative]
+            .iter()
+            .map(|eval| {
+                Row::new(vec![
+                    Cell::from(format!(
+                        "{} {}",
+                        eval,
+                        eval.friendly_name(EventTypeEnum::P, labels)
+                    )),
+                    Cell::from(
+                        count(selected_player, Some(*eval))
+                            .map_or("-".to_string(), |v| v.to_string()),
+                    ),
+                    Cell::from(pct(stats.reception_sideout_percentage(
+                        selected_player,
+                        rotation,
+                        Some(*eval),
+                    ))),
+                    Cell::from(pct(stats.reception_kill_percentage(
+                        selected_player,
+                        rotation,
+                        Some(*eval),
+                    ))),
+                ])
+            })
+            .collect();
+        let quality_table =
+            Table::new(
+                quality_rows,
+                [
+                    Constraint::Length(28),
+                    Constraint::Length(6),
+                    Constraint::Length(10),
+                    Constraint::Length(10),
+                ],
+            )
+            .header(
+                Row::new(vec![
+                    labels.pass_quality,
+                    labels.tot,
+                    labels.sideout_perc,
+                    labels.pt_perc,
+                ])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+            )
+            .block(Block::default().borders(Borders::ALL).title(
+                match self.player_filter.selected() {
+                    Some(player) => format!("{} - {}", labels.pass_quality, player),
+                    None => labels.pass_quality.to_string(),
+                },
+            ));
+        f.render_widget(quality_table, chunks[1]);
+    }
+
+    fn render_setter_distribution(&self, f: &mut Frame, area: Rect) {
+        use EvalEnum::*;
+        use ZoneEnum::*;
+        let labels = current_labels();
+        let set = self.set_filter.selected().copied();
+        let rotation = self.rotation_filter.selected().cloned().map(|r| r as u8);
+        let phase = self.phase_filter.selected().cloned();
+        let Some(stats) = self.get_current_stats(set) else {
+            return;
+        };
+        let zones = [Four, Three, Two, Eight, Nine];
+        let rows: Vec<Row> = [
+            Some(Perfect),
+            Some(Positive),
+            Some(Exclamative),
+            Some(Negative),
+        ]
+        .into_iter()
+        .chain(once(None))
+        .map(|eval| {
+            let top_zone = stats.distribution.top_zone(phase, rotation, None, eval);
+            let mut cells = vec![
+                Cell::from(eval.map_or(labels.global.to_string(), |e| {
+                    format!("{} {}", e, e.friendly_name(EventTypeEnum::P, labels))
+                })),
+                Cell::from(top_zone.map_or("-".to_string(), |(_, _, total)| total.to_string())),
+            ];
+            cells.extend(zones.iter().map(|zone| {
+                Cell::from(
+                    stats
+                        .distribution
+                        .zone_stats(*zone, phase, rotation, None, eval)
+                        .map_or("-".to_string(), |(share, kill)| {
+                            format!("{:.0}%/{:.0}%", share, kill)
+                        }),
+                )
+            }));
+            cells.push(Cell::from(
+                top_zone.map_or("-".to_string(), |(zone, _, _)| zone.to_string()),
+            ));
+            cells.push(Cell::from(
+                top_zone.map_or("-".to_string(), |(_, share, _)| format!("{:.1}%", share)),
+            ));
+            let row = Row::new(cells);
+            if eval.is_none() {
+                row.style(Style::default().add_modifier(Modifier::BOLD))
+            } else {
+                row
+            }
+        })
+        .collect();
+        let mut header = vec![labels.pass_quality.to_string(), labels.tot.to_string()];
+        header.extend(zones.iter().map(|zone| format!("{} {}", labels.zone, zone)));
+        header.push(labels.top_zone.to_string());
+        header.push(labels.predictability.to_string());
+        let mut widths = vec![Constraint::Length(24), Constraint::Length(5)];
+        widths.extend(zones.iter().map(|_| Constraint::Length(10)));
+        widths.extend([Constraint::Length(9), Constraint::Length(14)]);
+        let table = Table::new(rows, widths)
+            .header(Row::new(header).style(Style::default().add_modifier(Modifier::BOLD)))
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                "{} ({})",
+                labels.setter_distribution, labels.distribution_kill
+            )));
+        f.render_widget(table, area);
+    }
+
+    fn render_plus_minus(&self, f: &mut Frame, area: Rect) {
+        let labels = current_labels();
+        let set = self.set_filter.selected().copied();
+        let phase = self.phase_filter.selected().cloned();
+        let selected_player = self.player_filter.selected().map(|p| p.id);
+        let Some(stats) = self.get_current_stats(set) else {
+            return;
+        };
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+        let number = |id: &Uuid| {
+            self.player_filter
+                .items
+                .iter()
+                .flatten()
+                .find(|p| p.id == *id)
+                .map_or("?".to_string(), |p| p.number.to_string())
+        };
+        let sort = self.plus_minus_sort;
+        let to_rows = |mut entries: Vec<(Vec<Uuid>, u32, u32)>| -> Vec<Row> {
+            entries.retain(|(players, _, _)| selected_player.is_none_or(|p| players.contains(&p)));
+            // stable sort: ties keep a deterministic order
+            entries.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+            entries.sort_by_key(|(_, won, lost)| {
+                let rallies = (won + lost) as i32;
+                let balance = *won as i32 - *lost as i32;
+                match sort {
+                    PlusMinusSort::Balance => (Reverse(balance), Reverse(rallies)),
+                    PlusMinusSort::Rallies => (Reverse(rallies), Reverse(balance)),
+                }
+            });
+            entries
+                .into_iter()
+                .map(|(players, won, lost)| {
+                    let mut numbers: Vec<String> = players.iter().map(number).collect();
+                    numbers.sort_by_key(|n| n.parse::<u8>().unwrap_or(u8::MAX));
+                    Row::new(vec![
+                        Cell::from(numbers.join("-")),
+                        Cell::from((won + lost).to_string()),
+                        Cell::from(won.to_string()),
+                        Cell::from(lost.to_string()),
+                        Cell::from(format!("{:+}", won as i32 - lost as i32)),
+                    ])
+                })
+                .collect()
+        };
+        let sorted_by = match sort {
+            PlusMinusSort::Balance => labels.plus_minus,
+            PlusMinusSort::Rallies => labels.rallies,
+        };
+        let header = |first: &str| {
+            Row::new(vec![
+                first.to_string(),
+                labels.rallies.to_string(),
+                labels.won.to_string(),
+                labels.lost.to_string(),
+                labels.plus_minus.to_string(),
+            ])
+            .style(Style::default().add_modifier(Modifier::BOLD))
+        };
+        let widths = [
+            Constraint::Length(24),
+            Constraint::Length(6),
+            Constraint::Length(6),
+            Constraint::Length(6),
+            Constraint::Length(6),
+        ];
+        let combinations = to_rows(
+            stats
+                .combinations_won_lost(phase)
+                .into_iter()
+                .map(|(players, won, lost)| (players.to_vec(), won, lost))
+                .collect(),
+        );
+        let combinations_table = Table::new(combinations, widths)
+            .header(header(labels.combination))
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                "{} ({} {})",
+                labels.lineup_combinations, labels.sorted_by, sorted_by
+            )));
+        f.render_widget(combinations_table, chunks[0]);
+        let pairs = to_rows(
+            stats
+                .pairs_won_lost(phase)
+                .into_iter()
+                .map(|(players, won, lost)| (players.to_vec(), won, lost))
+                .collect(),
+        );
+        let pairs_table = Table::new(pairs, widths).header(header(labels.pair)).block(
+            Block::default().borders(Borders::ALL).title(format!(
+                "{} ({} {})",
+                labels.pairs, labels.sorted_by, sorted_by
+            )),
+        );
+        f.render_widget(pairs_table, chunks[1]);
     }
 
     fn render_left(&mut self, f: &mut Frame, area: Rect) {
@@ -746,8 +1303,9 @@ impl MatchStatsScreen {
                 Constraint::Length(8),  // set
                 Constraint::Length(9),  // rotation
                 Constraint::Length(5),  // phase
-                Constraint::Length(9),  // event
+                Constraint::Length(13), // event
                 Constraint::Length(14), // player
+                Constraint::Min(3),     // tag
             ])
             .split(area);
 
@@ -756,5 +1314,6 @@ impl MatchStatsScreen {
         self.phase_filter.render(f, chunks[2]);
         self.event_filter.render(f, chunks[3]);
         self.player_filter.render(f, chunks[4]);
+        self.tag_filter.render(f, chunks[5]);
     }
 }
@@ -1,10 +1,16 @@
 pub mod add_match_screen;
+pub mod attach_video_screen;
+pub mod attack_combinations_screen;
 pub mod components;
+pub mod edit_attack_combination_screen;
+pub mod edit_opponent_screen;
 pub mod edit_player_screen;
 pub mod edit_team_screen;
+pub mod export_clips_screen;
 pub mod export_match_screen;
 pub mod export_team_screen;
 pub mod file_system_screen;
+pub mod head_to_head_screen;
 pub mod import_match_screen;
 pub mod import_team_screen;
 pub mod keybindings_action_add_screen;
@@ -12,10 +18,13 @@ pub mod keybindings_action_screen;
 pub mod keybindings_screen;
 pub mod match_list_screen;
 pub mod match_stats_screen;
+pub mod opponent_list_screen;
+pub mod rallies_screen;
 pub mod report_an_issue_screen;
 pub mod scouting_screen;
 pub mod screen;
 pub mod settings_screen;
 pub mod start_set_screen;
+pub mod sync_video_screen;
 pub mod team_details_screen;
 pub mod team_list_screen;
@@ -0,0 +1,293 @@
+use std::sync::Arc;
+
+use crate::{
+    localization::current_labels,
+    providers::{opponent_reader::OpponentReader, opponent_writer::OpponentWriter},
+    screens::{
+        components::{
+            navigation_footer::NavigationFooter, notify_banner::NotifyBanner,
+            team_header::TeamHeader,
+        },
+        edit_opponent_screen::EditOpponentScreen,
+        head_to_head_screen::HeadToHeadScreen,
+        screen::{get_keybinding_actions, AppAction, Renderable, Sba, ScreenAsync},
+    },
+    shapes::{
+        enums::{FriendlyName, ScreenActionEnum},
+        keybinding::{KeyBindings, ScreenKeyBindings},
+        opponent::OpponentEntry,
+        r#match::MatchEntry,
+        settings::Settings,
+        team::TeamEntry,
+    },
+};
+use async_trait::async_trait;
+use crokey::crossterm::event::KeyEvent;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, ListState, Paragraph, Row, Table},
+    Frame,
+};
+
+#[derive(Debug)]
+pub struct OpponentListScreen<OR: OpponentReader + Send + Sync, OW: OpponentWriter + Send + Sync> {
+    settings: Settings,
+    list_state: ListState,
+    team: TeamEntry,
+    matches: Vec<MatchEntry>,
+    opponents: Vec<OpponentEntry>,
+    notify_message: NotifyBanner,
+    header: TeamHeader,
+    footer: NavigationFooter,
+    opponent_reader: Arc<OR>,
+    opponent_writer: Arc<OW>,
+    screen_key_bindings: ScreenKeyBindings,
+}
+
+impl<OR: OpponentReader + Send + Sync + 'static, OW: OpponentWriter + Send + Sync + 'static>
+    Renderable for OpponentListScreen<OR, OW>
+{
+    fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
+        let container = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(5), Constraint::Min(1)])
+            .split(body);
+        let selected = self.list_state.selected().unwrap_or(0);
+        let rows: Vec<Row> = self
+            .opponents
+            .iter()
+            .enumerate()
+            .map(|(i, o)| self.get_opponent_row(o, i == selected))
+            .collect();
+        let widths = [
+            Constraint::Length(30),
+            Constraint::Length(40),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Length(30),
+        ];
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(vec![
+                    current_labels().name,
+                    current_labels().aliases,
+                    current_labels().matches,
+                    current_labels().won_lost,
+                    current_labels().team_classification,
+                ])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+            )
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(current_labels().opponents),
+            );
+        if self.opponents.is_empty() {
+            self.render_no_opponents_yet(f, container[1]);
+        } else {
+            f.render_widget(table, container[1]);
+        }
+        self.header.render(f, container[0], Some(&self.team));
+        self.notify_message.render(f, footer_right);
+        let kb: &KeyBindings = &self.settings.keybindings;
+        let screen_actions = &self.screen_actions();
+        let footer_entries = get_keybinding_actions(kb, screen_actions);
+        self.footer.render(f, footer_left, footer_entries);
+        self.screen_key_bindings = kb.slice(Sba::keys(screen_actions));
+    }
+}
+
+#[async_trait]
+impl<OR: OpponentReader + Send + Sync + 'static, OW: OpponentWriter + Send + Sync + 'static>
+    ScreenAsync for OpponentListScreen<OR, OW>
+{
+    async fn handle_key(&mut self, key: KeyEvent) -> AppAction {
+        if let Some(key_combination) = self.screen_key_bindings.transform(key) {
+            match (
+                self.screen_key_bindings.get(key_combination),
+                &self.notify_message.has_value(),
+            ) {
+                (_, true) => {
+                    self.notify_message.reset();
+                    AppAction::None
+                }
+                (Some(ScreenActionEnum::Next), _) => self.next_opponent(),
+                (Some(ScreenActionEnum::Previous), _) => self.previous_opponent(),
+                (Some(ScreenActionEnum::Select), _) => match self.get_selected_opponent() {
+                    Some(opponent) => AppAction::SwitchScreen(Box::new(HeadToHeadScreen::new(
+                        self.settings.clone(),
+                        self.team.clone(),
+                        opponent.clone(),
+                        self.matches
+                            .iter()
+                            .filter(|m| opponent.played(m))
+                            .cloned()
+                            .collect(),
+                    ))),
+                    None => {
+                        self.notify_message
+                            .set_error(current_labels().no_opponent_selected.to_string());
+                        AppAction::None
+                    }
+                },
+                (Some(ScreenActionEnum::New), _) => {
+                    AppAction::SwitchScreen(Box::new(EditOpponentScreen::new(
+                        self.settings.clone(),
+                        self.opponents.clone(),
+                        self.opponent_writer.clone(),
+                    )))
+                }
+                (Some(ScreenActionEnum::Edit), _) => match self.get_selected_opponent() {
+                    Some(opponent) => AppAction::SwitchScreen(Box::new(EditOpponentScreen::edit(
+                        self.settings.clone(),
+                        opponent,
+                        self.opponents.clone(),
+                        self.opponent_writer.clone(),
+                    ))),
+                    None => AppAction::None,
+                },
+                (Some(ScreenActionEnum::Back), _) => AppAction::Back(true, Some(1)),
+                (Some(ScreenActionEnum::Quit), _) => AppAction::Quit(Ok(())),
+                _ => AppAction::None,
+            }
+        } else {
+            AppAction::None
+        }
+    }
+
+    async fn refresh_data(&mut self) {
+        match self.opponent_reader.read_all().await {
+            Ok(opponents) => {
+                self.opponents = opponents;
+                if self.opponents.is_empty() {
+                    self.list_state.select(None);
+                } else if let Some(selected) = self.list_state.selected() {
+                    if selected >= self.opponents.len() {
+                        self.list_state.select(Some(self.opponents.len() - 1));
+                    }
+                } else {
+                    self.list_state.select(Some(0));
+                }
+            }
+            Err(_) => {
+                self.notify_message
+                    .set_error(current_labels().could_not_load_opponents.to_string());
+            }
+        }
+    }
+}
+
+impl<OR: OpponentReader + Send + Sync + 'static, OW: OpponentWriter + Send + Sync + 'static>
+    OpponentListScreen<OR, OW>
+{
+    pub fn new(
+        settings: Settings,
+        team: TeamEntry,
+        matches: Vec<MatchEntry>,
+        opponents: Vec<OpponentEntry>,
+        opponent_reader: Arc<OR>,
+        opponent_writer: Arc<OW>,
+    ) -> Self {
+        let mut list_state = ListState::default();
+        if !opponents.is_empty() {
+            list_state.select(Some(0));
+        }
+        OpponentListScreen {
+            settings,
+            list_state,
+            team,
+            matches,
+            opponents,
+            notify_message: NotifyBanner::new(),
+            header: TeamHeader::default(),
+            footer: NavigationFooter::new(),
+            opponent_reader,
+            opponent_writer,
+            screen_key_bindings: ScreenKeyBindings::empty(),
+        }
+    }
+
+    fn get_selected_opponent(&self) -> Option<&OpponentEntry> {
+        self.list_state
+            .selected()
+            .and_then(|i| self.opponents.get(i))
+    }
+
+    fn get_opponent_row(&self, opponent: &OpponentEntry, selected: bool) -> Row<'_> {
+        let statuses: Vec<_> = self
+            .matches
+            .iter()
+            .filter(|m| opponent.played(m))
+            .filter_map(|m| m.get_status().ok())
+            .collect();
+        let won = statuses
+            .iter()
+            .filter(|s| s.match_finished && s.us_wins > s.them_wins)
+            .count();
+        let lost = statuses
+            .iter()
+            .filter(|s| s.match_finished && s.them_wins > s.us_wins)
+            .count();
+        let row = Row::new(vec![
+            opponent.name.clone(),
+            opponent.aliases.join(", "),
+            statuses.len().to_string(),
+            format!("{}-{}", won, lost),
+            opponent
+                .classification
+                .map(|c| c.friendly_name(current_labels()).to_string())
+                .unwrap_or_default(),
+        ]);
+        if selected {
+            row.style(Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD))
+        } else {
+            row
+        }
+    }
+
+    fn next_opponent(&mut self) -> AppAction {
+        if let Some(selected) = self.list_state.selected() {
+            let new_selected = (selected + 1).min(self.opponents.len() - 1);
+            self.list_state.select(Some(new_selected));
+        };
+        AppAction::None
+    }
+
+    fn previous_opponent(&mut self) -> AppAction {
+        if let Some(selected) = self.list_state.selected() {
+            let new_selected = if selected == 0 { 0 } else { selected - 1 };
+            self.list_state.select(Some(new_selected));
+        };
+        AppAction::None
+    }
+
+    fn screen_actions(&self) -> Vec<Sba> {
+        let mut actions = Vec::new();
+        if !self.opponents.is_empty() {
+            actions.push(Sba::Simple(ScreenActionEnum::Next));
+            actions.push(Sba::Simple(ScreenActionEnum::Previous));
+            actions.push(Sba::Simple(ScreenActionEnum::Select));
+            actions.push(Sba::Simple(ScreenActionEnum::Edit));
+        }
+        actions.push(Sba::Simple(ScreenActionEnum::New));
+        actions.push(Sba::Simple(ScreenActionEnum::Back));
+        actions.push(Sba::Simple(ScreenActionEnum::Quit));
+        actions
+    }
+
+    fn render_no_opponents_yet(&self, f: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(40),
+                Constraint::Length(3),
+                Constraint::Percentage(40),
+            ])
+            .split(area);
+        let paragraph = Paragraph::new(current_labels().no_opponents_yet)
+            .block(Block::default().borders(Borders::NONE))
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, chunks[1]);
+    }
+}
@@ -0,0 +1,492 @@
+use crate::{
+    localization::current_labels,
+    providers::{
+        match_reader::MatchReader, match_writer::MatchWriter, set_writer::SetWriter,
+        settings_reader::SettingsReader, settings_writer::SettingsWriter, team_reader::TeamReader,
+        team_writer::TeamWriter,
+    },
+    screens::{
+        attach_video_screen::AttachVideoAction,
+        components::{navigation_footer::NavigationFooter, notify_banner::NotifyBanner},
+        export_clips_screen::ExportClipsScreen,
+        file_system_screen::FileSystemScreen,
+        screen::{get_keybinding_actions, AppAction, Renderable, Sba, ScreenAsync},
+        start_set_screen::StartSetScreen,
+        sync_video_screen::SyncVideoScreen,
+    },
+    shapes::{
+        enums::{FriendlyName, ScreenActionEnum, TeamSideEnum},
+        keybinding::ScreenKeyBindings,
+        r#match::MatchEntry,
+        rally::RallyEntry,
+        set::SetEntry,
+        settings::Settings,
+        timing::format_seconds,
+    },
+};
+use async_trait::async_trait;
+use crokey::crossterm::event::KeyEvent;
+use dirs::home_dir;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, ListState, Paragraph, Row, Table},
+    Frame,
+};
+use std::sync::Arc;
+
+/// Browses the rallies of a match, set by set, with the events of the selected one.
+///
+/// Sets can be linked to their video recording, which is synced on the serve of the selected
+/// rally, so that clips of the scouted events can be exported. The starting setup of the
+/// selected set can be corrected as well.
+#[derive(Debug)]
+pub struct RalliesScreen<
+    MR: MatchReader + Send + Sync,
+    MW: MatchWriter + Send + Sync,
+    SSW: SetWriter + Send + Sync,
+    TR: TeamReader + Send + Sync,
+    TW: TeamWriter + Send + Sync,
+    SR: SettingsReader + Send + Sync,
+    SW: SettingsWriter + Send + Sync,
+> {
+    settings: Settings,
+    current_match: MatchEntry,
+    list_state: ListState,
+    rallies: Vec<(u8, RallyEntry)>, // (set number, rally)
+    notify_message: NotifyBanner,
+    footer: NavigationFooter,
+    footer_entries: Vec<(String, String)>,
+    screen_key_bindings: ScreenKeyBindings,
+    match_reader: Arc<MR>,
+    match_writer: Arc<MW>,
+    set_writer: Arc<SSW>,
+    team_reader: Arc<TR>,
+    team_writer: Arc<TW>,
+    settings_reader: Arc<SR>,
+    settings_writer: Arc<SW>,
+}
+
+impl<
+        MR: MatchReader + Send + Sync + 'static,
+        MW: MatchWriter + Send + Sync + 'static,
+        SSW: SetWriter + Send + Sync + 'static,
+        TR: TeamReader + Send + Sync + 'static,
+        TW: TeamWriter + Send + Sync + 'static,
+        SR: SettingsReader + Send + Sync + 'static,
+        SW: SettingsWriter + Send + Sync + 'static,
+    > Renderable for RalliesScreen<MR, MW, SSW, TR, TW, SR, SW>
+{
+    fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
+        let container = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(body);
+        if self.rallies.is_empty() {
+            self.render_no_rallies_yet(f, body);
+        } else {
+            self.render_rallies(f, container[0]);
+            self.render_rally_events(f, container[1]);
+        }
+        self.notify_message.render(f, footer_right);
+        self.footer
+            .render(f, footer_left, self.footer_entries.clone());
+    }
+}
+
+#[async_trait]
+impl<
+        MR: MatchReader + Send + Sync + 'static,
+        MW: MatchWriter + Send + Sync + 'static,
+        SSW: SetWriter + Send + Sync + 'static,
+        TR: TeamReader + Send + Sync + 'static,
+        TW: TeamWriter + Send + Sync + 'static,
+        SR: SettingsReader + Send + Sync + 'static,
+        SW: SettingsWriter + Send + Sync + 'static,
+    > ScreenAsync for RalliesScreen<MR, MW, SSW, TR, TW, SR, SW>
+{
+    async fn handle_key(&mut self, key: KeyEvent) -> AppAction {
+        if let Some(key_combination) = self.screen_key_bindings.transform(key) {
+            match (
+                self.screen_key_bindings.get(key_combination),
+                &self.notify_message.has_value(),
+            ) {
+                (_, true) => {
+                    self.notify_message.reset();
+                    AppAction::None
+                }
+                (Some(ScreenActionEnum::Next), _) => self.next_rally(),
+                (Some(ScreenActionEnum::Previous), _) => self.previous_rally(),
+                (Some(ScreenActionEnum::AttachVideo), _) => self.handle_attach_video(),
+                (Some(ScreenActionEnum::SyncVideo), _) => self.handle_sync_video(),
+                (Some(ScreenActionEnum::EditSetup), _) => self.handle_edit_setup(),
+                (Some(ScreenActionEnum::ExportClips), _) => {
+                    AppAction::SwitchScreen(Box::new(ExportClipsScreen::new(
+                        self.settings.clone(),
+                        self.current_match.clone(),
+                        self.settings_reader.clone(),
+                        self.settings_writer.clone(),
+                    )))
+                }
+                (Some(ScreenActionEnum::Back), _) => AppAction::Back(true, Some(1)),
+                (Some(ScreenActionEnum::Quit), _) => AppAction::Quit(Ok(())),
+                _ => AppAction::None,
+            }
+        } else {
+            AppAction::None
+        }
+    }
+
+    async fn refresh_data(&mut self) {
+        // the video of a set might have been attached or synced
+        match self
+            .match_reader
+            .read_single(&self.current_match.team, &self.current_match.id)
+            .await
+        {
+            Ok(m) => {
+                self.rallies = Self::compute_rallies(&m);
+                self.current_match = m;
+                self.update_footer();
+            }
+            Err(_) => self
+                .notify_message
+                .set_error(current_labels().could_not_load_matches.to_string()),
+        }
+    }
+}
+
+impl<
+        MR: MatchReader + Send + Sync + 'static,
+        MW: MatchWriter + Send + Sync + 'static,
+        SSW: SetWriter + Send + Sync + 'static,
+        TR: TeamReader + Send + Sync + 'static,
+        TW: TeamWriter + Send + Sync + 'static,
+        SR: SettingsReader + Send + Sync + 'static,
+        SW: SettingsWriter + Send + Sync + 'static,
+    > RalliesScreen<MR, MW, SSW, TR, TW, SR, SW>
+{
+    pub fn new(
+        settings: Settings,
+        current_match: MatchEntry,
+        match_reader: Arc<MR>,
+        match_writer: Arc<MW>,
+        set_writer: Arc<SSW>,
+        team_reader: Arc<TR>,
+        team_writer: Arc<TW>,
+        settings_reader: Arc<SR>,
+        settings_writer: Arc<SW>,
+    ) -> Self {
+        let rallies = Self::compute_rallies(&current_match);
+        let mut list_state = ListState::default();
+        if !rallies.is_empty() {
+            list_state.select(Some(0));
+        }
+        let mut screen = RalliesScreen {
+            settings,
+            current_match,
+            list_state,
+            rallies,
+            notify_message: NotifyBanner::new(),
+            footer: NavigationFooter::new(),
+            footer_entries: vec![],
+            screen_key_bindings: ScreenKeyBindings::empty(),
+            match_reader,
+            match_writer,
+            set_writer,
+            team_reader,
+            team_writer,
+            settings_reader,
+            settings_writer,
+        };
+        screen.update_footer();
+        screen
+    }
+
+    fn compute_rallies(current_match: &MatchEntry) -> Vec<(u8, RallyEntry)> {
+        let mut rallies = vec![];
+        for set in &current_match.sets {
+            if let Ok((snapshot, _)) = set.compute_snapshot() {
+                rallies.extend(
+                    snapshot
+                        .rallies
+                        .into_iter()
+                        .chain(snapshot.current_rally)
+                        .map(|rally| (set.set_number, rally)),
+                );
+            }
+        }
+        rallies
+    }
+
+    fn update_footer(&mut self) {
+        let mut screen_actions = vec![];
+        if !self.rallies.is_empty() {
+            screen_actions.extend([
+                Sba::Simple(ScreenActionEnum::Next),
+                Sba::Simple(ScreenActionEnum::Previous),
+                Sba::Simple(ScreenActionEnum::AttachVideo),
+                Sba::Simple(ScreenActionEnum::EditSetup),
+            ]);
+        }
+        if self.selected_set().is_some_and(|set| set.video.is_some()) {
+            screen_actions.push(Sba::Simple(ScreenActionEnum::SyncVideo));
+        }
+        if self
+            .current_match
+            .sets
+            .iter()
+            .any(|set| set.video.is_some())
+        {
+            screen_actions.push(Sba::Simple(ScreenActionEnum::ExportClips));
+        }
+        screen_actions.extend([
+            Sba::Simple(ScreenActionEnum::Back),
+            Sba::Simple(ScreenActionEnum::Quit),
+        ]);
+        let kb = &self.settings.keybindings;
+        self.footer_entries = get_keybinding_actions(kb, &screen_actions);
+        self.screen_key_bindings = kb.slice(Sba::keys(&screen_actions));
+    }
+
+    fn selected_set(&self) -> Option<&SetEntry> {
+        let (set_number, _) = self
+            .list_state
+            .selected()
+            .and_then(|i| self.rallies.get(i))?;
+        self.current_match
+            .sets
+            .iter()
+            .find(|s| s.set_number == *set_number)
+    }
+
+    fn handle_attach_video(&mut self) -> AppAction {
+        let Some(set) = self.selected_set().cloned() else {
+            return AppAction::None;
+        };
+        match home_dir() {
+            Some(path) => AppAction::SwitchScreen(Box::new(FileSystemScreen::new(
+                self.settings.clone(),
+                path,
+                current_labels().attach_video,
+                AttachVideoAction::new(self.current_match.clone(), set, self.set_writer.clone()),
+                self.settings_reader.clone(),
+                self.settings_writer.clone(),
+            ))),
+            None => {
+                self.notify_message.set_error(
+                    current_labels()
+                        .could_not_recognize_home_directory
+                        .to_string(),
+                );
+                AppAction::None
+            }
+        }
+    }
+
+    fn handle_sync_video(&mut self) -> AppAction {
+        let event = self
+            .list_state
+            .selected()
+            .and_then(|i| self.rallies.get(i))
+            .and_then(|(_, rally)| rally.events.first().cloned());
+        match (self.selected_set().cloned(), event) {
+            (Some(set), Some(event)) if set.video.is_some() => {
+                AppAction::SwitchScreen(Box::new(SyncVideoScreen::new(
+                    self.settings.clone(),
+                    self.current_match.clone(),
+                    set,
+                    event,
+                    self.set_writer.clone(),
+                )))
+            }
+            _ => {
+                self.notify_message
+                    .set_error(current_labels().no_video.to_string());
+                AppAction::None
+            }
+        }
+    }
+
+    /// Where the rally starts into the video of its set, if any.
+    fn handle_edit_setup(&mut self) -> AppAction {
+        match self.selected_set().cloned() {
+            Some(set) => AppAction::SwitchScreen(Box::new(StartSetScreen::edit(
+                self.settings.clone(),
+                self.current_match.clone(),
+                set,
+                Some(1),
+                self.match_writer.clone(),
+                self.set_writer.clone(),
+                self.team_reader.clone(),
+                self.team_writer.clone(),
+            ))),
+            None => AppAction::None,
+        }
+    }
+
+    fn video_position(&self, set_number: u8, rally: &RallyEntry) -> Option<String> {
+        let video = self
+            .current_match
+            .sets
+            .iter()
+            .find(|s| s.set_number == set_number)?
+            .video
+            .as_ref()?;
+        let position = video.position(rally.events.first()?.timestamp);
+        (position >= 0.0).then(|| format_seconds(position as i64))
+    }
+
+    fn team_name(&self, side: TeamSideEnum) -> &str {
+        match side {
+            TeamSideEnum::Us => &self.current_match.team.name,
+            TeamSideEnum::Them => &self.current_match.opponent,
+        }
+    }
+
+    fn render_rallies(&self, f: &mut Frame, area: Rect) {
+        let labels = current_labels();
+        let selected = self.list_state.selected();
+        let mut rally_number = 0;
+        let mut previous_set = 0;
+        let rows: Vec<Row> = self
+            .rallies
+            .iter()
+            .enumerate()
+            .map(|(i, (set_number, rally))| {
+                // rallies are numbered within their set
+                if *set_number != previous_set {
+                    previous_set = *set_number;
+                    rally_number = 0;
+                }
+                rally_number += 1;
+                let row = Row::new(vec![
+                    set_number.to_string(),
+                    rally_number.to_string(),
+                    self.team_name(rally.serving_team).to_string(),
+                    format!("{}{}", labels.setter_prefix, rally.rotation + 1),
+                    rally.contacts().to_string(),
+                    rally
+                        .duration()
+                        .map_or("-".to_string(), |d| format!("{}s", d.num_seconds())),
+                    rally
+                        .winner
+                        .map_or(labels.in_progress, |w| self.team_name(w))
+                        .to_string(),
+                    format!("{}-{}", rally.score.0, rally.score.1),
+                    self.video_position(*set_number, rally)
+                        .unwrap_or("-".to_string()),
+                ]);
+                let mut style = match rally.winner {
+                    Some(TeamSideEnum::Us) => Style::default().fg(Color::LightGreen),
+                    Some(TeamSideEnum::Them) => Style::default().fg(Color::Red),
+                    None => Style::default().fg(Color::White),
+                };
+                if selected == Some(i) {
+                    style = style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
+                }
+                row.style(style)
+            })
+            .collect();
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(4),
+                Constraint::Length(4),
+                Constraint::Length(20),
+                Constraint::Length(5),
+                Constraint::Length(9),
+                Constraint::Length(9),
+                Constraint::Length(20),
+                Constraint::Length(8),
+                Constraint::Length(8),
+            ],
+        )
+        .header(
+            Row::new(vec![
+                "set",
+                "#",
+                labels.serving,
+                labels.rotation,
+                labels.contacts,
+                labels.duration,
+                labels.winner,
+                labels.score,
+                labels.video,
+            ])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(labels.rally_browser),
+        );
+        f.render_widget(table, area);
+    }
+
+    fn render_rally_events(&self, f: &mut Frame, area: Rect) {
+        let labels = current_labels();
+        let Some((_, rally)) = self.list_state.selected().and_then(|i| self.rallies.get(i)) else {
+            return;
+        };
+        let rows: Vec<Row> = rally
+            .events
+            .iter()
+            .map(|e| {
+                Row::new(vec![
+                    e.event_type.friendly_name(labels).to_string(),
+                    e.player
+                        .and_then(|p| self.current_match.find_player(p))
+                        .map(|p| format!("{} {}", p.number, p.name))
+                        .unwrap_or("-".to_string()),
+                    e.eval
+                        .map(|eval| eval.friendly_name(e.event_type, labels))
+                        .unwrap_or_default(),
+                ])
+            })
+            .collect();
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(14),
+                Constraint::Length(22),
+                Constraint::Min(10),
+            ],
+        )
+        .block(Block::default().borders(Borders::ALL).title(labels.rally));
+        f.render_widget(table, area);
+    }
+
+    fn render_no_rallies_yet(&self, f: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(40),
+                Constraint::Length(3),
+                Constraint::Percentage(40),
+            ])
+            .split(area);
+        let paragraph = Paragraph::new(current_labels().no_rallies_yet)
+            .block(Block::default().borders(Borders::NONE))
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, chunks[1]);
+    }
+
+    fn next_rally(&mut self) -> AppAction {
+        if let Some(selected) = self.list_state.selected() {
+            let new_selected = (selected + 1).min(self.rallies.len() - 1);
+            self.list_state.select(Some(new_selected));
+        };
+        self.update_footer();
+        AppAction::None
+    }
+
+    fn previous_rally(&mut self) -> AppAction {
+        if let Some(selected) = self.list_state.selected() {
+            let new_selected = if selected == 0 { 0 } else { selected - 1 };
+            self.list_state.select(Some(new_selected));
+        };
+        self.update_footer();
+        AppAction::None
+    }
+}
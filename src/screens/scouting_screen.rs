@@ -3,24 +3,37 @@ use crate::screens::screen::{get_keybinding_actions, Sba};
 use crate::shapes::enums::ScreenActionEnum;
 use crate::shapes::keybinding::ScreenKeyBindings;
 use crate::shapes::settings::Settings;
+use crate::shapes::symbol::KeyCombinationFormatExt;
 use crate::{
     localization::current_labels,
-    providers::set_writer::SetWriter,
+    providers::{match_writer::MatchWriter, set_writer::SetWriter},
     screens::{
-        components::{navigation_footer::NavigationFooter, notify_banner::NotifyBanner},
+        components::{
+            navigation_footer::NavigationFooter, notify_banner::NotifyBanner, select::Select,
+            text_box::TextBox,
+        },
         screen::{AppAction, Renderable, ScreenAsync},
     },
     shapes::{
-        enums::{EvalEnum, EventTypeEnum, FriendlyName, RoleEnum},
+        code_line::{resolve_code_line, CodeLineError, CodeLineEvent},
+        enums::{
+            EvalEnum, EventTypeEnum, FriendlyName, LineupWarningEnum, NoteTargetEnum, PhaseEnum,
+            RoleEnum, ServeTypeEnum, TeamSideEnum,
+        },
         player::PlayerEntry,
         r#match::MatchEntry,
         set::SetEntry,
         snapshot::{EventEntry, Snapshot},
+        stats::Metric,
+        tags::{format_tags, parse_tags},
     },
 };
 use async_trait::async_trait;
 use chrono::Utc;
-use crokey::crossterm::event::{KeyCode, KeyEvent};
+use crokey::{
+    crossterm::event::{KeyCode, KeyEvent},
+    KeyCombinationFormat,
+};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -30,8 +43,11 @@ use ratatui::{
 use std::sync::Arc;
 use uuid::Uuid;
 
+// undone events listed above the latest ones
+const REDO_HISTORY_ROWS: usize = 5;
+
 #[derive(Debug)]
-pub struct ScoutingScreen<SSW: SetWriter + Send + Sync> {
+pub struct ScoutingScreen<MW: MatchWriter + Send + Sync, SSW: SetWriter + Send + Sync> {
     settings: Settings,
     current_match: MatchEntry,
     set: SetEntry,
@@ -39,11 +55,28 @@ pub struct ScoutingScreen<SSW: SetWriter + Send + Sync> {
     currently_available_options: Vec<EventTypeEnum>,
     current_event: EventTypeInput,
     player: Option<Uuid>,
+    other_blockers: Vec<Uuid>,
+    touched: bool,
+    combination: String,
+    serve_type: Option<ServeTypeEnum>,
+    note_target: Select<NoteTargetEnum>,
+    notes: TextBox,
+    tags: TextBox,
+    note_field: usize,
+    code_line: TextBox,
+    // event waiting for confirmation because it breaks the tracked lineup
+    pending_event: Option<EventEntry>,
+    lineup_warnings: Vec<LineupWarningEnum>,
+    // whether the pending event is the first code of the code line, which goes on once added
+    pending_from_code_line: bool,
+    // undone events, the last one is the first to be redone
+    redo_stack: Vec<EventEntry>,
+    show_stats: bool,
     state: ScoutingScreenState,
     notify_message: NotifyBanner,
     back_stack_count: Option<u8>,
-    back: bool,
     footer: NavigationFooter,
+    match_writer: Arc<MW>,
     set_writer: Arc<SSW>,
     screen_key_bindings: ScreenKeyBindings,
 }
@@ -60,21 +93,21 @@ pub struct LineupChoiceEntry {
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum EventTypeInput {
     Some(EventTypeEnum),
-    Partial(char),
+    // the first key of a two keys event
+    Partial(ScreenActionEnum),
+    // a score adjustment awarding the point to the given team
+    Adjustment(TeamSideEnum),
     None,
 }
 
 impl EventTypeInput {
     pub fn is_allowed_for(&self, role: RoleEnum) -> bool {
-        use EventTypeEnum::*;
-        use EventTypeInput::*;
-        !matches!(
-            (self, role),
-            (Some(A), RoleEnum::Libero)
-                | (Some(B), RoleEnum::Libero)
-                | (Some(S), RoleEnum::Libero)
-                | (Some(CS), RoleEnum::Libero)
-        )
+        match (self, role) {
+            (EventTypeInput::Some(event_type), RoleEnum::Libero) => {
+                event_type.is_allowed_for_libero()
+            }
+            _ => true,
+        }
     }
 }
 
@@ -84,9 +117,15 @@ enum ScoutingScreenState {
     Player,
     Eval,
     Replacement,
+    Notes,
+    LineupWarning,
+    CodeLine,
+    SetOver,
 }
 
-impl<SSW: SetWriter + Send + Sync> Renderable for ScoutingScreen<SSW> {
+impl<MW: MatchWriter + Send + Sync, SSW: SetWriter + Send + Sync> Renderable
+    for ScoutingScreen<MW, SSW>
+{
     fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
         let rows = Layout::default()
             .direction(Direction::Vertical)
@@ -95,11 +134,22 @@ impl<SSW: SetWriter + Send + Sync> Renderable for ScoutingScreen<SSW> {
         let (header, body) = (rows[0], rows[1]);
         let columns = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(40),
-                Constraint::Percentage(20),
-                Constraint::Percentage(40),
-            ])
+            .constraints(if self.show_stats {
+                [
+                    Constraint::Percentage(30),
+                    Constraint::Percentage(15),
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(30),
+                ]
+                .as_slice()
+            } else {
+                [
+                    Constraint::Percentage(40),
+                    Constraint::Percentage(20),
+                    Constraint::Percentage(40),
+                ]
+                .as_slice()
+            })
             .split(body);
         let left = Layout::default()
             .direction(Direction::Vertical)
@@ -119,24 +169,43 @@ impl<SSW: SetWriter + Send + Sync> Renderable for ScoutingScreen<SSW> {
             ScoutingScreenState::Replacement => {
                 self.render_replacement_choices(f, left_top);
             }
+            ScoutingScreenState::Notes => {
+                self.render_notes(f, left_top);
+            }
+            ScoutingScreenState::LineupWarning => {
+                self.render_lineup_warning(f, left_top);
+            }
+            ScoutingScreenState::CodeLine => {
+                self.render_code_line(f, left_top);
+            }
+            ScoutingScreenState::SetOver => {
+                self.render_set_over(f, left_top);
+            }
         }
         let screen_actions = &self.get_sreen_actions();
         let kb = &self.settings.keybindings.clone();
         let footer_entries = get_keybinding_actions(kb, screen_actions);
-        let screen_key_bindings = kb.slice(Sba::keys(screen_actions));
+        let mut actions = Sba::keys(screen_actions);
+        actions.extend(self.get_scouting_actions());
+        let screen_key_bindings = kb.slice(actions);
 
         self.render_header(f, header);
         self.footer.render(f, footer_left, footer_entries);
         self.render_recent_events(f, left_bottom);
         self.render_set_status(f, center);
         self.render_court(f, right);
+        if self.show_stats {
+            self.render_live_stats(f, columns[3]);
+        }
         self.notify_message.render(f, footer_right);
         self.screen_key_bindings = screen_key_bindings;
     }
 }
 
 #[async_trait]
-impl<SSW: SetWriter + Send + Sync> ScreenAsync for ScoutingScreen<SSW> {
+impl<MW: MatchWriter + Send + Sync, SSW: SetWriter + Send + Sync> ScreenAsync
+    for ScoutingScreen<MW, SSW>
+{
     async fn handle_key(&mut self, key: KeyEvent) -> AppAction {
         use ScoutingScreenState::*;
         if let Some(key_combination) = self.screen_key_bindings.transform(key) {
@@ -148,24 +217,34 @@ impl<SSW: SetWriter + Send + Sync> ScreenAsync for ScoutingScreen<SSW> {
             ) {
                 (true, _, _, _) => {
                     self.notify_message.reset();
-                    if self.back {
-                        return AppAction::Back(true, self.back_stack_count);
-                    } else {
-                        return AppAction::None;
-                    }
+                    return AppAction::None;
+                }
+                (false, action, _, Notes) => self.handle_notes_screen(key, action.cloned()).await,
+                (false, action, _, LineupWarning) => {
+                    self.handle_lineup_warning_screen(action.cloned()).await
+                }
+                (false, action, _, CodeLine) => {
+                    self.handle_code_line_screen(key, action.cloned()).await
                 }
                 (false, Some(ScreenActionEnum::Back), _, _) => {
                     return AppAction::Back(true, self.back_stack_count)
                 }
-                (false, action, _, Event) => self.handle_event_screen(key, action.cloned()).await,
+                (false, Some(ScreenActionEnum::LiveStats), _, _) => {
+                    self.show_stats = !self.show_stats;
+                    AppAction::None
+                }
+                (false, Some(ScreenActionEnum::Undo), _, SetOver) => self.undo_last_event().await,
+                (false, _, _, SetOver) => AppAction::None,
+                (false, Some(ScreenActionEnum::Redo), _, _) => self.redo_event().await,
+                (false, action, _, Event) => self.handle_event_screen(action.cloned()).await,
                 (false, action, _, Player) => {
-                    return self.handle_player_screen(key, action.cloned()).await
+                    return self.handle_player_screen(action.cloned()).await
                 }
                 (false, action, _, Eval) => {
                     return self.handle_eval_screen(key, action.cloned()).await
                 }
                 (false, action, _, Replacement) => {
-                    return self.handle_replacement_screen(key, action.cloned()).await
+                    return self.handle_replacement_screen(action.cloned()).await
                 }
             }
         } else {
@@ -176,7 +255,7 @@ impl<SSW: SetWriter + Send + Sync> ScreenAsync for ScoutingScreen<SSW> {
     async fn refresh_data(&mut self) {}
 }
 
-impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
+impl<MW: MatchWriter + Send + Sync, SSW: SetWriter + Send + Sync> ScoutingScreen<MW, SSW> {
     pub fn new(
         settings: Settings,
         current_match: MatchEntry,
@@ -184,6 +263,7 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
         snapshot: Snapshot,
         available_options: Vec<EventTypeEnum>,
         back_stack_count: Option<u8>,
+        match_writer: Arc<MW>,
         set_writer: Arc<SSW>,
     ) -> Self {
         ScoutingScreen {
@@ -194,11 +274,30 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
             currently_available_options: available_options,
             current_event: EventTypeInput::None,
             player: None,
+            other_blockers: vec![],
+            touched: false,
+            combination: String::new(),
+            serve_type: None,
+            note_target: Select::new(
+                current_labels().note_target.to_owned(),
+                NoteTargetEnum::ALL.to_vec(),
+                None,
+                false,
+            ),
+            notes: TextBox::new(current_labels().notes.to_owned(), false, None),
+            tags: TextBox::new(current_labels().tags.to_owned(), false, None),
+            note_field: 0,
+            code_line: TextBox::new(current_labels().code_line.to_owned(), true, None),
+            pending_event: None,
+            lineup_warnings: vec![],
+            pending_from_code_line: false,
+            redo_stack: vec![],
+            show_stats: false,
             state: ScoutingScreenState::Event,
             notify_message: NotifyBanner::new(),
             back_stack_count,
-            back: false,
             footer: NavigationFooter::new(),
+            match_writer,
             set_writer,
             screen_key_bindings: ScreenKeyBindings::empty(),
         }
@@ -227,12 +326,7 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
                 self.snapshot.current_lineup.get_current_libero() != *id
                     || self.current_event.is_allowed_for(RoleEnum::Libero)
             })
-            // on block, do not allow back players
-            .take_if(|id| {
-                !self.snapshot.current_lineup.is_back_row_player(id)
-                    || self.current_event != EventTypeInput::Some(EventTypeEnum::B)
-            })
-            .and_then(|id| self.current_match.team.find_player(id))
+            .and_then(|id| self.current_match.find_player(id))
             .map(|player| LineupChoiceEntry {
                 index,
                 id: player.id,
@@ -252,7 +346,7 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
             self.snapshot
                 .current_lineup
                 .find_position(&id)
-                .and_then(|_| self.current_match.team.find_player(id))
+                .and_then(|_| self.current_match.find_player(id))
                 .map(|p| LineupChoiceEntry {
                     index,
                     id,
@@ -304,11 +398,28 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
             return AppAction::None;
         };
         self.set.events.pop();
+        // keep the set in the match in step, it could have been completed by the removed event
+        if let Some(existing_set) = self
+            .current_match
+            .sets
+            .iter_mut()
+            .find(|s| s.set_number == self.set.set_number)
+        {
+            *existing_set = self.set.clone();
+        }
         // set the previous (removed) event player
         self.player = removed_event.player;
+        self.other_blockers = [removed_event.second_blocker, removed_event.third_blocker]
+            .into_iter()
+            .flatten()
+            .collect();
+        self.touched = removed_event.touched;
+        self.combination = removed_event.combination.clone().unwrap_or_default();
+        self.serve_type = removed_event.serve_type;
+        self.redo_stack.push(removed_event.clone());
         match (removed_event.event_type, removed_event.player) {
-            (A | B | D | P | S, Some(_)) => {
-                // (A)ttack, (B) block, (D)ig, (P)ass and (S)erve require evaluation
+            (A | B | D | P | S | E | X, Some(_)) => {
+                // (A)ttack, (B) block, (D)ig, (P)ass, (S)erve, s(E)t and free ball (X) require evaluation
                 self.current_event = EventTypeInput::Some(removed_event.event_type);
                 // set the prompt eval state
                 self.state = Eval;
@@ -345,29 +456,132 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
         }
     }
 
-    fn map_key_to_event(&self, key: KeyCode, last_event: &EventTypeInput) -> EventTypeInput {
+    fn map_key_to_event(
+        &self,
+        action: Option<ScreenActionEnum>,
+        last_event: &EventTypeInput,
+    ) -> EventTypeInput {
         use EventTypeEnum::*;
         use EventTypeInput::*;
-        use KeyCode::*;
-        match (key, last_event) {
-            (Char('s'), None) => Some(S),
-            (Char('p'), None) => Some(P),
-            (Char('a'), None) => Some(A),
-            (Char('d'), None) => Some(D),
-            (Char('b'), None) => Some(B),
-            (Char('f'), None) => Some(EventTypeEnum::F),
-            (Char('r'), None) => Some(R),
-            (Char('o'), None) => Partial('o'),
-            (Char('c'), None) => Partial('c'),
-            (Char('e'), Partial('o')) => Some(OE),
-            (Char('s'), Partial('o')) => Some(OS),
-            (Char('l'), Partial('c')) => Some(CL),
-            (Char('s'), Partial('c')) => Some(CS),
+        use ScreenActionEnum::*;
+        let Option::Some(action) = action else {
+            return None;
+        };
+        match (action, last_event) {
+            (Serve, None) => Some(S),
+            (Reception, None) => Some(P),
+            (Attack, None) => Some(A),
+            (Defense, None) => Some(D),
+            (Block, None) => Some(B),
+            (SetTouch, None) => Some(E),
+            (FreeBall, None) => Some(X),
+            (Fault, None) => Some(F),
+            (Substitution, None) => Some(R),
+            (OpponentEvent | LineupChange | ScoreAdjustment, None) => Partial(action),
+            (OpponentError, Partial(OpponentEvent)) => Some(OE),
+            (OpponentScore, Partial(OpponentEvent)) => Some(OS),
+            (ChangeLibero, Partial(LineupChange)) => Some(CL),
+            (ChangeSetter, Partial(LineupChange)) => Some(CS),
+            (PointToUs, Partial(ScoreAdjustment)) => Adjustment(TeamSideEnum::Us),
+            (PointToThem, Partial(ScoreAdjustment)) => Adjustment(TeamSideEnum::Them),
             _ => None,
         }
     }
 
+    /// Scouting keys available at the current step, besides the screen actions.
+    fn get_scouting_actions(&self) -> Vec<&'static ScreenActionEnum> {
+        let keys: &'static [ScreenActionEnum] = match (&self.state, &self.current_event) {
+            (ScoutingScreenState::Event, EventTypeInput::Partial(prefix)) => match prefix {
+                ScreenActionEnum::OpponentEvent => &ScreenActionEnum::OPPONENT_KEYS,
+                ScreenActionEnum::LineupChange => &ScreenActionEnum::LINEUP_CHANGE_KEYS,
+                _ => &ScreenActionEnum::SCORE_ADJUSTMENT_KEYS,
+            },
+            (ScoutingScreenState::Event, _) => &ScreenActionEnum::EVENT_KEYS,
+            (ScoutingScreenState::Eval, _) => {
+                return ScreenActionEnum::EVAL_KEYS
+                    .iter()
+                    .chain(ScreenActionEnum::PLAYER_SLOTS.iter())
+                    .collect();
+            }
+            (ScoutingScreenState::Player | ScoutingScreenState::Replacement, _) => {
+                &ScreenActionEnum::PLAYER_SLOTS
+            }
+            _ => &[],
+        };
+        keys.iter().collect()
+    }
+
+    /// The key bound to the action, as shown in the hints.
+    fn key_hint(&self, action: ScreenActionEnum) -> String {
+        self.settings
+            .keybindings
+            .shortest_key_for(&action)
+            .map(|(ck, _)| {
+                KeyCombinationFormatExt::new(&KeyCombinationFormat::default()).to_string(ck)
+            })
+            .unwrap_or_else(|| "-".to_string())
+    }
+
+    /// The keys typed to choose the event.
+    fn event_key_hint(&self, event_type: EventTypeEnum) -> String {
+        use ScreenActionEnum::*;
+        let keys: &[ScreenActionEnum] = match event_type {
+            EventTypeEnum::S => &[Serve],
+            EventTypeEnum::P => &[Reception],
+            EventTypeEnum::A => &[Attack],
+            EventTypeEnum::D => &[Defense],
+            EventTypeEnum::B => &[Block],
+            EventTypeEnum::E => &[SetTouch],
+            EventTypeEnum::X => &[FreeBall],
+            EventTypeEnum::F => &[Fault],
+            EventTypeEnum::R => &[Substitution],
+            EventTypeEnum::OS => &[OpponentEvent, OpponentScore],
+            EventTypeEnum::OE => &[OpponentEvent, OpponentError],
+            EventTypeEnum::CL => &[LineupChange, ChangeLibero],
+            EventTypeEnum::CS => &[LineupChange, ChangeSetter],
+            EventTypeEnum::SA => &[ScoreAdjustment],
+        };
+        keys.iter().map(|a| self.key_hint(*a)).collect()
+    }
+
+    fn eval_key_hint(&self, eval: EvalEnum) -> String {
+        ScreenActionEnum::EVAL_KEYS
+            .iter()
+            .find(|a| a.eval() == Some(eval))
+            .map_or_else(|| eval.to_string(), |a| self.key_hint(*a))
+    }
+
+    fn slot_key_hint(&self, slot: u8) -> String {
+        ScreenActionEnum::PLAYER_SLOTS
+            .get((slot as usize).wrapping_sub(1))
+            .map_or_else(|| slot.to_string(), |a| self.key_hint(*a))
+    }
+
+    /// Adds a new event, the undone events cannot be redone anymore.
     async fn add_event(&mut self, event: &EventEntry) -> AppAction {
+        let count = self.set.events.len();
+        let action = self.append_event(event).await;
+        if self.set.events.len() > count {
+            self.redo_stack.clear();
+        }
+        action
+    }
+
+    /// Adds back the last undone event, dropping the input of the current one.
+    async fn redo_event(&mut self) -> AppAction {
+        let Some(event) = self.redo_stack.pop() else {
+            return AppAction::None;
+        };
+        let count = self.set.events.len();
+        let action = self.append_event(&event).await;
+        if self.set.events.len() == count {
+            // could not be added, it can be tried again
+            self.redo_stack.push(event);
+        }
+        action
+    }
+
+    async fn append_event(&mut self, event: &EventEntry) -> AppAction {
         // append event to the file
         let currently_available_options = self
             .set_writer
@@ -386,6 +600,10 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
                 self.currently_available_options = options;
                 self.current_event = EventTypeInput::None;
                 self.player = None;
+                self.other_blockers.clear();
+                self.touched = false;
+                self.combination.clear();
+                self.serve_type = None;
                 self.state = ScoutingScreenState::Event;
                 match self.snapshot.get_set_winner(self.set.set_number) {
                     None => AppAction::None,
@@ -411,7 +629,7 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
                                 self.enqueue_match_for_analytics().await;
                             }
                         }
-                        self.back = true;
+                        self.state = ScoutingScreenState::SetOver;
                         AppAction::None
                     }
                 }
@@ -427,22 +645,27 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
     /* event handling */
 
     // sequence is event type => player => eval
-    async fn handle_event_screen(
-        &mut self,
-        key: KeyEvent,
-        action: Option<ScreenActionEnum>,
-    ) -> AppAction {
+    async fn handle_event_screen(&mut self, action: Option<ScreenActionEnum>) -> AppAction {
         use EventTypeEnum::*;
-        let last_event = self.map_key_to_event(key.code, &self.current_event);
-        match (action, key.code, last_event) {
+        let last_event = self.map_key_to_event(action, &self.current_event);
+        match (action, last_event) {
             // undo
-            (Some(ScreenActionEnum::Undo), _, _) => self.undo_last_event().await,
-            (_, _, EventTypeInput::Some(event_type)) => {
+            (Some(ScreenActionEnum::Undo), _) => self.undo_last_event().await,
+            (Some(ScreenActionEnum::Notes), _) => self.open_notes(),
+            (Some(ScreenActionEnum::CodeLine), _) => {
+                self.state = ScoutingScreenState::CodeLine;
+                AppAction::None
+            }
+            (_, EventTypeInput::Some(event_type)) => {
                 let is_option_available = self.currently_available_options.contains(&event_type);
                 match (is_option_available, event_type) {
                     // player is inferred when serving
                     (true, S) => {
                         self.player = self.snapshot.current_lineup.get_serving_player();
+                        self.serve_type = self
+                            .player
+                            .and_then(|p| self.current_match.find_player(p))
+                            .and_then(|p| p.serve_type);
                         self.state = ScoutingScreenState::Eval;
                         self.current_event = last_event;
                         AppAction::None
@@ -461,6 +684,14 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
                             eval: None,
                             player: None,
                             target_player: None,
+                            second_blocker: None,
+                            third_blocker: None,
+                            touched: false,
+                            combination: None,
+                            serve_type: None,
+                            notes: None,
+                            tags: vec![],
+                            awarded_to: None,
                         };
                         self.add_event(&entry).await
                     }
@@ -474,7 +705,26 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
                     }
                 }
             }
-            (_, _, EventTypeInput::Partial(c)) => {
+            // score adjustments are always available, they follow the official scoresheet
+            (_, EventTypeInput::Adjustment(side)) => {
+                let entry = EventEntry {
+                    timestamp: Utc::now(),
+                    event_type: SA,
+                    eval: None,
+                    player: None,
+                    target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: Some(side),
+                };
+                self.add_event(&entry).await
+            }
+            (_, EventTypeInput::Partial(c)) => {
                 self.current_event = EventTypeInput::Partial(c);
                 AppAction::None
             }
@@ -485,43 +735,45 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
         }
     }
 
-    async fn handle_replacement_screen(
-        &mut self,
-        key: KeyEvent,
-        action: Option<ScreenActionEnum>,
-    ) -> AppAction {
-        use KeyCode::*;
-        match (action, key.code, &self.current_event, self.player) {
+    async fn handle_replacement_screen(&mut self, action: Option<ScreenActionEnum>) -> AppAction {
+        let slot = action.and_then(|a| a.player_slot());
+        match (action, slot, &self.current_event, self.player) {
             (Some(ScreenActionEnum::Undo), _, _, _) => {
                 // undo
                 self.player = None;
                 self.state = ScoutingScreenState::Player;
                 AppAction::None
             }
-            (_, Char(_), EventTypeInput::Some(_), None) => {
+            (_, Some(_), EventTypeInput::Some(_), None) => {
                 // replaced player is required
                 self.notify_message
                     .set_error(current_labels().no_player_selected.to_string());
                 AppAction::None
             }
-            (_, Char(c), EventTypeInput::Some(event_type), Some(replaced_id)) => {
+            (_, Some(d), EventTypeInput::Some(event_type), Some(replaced_id)) => {
                 // find available replacements for the selected (replaced) player
                 let available_replacements = self
                     .snapshot
                     .current_lineup
-                    .get_available_replacements(&self.current_match.team, replaced_id);
-                if let Some(d) = c.to_digit(10).and_then(|d| u8::try_from(d).ok()) {
-                    if (1..=available_replacements.len() as u8).contains(&d) {
-                        if let Some((_, p)) = available_replacements.iter().find(|(i, _)| *i == d) {
-                            let entry = EventEntry {
-                                timestamp: Utc::now(),
-                                event_type: *event_type,
-                                eval: None,
-                                player: Some(replaced_id),
-                                target_player: Some(p.id),
-                            };
-                            return self.add_event(&entry).await;
-                        }
+                    .get_available_replacements(&self.current_match.players, replaced_id);
+                if (1..=available_replacements.len() as u8).contains(&d) {
+                    if let Some((_, p)) = available_replacements.iter().find(|(i, _)| *i == d) {
+                        let entry = EventEntry {
+                            timestamp: Utc::now(),
+                            event_type: *event_type,
+                            eval: None,
+                            player: Some(replaced_id),
+                            target_player: Some(p.id),
+                            second_blocker: None,
+                            third_blocker: None,
+                            touched: false,
+                            combination: None,
+                            serve_type: None,
+                            notes: None,
+                            tags: vec![],
+                            awarded_to: None,
+                        };
+                        return self.add_event(&entry).await;
                     }
                 }
                 AppAction::None
@@ -530,27 +782,17 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
         }
     }
 
-    async fn handle_player_screen(
-        &mut self,
-        key: KeyEvent,
-        action: Option<ScreenActionEnum>,
-    ) -> AppAction {
+    async fn handle_player_screen(&mut self, action: Option<ScreenActionEnum>) -> AppAction {
         let available_lineup_players = self.get_lineup_choices();
-        use KeyCode::*;
         // undo
         if let Some(ScreenActionEnum::Undo) = action {
             self.current_event = EventTypeInput::None;
             self.state = ScoutingScreenState::Event;
             return AppAction::None;
         }
-        let player = match key.code {
-            Char(c) => c
-                .to_digit(10)
-                .take_if(|d| (1..=7).contains(d))
-                .map(|d| d as u8)
-                .and_then(|d| available_lineup_players.iter().find(|p| p.index == d)),
-            _ => None,
-        };
+        let player = action
+            .and_then(|a| a.player_slot())
+            .and_then(|d| available_lineup_players.iter().find(|p| p.index == d));
         match (player, &self.current_event) {
             (Some(player), EventTypeInput::Some(event_type))
                 if event_type.requires_evaluation() =>
@@ -571,6 +813,14 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
                     eval: None,
                     player: Some(player.id),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                 })
                 .await
             }
@@ -593,6 +843,10 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
             // * the ScoutingScreenState::Player screen if it's not serving
             // * otherwise, so it's serving, go to the ScoutingScreenState::Event
             self.player = None;
+            self.other_blockers.clear();
+            self.touched = false;
+            self.combination.clear();
+            self.serve_type = None;
             if self.current_event == EventTypeInput::Some(S) {
                 self.current_event = EventTypeInput::None;
                 self.state = ScoutingScreenState::Event;
@@ -603,27 +857,88 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
         }
         use EventTypeEnum::*;
         use KeyCode::*;
-        let eval = match (key.code, &self.current_event) {
-            (Char('#'), EventTypeInput::Some(A | B | D | P | S)) => Some(EvalEnum::Perfect),
-            (Char('+'), EventTypeInput::Some(A | B | D | P | S)) => Some(EvalEnum::Positive),
-            (Char('!'), EventTypeInput::Some(P | D)) => Some(EvalEnum::Exclamative),
-            (Char('-'), EventTypeInput::Some(A | B | D | P | S)) => Some(EvalEnum::Negative),
-            (Char('/'), EventTypeInput::Some(A | B | D | P | S)) => Some(EvalEnum::Over),
-            (Char('='), EventTypeInput::Some(A | B | D | P | S)) => Some(EvalEnum::Error),
+        let slot = action.and_then(|a| a.player_slot());
+        if self.current_event == EventTypeInput::Some(B) {
+            match (action, slot) {
+                (Some(ScreenActionEnum::EventOption), _) => {
+                    self.touched = !self.touched;
+                    return AppAction::None;
+                }
+                (_, Some(slot)) => {
+                    self.toggle_blocker(slot);
+                    return AppAction::None;
+                }
+                _ => {}
+            }
+        }
+        if self.current_event == EventTypeInput::Some(S) {
+            match (action, slot) {
+                (Some(ScreenActionEnum::EventOption), _) => {
+                    self.serve_type =
+                        Some(self.serve_type.map_or(ServeTypeEnum::Float, |t| t.next()));
+                    return AppAction::None;
+                }
+                // the server is inferred from the lineup, but can be changed
+                (_, Some(slot)) => {
+                    self.change_server(slot);
+                    return AppAction::None;
+                }
+                _ => {}
+            }
+        }
+        let eval = action.and_then(|a| a.eval());
+        if self.current_event == EventTypeInput::Some(A) && eval.is_none() {
+            // the code of the attack combination is typed before the evaluation, evaluation keys
+            // cannot be letters or digits (see `types_combination_code`)
+            match key.code {
+                Char(c) if c.is_ascii_alphanumeric() && self.combination.len() < 4 => {
+                    self.combination.push(c.to_ascii_uppercase());
+                    return AppAction::None;
+                }
+                Backspace => {
+                    self.combination.pop();
+                    return AppAction::None;
+                }
+                _ => {}
+            }
+        }
+        let eval = match &self.current_event {
+            EventTypeInput::Some(event_type) => {
+                eval.filter(|e| event_type.available_evals().contains(e))
+            }
             _ => None,
         };
         if let (Some(eval), EventTypeInput::Some(event_type)) = (eval, &self.current_event) {
             match event_type {
                 // ensure event type allows evaluation
-                A | B | P | D | S => {
+                A | B | P | D | S | E | X => {
+                    let is_block = *event_type == B;
+                    let serve_type = self.serve_type.filter(|_| *event_type == S);
+                    let combination = match self.get_typed_combination() {
+                        Ok(combination) => combination,
+                        Err(_) => {
+                            let template = current_labels().unknown_attack_combination;
+                            self.notify_message
+                                .set_error(template.replace("{}", &self.combination));
+                            return AppAction::None;
+                        }
+                    };
                     let entry = EventEntry {
                         timestamp: Utc::now(),
                         event_type: *event_type,
                         eval: Some(eval),
                         player: self.player,
                         target_player: None,
+                        second_blocker: self.other_blockers.first().copied().filter(|_| is_block),
+                        third_blocker: self.other_blockers.get(1).copied().filter(|_| is_block),
+                        touched: is_block && self.touched,
+                        combination,
+                        serve_type,
+                        notes: None,
+                        tags: vec![],
+                        awarded_to: None,
                     };
-                    return self.add_event(&entry).await;
+                    return self.submit_event(entry).await;
                 }
                 _ => {
                     let template = current_labels().evaluation_not_allowed_for_event;
@@ -635,6 +950,437 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
         AppAction::None
     }
 
+    /// Resolves the typed attack combination code against the team catalogue.
+    fn get_typed_combination(&self) -> Result<Option<String>, ()> {
+        if self.combination.is_empty()
+            || self.current_event != EventTypeInput::Some(EventTypeEnum::A)
+        {
+            return Ok(None);
+        }
+        self.current_match
+            .team
+            .find_combination(&self.combination)
+            .map(|c| Some(c.code.clone()))
+            .ok_or(())
+    }
+
+    /// Adds the front-row player at the given index to the block, or removes them if already in.
+    fn toggle_blocker(&mut self, slot: u8) {
+        let Some(blocker) = self
+            .get_lineup_choices()
+            .into_iter()
+            .find(|p| p.index == slot)
+            .map(|p| p.id)
+            .filter(|id| Some(*id) != self.player)
+        else {
+            return;
+        };
+        if let Some(position) = self.other_blockers.iter().position(|id| *id == blocker) {
+            self.other_blockers.remove(position);
+        } else if self.other_blockers.len() < 2 {
+            self.other_blockers.push(blocker);
+        }
+    }
+
+    fn change_server(&mut self, slot: u8) {
+        if let Some(server) = self
+            .get_lineup_choices()
+            .into_iter()
+            .find(|p| p.index == slot)
+        {
+            self.player = Some(server.id);
+            self.serve_type = self
+                .current_match
+                .team
+                .find_player(server.id)
+                .and_then(|p| p.serve_type);
+        }
+    }
+
+    /* lineup warnings */
+
+    fn check_event(&self, event: &EventEntry) -> Vec<LineupWarningEnum> {
+        let attack_zone = event
+            .combination
+            .as_ref()
+            .and_then(|code| self.current_match.team.find_combination(code))
+            .map(|c| c.zone);
+        self.snapshot.current_lineup.check_event(event, attack_zone)
+    }
+
+    /// Adds the event, or asks for a confirmation when it breaks the tracked lineup.
+    async fn submit_event(&mut self, event: EventEntry) -> AppAction {
+        let warnings = self.check_event(&event);
+        if warnings.is_empty() {
+            return self.add_event(&event).await;
+        }
+        self.lineup_warnings = warnings;
+        self.pending_event = Some(event);
+        self.state = ScoutingScreenState::LineupWarning;
+        AppAction::None
+    }
+
+    fn can_fix_lineup(&self) -> bool {
+        self.lineup_warnings
+            .contains(&LineupWarningEnum::WrongServer)
+    }
+
+    async fn handle_lineup_warning_screen(
+        &mut self,
+        action: Option<ScreenActionEnum>,
+    ) -> AppAction {
+        match action {
+            Some(ScreenActionEnum::Back) => {
+                // back to the evaluation or to the code line, to change the event
+                self.pending_event = None;
+                self.lineup_warnings.clear();
+                self.state = if std::mem::take(&mut self.pending_from_code_line) {
+                    ScoutingScreenState::CodeLine
+                } else {
+                    ScoutingScreenState::Eval
+                };
+                AppAction::None
+            }
+            Some(ScreenActionEnum::Confirm) => {
+                let Some(mut event) = self.pending_event.take() else {
+                    return AppAction::None;
+                };
+                // accepted as it is, flagged so that it can be reviewed later
+                for warning in self.lineup_warnings.drain(..) {
+                    let tag = warning.tag().to_string();
+                    if !event.tags.contains(&tag) {
+                        event.tags.push(tag);
+                    }
+                }
+                let count = self.set.events.len();
+                let action = self.add_event(&event).await;
+                self.resume_code_line(count, action).await
+            }
+            Some(ScreenActionEnum::FixLineup) if self.can_fix_lineup() => {
+                let count = self.set.events.len();
+                let action = self.fix_lineup().await;
+                self.resume_code_line(count, action).await
+            }
+            _ => AppAction::None,
+        }
+    }
+
+    /// Swaps the recorded server with the expected one in the starting lineup.
+    async fn fix_lineup(&mut self) -> AppAction {
+        let (Some(event), Some(expected)) = (
+            self.pending_event.clone(),
+            self.snapshot.current_lineup.get_serving_player(),
+        ) else {
+            return AppAction::None;
+        };
+        let Some(server) = event.player else {
+            return AppAction::None;
+        };
+        let mut set = self.set.clone();
+        if !set.swap_initial_players(server, expected) {
+            self.notify_message
+                .set_error(current_labels().could_not_fix_lineup.to_string());
+            return AppAction::None;
+        }
+        let fixed = match set.compute_snapshot() {
+            Ok(fixed) => fixed,
+            Err(_) => {
+                self.notify_message
+                    .set_error(current_labels().could_not_fix_lineup.to_string());
+                return AppAction::None;
+            }
+        };
+        if self
+            .set_writer
+            .update(&self.current_match, &set)
+            .await
+            .is_err()
+        {
+            self.notify_message
+                .set_error(current_labels().could_not_save_set.to_string());
+            return AppAction::None;
+        }
+        let (snapshot, available_options) = fixed;
+        self.set = set;
+        // the undone events were recorded with the previous lineup
+        self.redo_stack.clear();
+        self.snapshot = snapshot;
+        self.currently_available_options = available_options;
+        self.pending_event = None;
+        self.lineup_warnings.clear();
+        self.submit_event(event).await
+    }
+
+    /* code line */
+
+    fn resolve_code_line(&self) -> Result<Vec<CodeLineEvent>, CodeLineError> {
+        resolve_code_line(
+            &self.code_line.get_selected_value().unwrap_or_default(),
+            &self.current_match.team,
+            &self.current_match.players,
+            &self.snapshot,
+            &self.currently_available_options,
+            self.set.set_number,
+        )
+    }
+
+    async fn handle_code_line_screen(
+        &mut self,
+        key: KeyEvent,
+        action: Option<ScreenActionEnum>,
+    ) -> AppAction {
+        match (action, key.code) {
+            (Some(ScreenActionEnum::Back), _) => {
+                self.state = ScoutingScreenState::Event;
+                AppAction::None
+            }
+            (Some(ScreenActionEnum::Confirm), _) => self.commit_code_line().await,
+            (_, KeyCode::Char(c)) => {
+                self.code_line.handle_char(c);
+                AppAction::None
+            }
+            (_, KeyCode::Backspace) => {
+                self.code_line.handle_backspace();
+                AppAction::None
+            }
+            _ => AppAction::None,
+        }
+    }
+
+    /// Adds the events of the whole line, one after the other. Codes left out because an
+    /// event could not be added stay in the line. An event breaking the tracked lineup goes
+    /// through the same confirmation as in the step by step input, the rest of the line is
+    /// added once it is accepted or fixed.
+    async fn commit_code_line(&mut self) -> AppAction {
+        let events = match self.resolve_code_line() {
+            Ok(events) => events,
+            Err(error) => {
+                self.notify_message
+                    .set_error(Self::format_code_line_error(&error));
+                return AppAction::None;
+            }
+        };
+        let mut added = 0;
+        let mut pending = None;
+        for CodeLineEvent { event, warnings } in events.into_iter() {
+            if !warnings.is_empty() {
+                pending = Some(event);
+                break;
+            }
+            let count = self.set.events.len();
+            self.add_event(&event).await;
+            if self.set.events.len() == count {
+                break;
+            }
+            added += 1;
+        }
+        self.skip_code_line_codes(added);
+        if let Some(event) = pending {
+            // the code stays in the line until the event is added
+            self.pending_from_code_line = true;
+            return self.submit_event(event).await;
+        }
+        if self.state != ScoutingScreenState::SetOver {
+            self.state = ScoutingScreenState::CodeLine;
+        }
+        AppAction::None
+    }
+
+    /// Goes on with the rest of the code line once its pending event has been added.
+    async fn resume_code_line(&mut self, count: usize, action: AppAction) -> AppAction {
+        if !self.pending_from_code_line || self.set.events.len() == count {
+            return action;
+        }
+        self.pending_from_code_line = false;
+        self.skip_code_line_codes(1);
+        if self.state == ScoutingScreenState::SetOver {
+            return action;
+        }
+        self.commit_code_line().await
+    }
+
+    /// Leaves in the code line only the codes after the given number of added ones.
+    fn skip_code_line_codes(&mut self, added: usize) {
+        let remaining = self
+            .code_line
+            .get_selected_value()
+            .unwrap_or_default()
+            .split_whitespace()
+            .skip(added)
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.code_line = TextBox::new(
+            current_labels().code_line.to_owned(),
+            true,
+            Some(&remaining),
+        );
+    }
+
+    fn format_code_line_error(error: &CodeLineError) -> String {
+        current_labels()
+            .code_line_error
+            .replacen("{}", &(error.token + 1).to_string(), 1)
+            .replacen("{}", &error.message, 1)
+    }
+
+    /* notes */
+
+    fn open_notes(&mut self) -> AppAction {
+        let target = if self.set.has_events() {
+            NoteTargetEnum::Event
+        } else {
+            NoteTargetEnum::Set
+        };
+        self.note_target = Select::new(
+            current_labels().note_target.to_owned(),
+            NoteTargetEnum::ALL.to_vec(),
+            Some(target),
+            false,
+        );
+        self.note_field = 1;
+        self.load_notes();
+        self.state = ScoutingScreenState::Notes;
+        AppAction::None
+    }
+
+    /// Fills the text boxes with the notes and tags of the selected target.
+    fn load_notes(&mut self) {
+        let (notes, tags) = match self.note_target.get_selected_value() {
+            Some(NoteTargetEnum::Event) => self
+                .set
+                .events
+                .last()
+                .map(|e| (e.notes.clone(), e.tags.clone()))
+                .unwrap_or_default(),
+            Some(NoteTargetEnum::Set) => (self.set.notes.clone(), self.set.tags.clone()),
+            Some(NoteTargetEnum::Match) => (
+                self.current_match.notes.clone(),
+                self.current_match.tags.clone(),
+            ),
+            None => (None, vec![]),
+        };
+        self.notes = TextBox::new(
+            current_labels().notes.to_owned(),
+            self.note_field == 1,
+            notes.as_deref(),
+        );
+        self.tags = TextBox::new(
+            current_labels().tags.to_owned(),
+            self.note_field == 2,
+            Some(&format_tags(&tags)),
+        );
+        self.note_target.writing_mode = self.note_field == 0;
+    }
+
+    fn update_note_writing_modes(&mut self) {
+        self.note_target.writing_mode = self.note_field == 0;
+        self.notes.writing_mode = self.note_field == 1;
+        self.tags.writing_mode = self.note_field == 2;
+    }
+
+    async fn handle_notes_screen(
+        &mut self,
+        key: KeyEvent,
+        action: Option<ScreenActionEnum>,
+    ) -> AppAction {
+        use KeyCode::*;
+        match (action, key.code) {
+            (Some(ScreenActionEnum::Back), _) => {
+                self.state = ScoutingScreenState::Event;
+                AppAction::None
+            }
+            (Some(ScreenActionEnum::Confirm), _) => self.save_notes().await,
+            (Some(ScreenActionEnum::Next), _) => {
+                self.note_field = (self.note_field + 1) % 3;
+                self.update_note_writing_modes();
+                AppAction::None
+            }
+            (Some(ScreenActionEnum::Previous), _) => {
+                self.note_field = (self.note_field + 2) % 3;
+                self.update_note_writing_modes();
+                AppAction::None
+            }
+            (_, Up | Down) if self.note_field == 0 => {
+                if key.code == Up {
+                    self.note_target.handle_up();
+                } else {
+                    self.note_target.handle_down();
+                }
+                self.load_notes();
+                AppAction::None
+            }
+            (_, Char(c)) => {
+                match self.note_field {
+                    1 => self.notes.handle_char(c),
+                    2 => self.tags.handle_char(c),
+                    _ => {}
+                }
+                AppAction::None
+            }
+            (_, Backspace) => {
+                match self.note_field {
+                    1 => self.notes.handle_backspace(),
+                    2 => self.tags.handle_backspace(),
+                    _ => {}
+                }
+                AppAction::None
+            }
+            _ => AppAction::None,
+        }
+    }
+
+    async fn save_notes(&mut self) -> AppAction {
+        let notes = self
+            .notes
+            .get_selected_value()
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty());
+        let tags = parse_tags(&self.tags.get_selected_value().unwrap_or_default());
+        let saved = match self.note_target.get_selected_value() {
+            Some(NoteTargetEnum::Event) => {
+                let Some(mut event) = self.set.events.last().cloned() else {
+                    self.notify_message
+                        .set_error(current_labels().no_events_yet.to_string());
+                    return AppAction::None;
+                };
+                event.notes = notes;
+                event.tags = tags;
+                let result = self
+                    .set_writer
+                    .replace_last_event(&self.current_match, self.set.set_number, &event)
+                    .await;
+                if result.is_ok() {
+                    self.set.events.pop();
+                    self.set.events.push(event);
+                }
+                result
+            }
+            Some(NoteTargetEnum::Set) => {
+                self.set.notes = notes;
+                self.set.tags = tags;
+                self.set_writer.update(&self.current_match, &self.set).await
+            }
+            Some(NoteTargetEnum::Match) => {
+                self.current_match.notes = notes;
+                self.current_match.tags = tags;
+                self.match_writer.update(&self.current_match).await
+            }
+            None => return AppAction::None,
+        };
+        match saved {
+            Ok(_) => {
+                self.state = ScoutingScreenState::Event;
+                self.notify_message
+                    .set_info(current_labels().operation_successful.to_string());
+            }
+            Err(_) => {
+                self.notify_message
+                    .set_error(current_labels().could_not_save_notes.to_string());
+            }
+        }
+        AppAction::None
+    }
+
     /* rendering */
     fn render_header(&self, f: &mut Frame, area: Rect) {
         let block = Block::default()
@@ -707,10 +1453,18 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
             .map(|ev| {
                 Row::new(vec![format!(
                     "{} ({})",
-                    ev,
+                    self.event_key_hint(*ev),
                     ev.friendly_name(current_labels())
                 )])
             })
+            .chain(std::iter::once(Row::new(vec![format!(
+                "{}{}/{}{} ({})",
+                self.event_key_hint(EventTypeEnum::SA),
+                self.key_hint(ScreenActionEnum::PointToUs),
+                self.event_key_hint(EventTypeEnum::SA),
+                self.key_hint(ScreenActionEnum::PointToThem),
+                EventTypeEnum::SA.friendly_name(current_labels())
+            )])))
             .collect();
         let table = Table::new(rows, [Constraint::Percentage(100)]).block(
             Block::default()
@@ -726,12 +1480,12 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
             EventTypeInput::Some(event_type) => event_type.available_evals(),
             _ => vec![],
         };
-        let rows: Vec<Row> = available_evals
+        let mut rows: Vec<Row> = available_evals
             .iter()
             .map(|ev| {
                 Row::new(vec![format!(
                     "{} => {}",
-                    ev.to_string(),
+                    self.eval_key_hint(*ev),
                     if let EventTypeInput::Some(last_event) = self.current_event {
                         if let Some(desc) = ev.friendly_description(last_event, current_labels()) {
                             format!(
@@ -748,6 +1502,17 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
                 )])
             })
             .collect();
+        if self.current_event == EventTypeInput::Some(EventTypeEnum::B) {
+            rows.extend(self.block_rows());
+        }
+        if self.current_event == EventTypeInput::Some(EventTypeEnum::S) {
+            rows.extend(self.serve_type_rows());
+        }
+        if self.current_event == EventTypeInput::Some(EventTypeEnum::A)
+            && !self.current_match.team.combinations.is_empty()
+        {
+            rows.extend(self.combination_rows());
+        }
         let table = Table::new(rows, [Constraint::Percentage(100)]).block(
             Block::default()
                 .borders(Borders::ALL)
@@ -757,6 +1522,97 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
         f.render_widget(table, area);
     }
 
+    fn block_rows(&self) -> Vec<Row<'_>> {
+        let labels = current_labels();
+        let indexes: Vec<String> = self
+            .get_lineup_choices()
+            .iter()
+            .filter(|p| Some(p.id) != self.player)
+            .map(|p| self.slot_key_hint(p.index))
+            .collect();
+        let blockers: Vec<String> = self
+            .player
+            .iter()
+            .chain(self.other_blockers.iter())
+            .filter_map(|id| self.current_match.find_player(*id))
+            .map(|p| format!("{} {}", p.number, p.name))
+            .collect();
+        vec![
+            Row::new(vec![String::new()]),
+            Row::new(vec![labels
+                .block_eval_hint
+                .replacen("{}", &indexes.join(","), 1)
+                .replacen(
+                    "{}",
+                    &self.key_hint(ScreenActionEnum::EventOption),
+                    1,
+                )]),
+            Row::new(vec![format!(
+                "{}: {}{}",
+                labels.blockers,
+                blockers.join(", "),
+                if self.touched {
+                    format!(" ({})", labels.touched)
+                } else {
+                    String::new()
+                }
+            )]),
+        ]
+    }
+
+    fn serve_type_rows(&self) -> Vec<Row<'_>> {
+        let labels = current_labels();
+        let indexes: Vec<String> = self
+            .get_lineup_choices()
+            .iter()
+            .map(|p| self.slot_key_hint(p.index))
+            .collect();
+        vec![
+            Row::new(vec![String::new()]),
+            Row::new(vec![labels
+                .change_server_hint
+                .replace("{}", &indexes.join(","))]),
+            Row::new(vec![labels
+                .serve_type_hint
+                .replace("{}", &self.key_hint(ScreenActionEnum::EventOption))]),
+            Row::new(vec![format!(
+                "{}: {}",
+                labels.serve_type,
+                self.serve_type.friendly_name(labels)
+            )]),
+        ]
+    }
+
+    fn combination_rows(&self) -> Vec<Row<'_>> {
+        let labels = current_labels();
+        let mut rows = vec![
+            Row::new(vec![String::new()]),
+            Row::new(vec![labels.attack_combination_hint.to_string()]),
+            Row::new(vec![format!(
+                "{}: {}",
+                labels.combination, self.combination
+            )]),
+        ];
+        rows.extend(
+            self.current_match
+                .team
+                .combinations
+                .iter()
+                .filter(|c| c.code.starts_with(&self.combination))
+                .map(|c| {
+                    Row::new(vec![format!(
+                        "  {} - {}, {} {} {}",
+                        c.code,
+                        c.tempo.friendly_name(labels),
+                        labels.zone,
+                        c.zone,
+                        c.role.friendly_name(labels)
+                    )])
+                }),
+        );
+        rows
+    }
+
     fn format_court_cell(
         &self,
         player: Option<&PlayerEntry>,
@@ -807,7 +1663,7 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
             .has_libero_at_pos(position_index);
         let is_serving =
             self.currently_available_options.contains(&EventTypeEnum::S) && position_index == 0;
-        let player = self.current_match.team.find_player(player_id);
+        let player = self.current_match.find_player(player_id);
         let content =
             self.format_court_cell(player, position_index, is_serving, is_libero, is_setter);
         let cell = Paragraph::new(content)
@@ -850,15 +1706,21 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
         }
     }
 
-    fn recent_event_row(&'_ self, i: usize, e: &EventEntry) -> Row<'_> {
-        Row::new(vec![
+    fn event_cells(&self, marker: &str, e: &EventEntry) -> Vec<String> {
+        vec![
+            marker.to_string(),
             format!(" {:<12}", e.event_type.friendly_name(current_labels())),
             format!(
                 " {:<20}",
-                e.player
-                    .and_then(|p1| self.current_match.team.find_player(p1))
-                    .map(|p| p.name.as_str())
-                    .unwrap_or("-")
+                match e.adjusted_side() {
+                    Some(TeamSideEnum::Us) => self.current_match.team.name.as_str(),
+                    Some(TeamSideEnum::Them) => self.current_match.opponent.as_str(),
+                    None => e
+                        .player
+                        .and_then(|p1| self.current_match.find_player(p1))
+                        .map(|p| p.name.as_str())
+                        .unwrap_or("-"),
+                }
             ),
             format!(
                 " {:<10}",
@@ -866,8 +1728,18 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
                     .map(|e1| e1.friendly_name(e.event_type, current_labels()))
                     .unwrap_or("".to_string())
             ),
-        ])
-        .style(if i == 0 {
+            // flags the events carrying notes or tags
+            if e.notes.is_some() || !e.tags.is_empty() {
+                "✎".to_string()
+            } else {
+                String::new()
+            },
+        ]
+    }
+
+    fn recent_event_row(&'_ self, i: usize, e: &EventEntry) -> Row<'_> {
+        // the first one is the next event to be undone
+        Row::new(self.event_cells(if i == 0 { "↶" } else { "" }, e)).style(if i == 0 {
             Style::default()
                 .bg(Color::Blue)
                 .fg(Color::White)
@@ -879,28 +1751,56 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
         })
     }
 
+    fn redo_event_row(&'_ self, e: &EventEntry) -> Row<'_> {
+        Row::new(self.event_cells("↷", e)).style(
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+        )
+    }
+
+    /// Latest events, newest first, below the undone events that can be redone.
     fn render_recent_events(&self, f: &mut Frame, area: Rect) {
+        let labels = current_labels();
         let mut events: Vec<_> = self.set.events.iter().collect();
         events.sort_by_key(|e| e.timestamp);
-        let rows = events
-            .into_iter()
-            .rev()
-            .take(16)
-            .enumerate()
-            .map(|(i, e)| self.recent_event_row(i, e));
+        // the undone events closest to the latest one
+        let redo_rows = self
+            .redo_stack
+            .iter()
+            .skip(self.redo_stack.len().saturating_sub(REDO_HISTORY_ROWS))
+            .map(|e| self.redo_event_row(e));
+        let rows = redo_rows
+            .chain(
+                events
+                    .into_iter()
+                    .rev()
+                    .enumerate()
+                    .map(|(i, e)| self.recent_event_row(i, e)),
+            )
+            .take(16);
+        let title = if self.redo_stack.is_empty() {
+            labels.latest_events.to_string()
+        } else {
+            format!(
+                "{} ({})",
+                labels.latest_events,
+                labels
+                    .redo_available
+                    .replace("{}", &self.redo_stack.len().to_string())
+            )
+        };
         let table = Table::new(
             rows,
             [
+                Constraint::Length(2),
+                Constraint::Percentage(25),
+                Constraint::Percentage(40),
                 Constraint::Percentage(26),
-                Constraint::Percentage(44),
-                Constraint::Percentage(30),
+                Constraint::Percentage(5),
             ],
         )
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(current_labels().latest_events),
-        );
+        .block(Block::default().borders(Borders::ALL).title(title));
         f.render_widget(table, area);
     }
 
@@ -908,11 +1808,275 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
         let lineup_choices = self.get_lineup_choices();
         let rows: Vec<Row> = lineup_choices
             .iter()
-            .map(Self::format_player_choice_row)
+            .map(|e| self.format_player_choice_row(e))
             .collect();
         self.render_player_choices_table(f, area, rows, current_labels().player_selection);
     }
 
+    fn render_lineup_warning(&self, f: &mut Frame, area: Rect) {
+        let labels = current_labels();
+        let player_name = |id: Option<Uuid>| {
+            id.and_then(|id| self.current_match.find_player(id))
+                .map_or("-".to_string(), |p| format!("{} {}", p.number, p.name))
+        };
+        let mut rows: Vec<Row> = self
+            .lineup_warnings
+            .iter()
+            .map(|w| Row::new(vec![format!("! {}", w.friendly_name(labels))]))
+            .collect();
+        rows.push(Row::new(vec![String::new()]));
+        if self.can_fix_lineup() {
+            let server = self.pending_event.as_ref().and_then(|e| e.player);
+            rows.push(Row::new(vec![format!(
+                "{}: {}",
+                labels.expected_server,
+                player_name(self.snapshot.current_lineup.get_serving_player())
+            )]));
+            rows.push(Row::new(vec![labels
+                .fix_lineup_hint
+                .replace("{}", &player_name(server))]));
+        }
+        rows.push(Row::new(vec![labels.lineup_warning_hint.to_string()]));
+        let table = Table::new(rows, [Constraint::Percentage(100)]).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(labels.lineup_warning)
+                .style(Style::default().fg(Color::Yellow)),
+        );
+        f.render_widget(table, area);
+    }
+
+    fn render_set_over(&self, f: &mut Frame, area: Rect) {
+        let labels = current_labels();
+        let winner = match self.snapshot.get_set_winner(self.set.set_number) {
+            Some(TeamSideEnum::Us) => self.current_match.team.name.as_str(),
+            Some(TeamSideEnum::Them) => self.current_match.opponent.as_str(),
+            None => "-",
+        };
+        let rows = vec![
+            Row::new(vec![format!(
+                "{} {} - {}",
+                winner, self.snapshot.score_us, self.snapshot.score_them
+            )]),
+            Row::new(vec![String::new()]),
+            Row::new(vec![labels
+                .set_over_hint
+                .replacen("{}", &self.key_hint(ScreenActionEnum::Undo), 1)
+                .replacen("{}", &self.key_hint(ScreenActionEnum::Back), 1)]),
+        ];
+        let table = Table::new(rows, [Constraint::Percentage(100)]).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(labels.set_over)
+                .style(Style::default().fg(Color::Green)),
+        );
+        f.render_widget(table, area);
+    }
+
+    /// Numbers of the current set, updated with every event.
+    fn render_live_stats(&self, f: &mut Frame, area: Rect) {
+        use EventTypeEnum::*;
+        let labels = current_labels();
+        let stats = &self.snapshot.stats;
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(6), Constraint::Min(0)])
+            .split(area);
+        let phase_row = |label: &str, phase: PhaseEnum| {
+            let (perc, count) = stats
+                .phase_win_percentage(phase, None)
+                .map_or(("-".to_string(), "-".to_string()), |(p, total, won)| {
+                    (format!("{:.1}%", p), format!("{}/{}", won, total))
+                });
+            Row::new(vec![label.to_string(), perc, count])
+        };
+        let team_row = |label: &str, event_type: EventTypeEnum, metric: Metric| {
+            let (perc, count) = stats
+                .event_positiveness(event_type, None, None, None, None, metric)
+                .map_or(("-".to_string(), "-".to_string()), |(p, total, _)| {
+                    (format!("{:.1}%", p), total.to_string())
+                });
+            Row::new(vec![label.to_string(), perc, count])
+        };
+        let summary = Table::new(
+            vec![
+                phase_row(labels.sideout_perc, PhaseEnum::SideOut),
+                phase_row(labels.break_point_perc, PhaseEnum::Break),
+                team_row(labels.reception_positive_perc, P, Metric::Positive),
+                team_row(labels.attack_efficiency_perc, A, Metric::Efficiency),
+            ],
+            [
+                Constraint::Length(12),
+                Constraint::Length(8),
+                Constraint::Min(0),
+            ],
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(labels.live_stats),
+        );
+        f.render_widget(summary, chunks[0]);
+        let mut players: Vec<&PlayerEntry> = self
+            .current_match
+            .players
+            .iter()
+            .filter(|p| {
+                stats
+                    .event_count(P, Some(p.id), None, None, None, None)
+                    .is_some()
+                    || stats
+                        .event_count(A, Some(p.id), None, None, None, None)
+                        .is_some()
+                    || stats
+                        .total_errors(None, Some(p.id), None, None, None)
+                        .is_some()
+            })
+            .collect();
+        players.sort_by_key(|p| p.number);
+        let rows: Vec<Row> = players
+            .into_iter()
+            .map(|player| {
+                let cell = |event_type: EventTypeEnum, metric: Metric| {
+                    stats
+                        .event_positiveness(event_type, Some(player.id), None, None, None, metric)
+                        .map_or("-".to_string(), |(p, total, _)| {
+                            format!("{:.0}% ({})", p, total)
+                        })
+                };
+                Row::new(vec![
+                    player.to_string(),
+                    cell(P, Metric::Positive),
+                    cell(A, Metric::Efficiency),
+                    stats
+                        .total_errors(None, Some(player.id), None, None, None)
+                        .map_or("-".to_string(), |v| v.to_string()),
+                ])
+            })
+            .collect();
+        let header = Row::new(vec![
+            labels.player,
+            labels.reception_positive_perc,
+            labels.attack_efficiency_perc,
+            labels.errors,
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(37),
+                Constraint::Percentage(23),
+                Constraint::Percentage(23),
+                Constraint::Percentage(17),
+            ],
+        )
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(labels.players));
+        f.render_widget(table, chunks[1]);
+    }
+
+    fn render_code_line(&mut self, f: &mut Frame, area: Rect) {
+        let labels = current_labels();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(labels.code_line);
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(1),
+                Constraint::Min(0),
+            ])
+            .split(inner);
+        self.code_line.render(f, chunks[0]);
+        f.render_widget(Paragraph::new(labels.code_line_hint), chunks[1]);
+        let player_number = |id: Option<Uuid>| {
+            id.and_then(|id| self.current_match.find_player(id))
+                .map_or(String::new(), |p| p.number.to_string())
+        };
+        let preview = Block::default()
+            .borders(Borders::TOP)
+            .title(labels.code_line_events);
+        let events = match self.resolve_code_line() {
+            Ok(events) => events,
+            Err(error) => {
+                let message = Paragraph::new(Self::format_code_line_error(&error))
+                    .style(Style::default().fg(Color::Red))
+                    .block(preview);
+                f.render_widget(message, chunks[2]);
+                return;
+            }
+        };
+        let rows: Vec<Row> = events
+            .iter()
+            .map(|CodeLineEvent { event, warnings }| {
+                let mut players = vec![player_number(event.player)];
+                players.extend(
+                    [
+                        event.second_blocker,
+                        event.third_blocker,
+                        event.target_player,
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .map(|id| player_number(Some(id))),
+                );
+                let warnings = warnings
+                    .iter()
+                    .map(|w| format!("! {}", w.friendly_name(labels)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let style = if warnings.is_empty() {
+                    Style::default()
+                } else {
+                    Style::default().fg(Color::Yellow)
+                };
+                Row::new(vec![
+                    event.event_type.friendly_name(labels).to_string(),
+                    players.join(" "),
+                    event.eval.map_or(String::new(), |e| e.to_string()),
+                    event.combination.clone().unwrap_or_default(),
+                    warnings,
+                ])
+                .style(style)
+            })
+            .collect();
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(16),
+                Constraint::Length(10),
+                Constraint::Length(3),
+                Constraint::Length(6),
+                Constraint::Min(0),
+            ],
+        )
+        .block(preview);
+        f.render_widget(table, chunks[2]);
+    }
+
+    fn render_notes(&mut self, f: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(current_labels().add_notes);
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+            ])
+            .split(inner);
+        self.note_target.render(f, chunks[0]);
+        self.notes.render(f, chunks[1]);
+        self.tags.render(f, chunks[2]);
+    }
+
     fn render_replacement_choices(&mut self, f: &mut Frame, area: Rect) {
         match self.player {
             None => {
@@ -923,7 +2087,7 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
                 let rows: Vec<Row> = self
                     .snapshot
                     .current_lineup
-                    .get_available_replacements(&self.current_match.team, replaced_id)
+                    .get_available_replacements(&self.current_match.players, replaced_id)
                     .iter()
                     .map(|(i, player)| LineupChoiceEntry {
                         index: *i,
@@ -934,7 +2098,7 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
                             .role
                             .map_or_else(|| "-".to_string(), |r| r.to_string()),
                     })
-                    .map(|e| Self::format_player_choice_row(&e))
+                    .map(|e| self.format_player_choice_row(&e))
                     .collect();
                 self.render_player_choices_table(
                     f,
@@ -946,9 +2110,9 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
         }
     }
 
-    fn format_player_choice_row<'a>(entry: &LineupChoiceEntry) -> Row<'a> {
+    fn format_player_choice_row<'a>(&self, entry: &LineupChoiceEntry) -> Row<'a> {
         Row::new(vec![
-            format!(" {:<12}", entry.index),
+            format!(" {:<12}", self.slot_key_hint(entry.index)),
             format!(" #{:<12}", entry.number),
             format!(" {:<12}", entry.name),
             format!(" {:<20}", entry.role),
@@ -975,8 +2139,41 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
     }
 
     fn get_sreen_actions(&self) -> Vec<Sba> {
-        match (self.set.events.len(), &self.state) {
+        let mut actions = match (self.set.events.len(), &self.state) {
+            (_, ScoutingScreenState::LineupWarning) if self.can_fix_lineup() => vec![
+                Sba::Simple(ScreenActionEnum::Confirm),
+                Sba::Simple(ScreenActionEnum::FixLineup),
+                Sba::Simple(ScreenActionEnum::Back),
+            ],
+            (_, ScoutingScreenState::LineupWarning) => vec![
+                Sba::Simple(ScreenActionEnum::Confirm),
+                Sba::Simple(ScreenActionEnum::Back),
+            ],
+            (_, ScoutingScreenState::CodeLine) => vec![
+                Sba::Simple(ScreenActionEnum::Confirm),
+                Sba::Simple(ScreenActionEnum::Back),
+            ],
+            (_, ScoutingScreenState::Notes) => vec![
+                Sba::Simple(ScreenActionEnum::Next),
+                Sba::Simple(ScreenActionEnum::Previous),
+                Sba::Simple(ScreenActionEnum::Confirm),
+                Sba::Simple(ScreenActionEnum::Back),
+            ],
+            (_, ScoutingScreenState::SetOver) => vec![
+                Sba::Simple(ScreenActionEnum::Undo),
+                Sba::Simple(ScreenActionEnum::Back),
+                Sba::Simple(ScreenActionEnum::Quit),
+            ],
             (0, ScoutingScreenState::Event) => vec![
+                Sba::Simple(ScreenActionEnum::CodeLine),
+                Sba::Simple(ScreenActionEnum::Notes),
+                Sba::Simple(ScreenActionEnum::Back),
+                Sba::Simple(ScreenActionEnum::Quit),
+            ],
+            (_, ScoutingScreenState::Event) => vec![
+                Sba::Simple(ScreenActionEnum::Undo),
+                Sba::Simple(ScreenActionEnum::CodeLine),
+                Sba::Simple(ScreenActionEnum::Notes),
                 Sba::Simple(ScreenActionEnum::Back),
                 Sba::Simple(ScreenActionEnum::Quit),
             ],
@@ -985,7 +2182,30 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
                 Sba::Simple(ScreenActionEnum::Back),
                 Sba::Simple(ScreenActionEnum::Quit),
             ],
+        };
+        let scouting_step = matches!(
+            self.state,
+            ScoutingScreenState::Event
+                | ScoutingScreenState::Player
+                | ScoutingScreenState::Eval
+                | ScoutingScreenState::Replacement
+        );
+        if scouting_step || self.state == ScoutingScreenState::SetOver {
+            let position = actions
+                .iter()
+                .position(|a| *a.key() == ScreenActionEnum::Back)
+                .unwrap_or(actions.len());
+            actions.insert(position, Sba::Simple(ScreenActionEnum::LiveStats));
+        }
+        if scouting_step && !self.redo_stack.is_empty() {
+            // right after undo, when available
+            let position = actions
+                .iter()
+                .position(|a| *a.key() == ScreenActionEnum::Undo)
+                .map_or(0, |p| p + 1);
+            actions.insert(position, Sba::Simple(ScreenActionEnum::Redo));
         }
+        actions
     }
 
     fn render_set_status(&self, f: &mut Frame, area: Rect) {
@@ -1031,7 +2251,7 @@ impl<SSW: SetWriter + Send + Sync> ScoutingScreen<SSW> {
             (
                 chunks[3],
                 self.player
-                    .and_then(|p| self.current_match.team.find_player(p))
+                    .and_then(|p| self.current_match.find_player(p))
                     .map(|p| p.name.clone()),
                 Color::Magenta,
                 Color::Black,
@@ -1,17 +1,25 @@
 use crate::{
+    errors::AppError,
     localization::current_labels,
-    providers::set_writer::SetWriter,
+    providers::{
+        match_writer::MatchWriter,
+        set_writer::SetWriter,
+        team_reader::TeamReader,
+        team_writer::{TeamInput, TeamWriter},
+    },
     screens::{
-        components::notify_banner::NotifyBanner,
+        components::{notify_banner::NotifyBanner, select::Select, text_box::TextBox},
         scouting_screen::ScoutingScreen,
         screen::{get_keybinding_actions, AppAction, Renderable, Sba, ScreenAsync},
     },
     shapes::{
-        enums::{RoleEnum, ScreenActionEnum, TeamSideEnum},
+        enums::{RoleEnum, RotationEnum, ScreenActionEnum, TeamSideEnum},
         keybinding::ScreenKeyBindings,
         player::PlayerEntry,
         r#match::MatchEntry,
+        set::{MidSetStartEntry, SetEntry, SetupConflict},
         settings::Settings,
+        team::{LineupTemplateEntry, TeamEntry},
     },
 };
 use async_trait::async_trait;
@@ -26,7 +34,12 @@ use std::{collections::HashSet, sync::Arc};
 use uuid::Uuid;
 
 #[derive(Debug)]
-pub struct StartSetScreen<SSW: SetWriter + Send + Sync> {
+pub struct StartSetScreen<
+    MW: MatchWriter + Send + Sync,
+    SSW: SetWriter + Send + Sync,
+    TR: TeamReader + Send + Sync,
+    TW: TeamWriter + Send + Sync,
+> {
     settings: Settings,
     current_match: MatchEntry,
     set_number: u8,
@@ -37,19 +50,57 @@ pub struct StartSetScreen<SSW: SetWriter + Send + Sync> {
     state: StartSetScreenState,
     serving_team: Option<TeamSideEnum>,
     list_state: TableState,
+    mid_set_start: Option<MidSetStartEntry>,
+    score_us: TextBox,
+    score_them: TextBox,
+    mid_set_rotation: Select<RotationEnum>,
+    mid_set_serving_team: Select<TeamSideEnum>,
+    mid_set_field: usize,
+    // the set whose starting setup is being corrected, if any
+    editing: Option<SetEntry>,
+    setup_conflicts: Vec<SetupConflict>,
+    templates_state: TableState,
+    template_name: TextBox,
     back_stack_count: Option<u8>,
+    match_writer: Arc<MW>,
     set_writer: Arc<SSW>,
+    team_reader: Arc<TR>,
+    team_writer: Arc<TW>,
     screen_key_bindings: ScreenKeyBindings,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum StartSetScreenState {
     SelectServingTeam,
     // (player position index, setter, libero)
     SelectLineupPlayers(usize, Option<Uuid>, Option<Uuid>),
+    // score, rotation and serving team of a set joined in progress, then back to the lineup
+    // selection step it was opened from
+    MidSetStart(usize, Option<Uuid>, Option<Uuid>),
+    // events of the edited set that do not fit the new setup
+    SetupConflicts,
+    // a lineup to apply at once, then back to the lineup selection step it was opened from
+    SelectTemplate(usize, Option<Uuid>, Option<Uuid>),
+    // name of the template for the lineup just picked
+    SaveTemplate,
+}
+
+/// A lineup that can be applied at once: one of the previous set or a saved template.
+#[derive(Debug, Clone)]
+struct LineupChoice {
+    label: String,
+    lineup: LineupTemplateEntry,
+    fallback_libero: Option<Uuid>,
+    saved: bool,
 }
 
-impl<SSW: SetWriter + Send + Sync + 'static> Renderable for StartSetScreen<SSW> {
+impl<
+        MW: MatchWriter + Send + Sync + 'static,
+        SSW: SetWriter + Send + Sync + 'static,
+        TR: TeamReader + Send + Sync + 'static,
+        TW: TeamWriter + Send + Sync + 'static,
+    > Renderable for StartSetScreen<MW, SSW, TR, TW>
+{
     fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
         use StartSetScreenState::*;
         let rows = Layout::default()
@@ -76,12 +127,22 @@ impl<SSW: SetWriter + Send + Sync + 'static> Renderable for StartSetScreen<SSW>
                     footer_left,
                 );
             }
+            MidSetStart(..) => self.render_mid_set_start(f, rows[1], footer_left),
+            SetupConflicts => self.render_setup_conflicts(f, rows[1], footer_left),
+            SelectTemplate(..) => self.render_lineup_templates(f, rows[1], footer_left),
+            SaveTemplate => self.render_save_template(f, rows[1], footer_left),
         }
     }
 }
 
 #[async_trait]
-impl<SSW: SetWriter + Send + Sync + 'static> ScreenAsync for StartSetScreen<SSW> {
+impl<
+        MW: MatchWriter + Send + Sync + 'static,
+        SSW: SetWriter + Send + Sync + 'static,
+        TR: TeamReader + Send + Sync + 'static,
+        TW: TeamWriter + Send + Sync + 'static,
+    > ScreenAsync for StartSetScreen<MW, SSW, TR, TW>
+{
     async fn handle_key(&mut self, key: KeyEvent) -> AppAction {
         use StartSetScreenState::*;
         if let Some(key_combination) = self.screen_key_bindings.transform(key) {
@@ -92,6 +153,16 @@ impl<SSW: SetWriter + Send + Sync + 'static> ScreenAsync for StartSetScreen<SSW>
                     AppAction::None
                 }
                 (SelectServingTeam, _) => self.handle_serving_team_selection(action),
+                (SetupConflicts, _) => self.handle_setup_conflicts_key(action),
+                (SelectTemplate(current_player_position, setter, libero), _) => {
+                    let previous = SelectLineupPlayers(*current_player_position, *setter, *libero);
+                    self.handle_lineup_templates_key(action, previous).await
+                }
+                (SaveTemplate, _) => self.handle_save_template_key(action, key).await,
+                (MidSetStart(current_player_position, setter, libero), _) => {
+                    let previous = SelectLineupPlayers(*current_player_position, *setter, *libero);
+                    self.handle_mid_set_start_key(action, key, previous)
+                }
                 (SelectLineupPlayers(current_player_position, setter, libero), _) => {
                     self.handle_select_lineup_players_key(
                         action,
@@ -111,7 +182,13 @@ impl<SSW: SetWriter + Send + Sync + 'static> ScreenAsync for StartSetScreen<SSW>
     async fn refresh_data(&mut self) {}
 }
 
-impl<SSW: SetWriter + Send + Sync + 'static> StartSetScreen<SSW> {
+impl<
+        MW: MatchWriter + Send + Sync + 'static,
+        SSW: SetWriter + Send + Sync + 'static,
+        TR: TeamReader + Send + Sync + 'static,
+        TW: TeamWriter + Send + Sync + 'static,
+    > StartSetScreen<MW, SSW, TR, TW>
+{
     fn handle_serving_team_selection(&mut self, action: Option<ScreenActionEnum>) -> AppAction {
         use TeamSideEnum::*;
         match (action, self.serving_team) {
@@ -170,6 +247,10 @@ impl<SSW: SetWriter + Send + Sync + 'static> StartSetScreen<SSW> {
         libero: Option<Uuid>,
     ) -> AppAction {
         match (current_player_position, self.set_number, setter, libero) {
+            // the serving team is not part of the setup to correct
+            (0, _, _, _) if self.editing.is_some() => {
+                return AppAction::Back(false, self.back_stack_count)
+            }
             // set number (1 or 5) => back to serving team selection
             (0, 1 | 5, _, _) => {
                 self.state = StartSetScreenState::SelectServingTeam;
@@ -233,7 +314,18 @@ impl<SSW: SetWriter + Send + Sync + 'static> StartSetScreen<SSW> {
             ) => {
                 // this is due to the start of a new set, so selection is cleaned up here
                 self.list_state.select(self.default_select(0, None, None));
-                match self
+                if let Some(original) = self.editing.clone() {
+                    return self
+                        .save_setup(
+                            original,
+                            lineup,
+                            initial_libero.id,
+                            fallback_libero.map(|f| f.id),
+                            initial_setter.id,
+                        )
+                        .await;
+                }
+                let created = self
                     .set_writer
                     .create(
                         &self.current_match,
@@ -245,12 +337,16 @@ impl<SSW: SetWriter + Send + Sync + 'static> StartSetScreen<SSW> {
                         initial_setter.id,
                         vec![], // no events at the start of the set
                     )
-                    .await
-                    .and_then(|set_entry| {
-                        set_entry
-                            .compute_snapshot()
-                            .map(|(snapshot, options)| (set_entry, snapshot, options))
-                    }) {
+                    .await;
+                let created = match created {
+                    Ok(set_entry) => self.save_mid_set_start(set_entry).await,
+                    Err(e) => Err(e),
+                };
+                match created.and_then(|set_entry| {
+                    set_entry
+                        .compute_snapshot()
+                        .map(|(snapshot, options)| (set_entry, snapshot, options))
+                }) {
                     Ok((set_entry, snapshot, available_options)) => {
                         AppAction::SwitchScreen(Box::new(ScoutingScreen::new(
                             self.settings.clone(),
@@ -259,6 +355,7 @@ impl<SSW: SetWriter + Send + Sync + 'static> StartSetScreen<SSW> {
                             snapshot,
                             available_options,
                             self.back_stack_count.map(|x| x + 1),
+                            self.match_writer.clone(),
                             self.set_writer.clone(),
                         )))
                     }
@@ -316,7 +413,7 @@ impl<SSW: SetWriter + Send + Sync + 'static> StartSetScreen<SSW> {
                 .select(self.default_select(6, Some(setter_id), Some(libero.id)));
             self.state =
                 StartSetScreenState::SelectLineupPlayers(6, Some(setter_id), Some(libero.id));
-            if self.current_match.team.players.len() < 8 {
+            if !self.can_have_fallback_libero() {
                 // cannot have a second libero with less than 8 players
                 self.handle_fallback_libero_selection(None, available_players)
                     .await
@@ -381,6 +478,20 @@ impl<SSW: SetWriter + Send + Sync + 'static> StartSetScreen<SSW> {
             (Some(ScreenActionEnum::Back), _, _) => {
                 self.handle_lineup_selection_back(current_player_position, setter, libero)
             }
+            (Some(ScreenActionEnum::LineupTemplates), _, _) => {
+                self.open_lineup_templates(current_player_position, setter, libero)
+            }
+            (Some(ScreenActionEnum::SaveLineupTemplate), _, _)
+                if matches!(
+                    (current_player_position, setter, libero),
+                    (6, Some(_), Some(_))
+                ) =>
+            {
+                self.open_save_template()
+            }
+            (Some(ScreenActionEnum::MidSetStart), _, _) if self.editing.is_none() => {
+                self.open_mid_set_start(current_player_position, setter, libero)
+            }
             (Some(ScreenActionEnum::Select), _, Some(selection_index)) => {
                 match (current_player_position, setter, libero) {
                     // players selection
@@ -427,7 +538,10 @@ impl<SSW: SetWriter + Send + Sync + 'static> StartSetScreen<SSW> {
         set_number: u8,
         serving_team: Option<TeamSideEnum>,
         back_stack_count: Option<u8>,
+        match_writer: Arc<MW>,
         set_writer: Arc<SSW>,
+        team_reader: Arc<TR>,
+        team_writer: Arc<TW>,
     ) -> Self {
         StartSetScreen {
             settings,
@@ -439,17 +553,63 @@ impl<SSW: SetWriter + Send + Sync + 'static> StartSetScreen<SSW> {
             initial_libero: None,
             notify_message: NotifyBanner::new(),
             list_state: TableState::default(),
+            mid_set_start: None,
+            score_us: TextBox::new(String::new(), false, None),
+            score_them: TextBox::new(String::new(), false, None),
+            mid_set_rotation: Select::new(String::new(), RotationEnum::ALL.to_vec(), None, false),
+            mid_set_serving_team: Select::new(
+                String::new(),
+                TeamSideEnum::ALL.to_vec(),
+                None,
+                false,
+            ),
+            mid_set_field: 0,
+            editing: None,
+            setup_conflicts: vec![],
+            templates_state: TableState::default(),
+            template_name: TextBox::new(String::new(), true, None),
             state: if set_number == 1 || set_number == 5 {
                 StartSetScreenState::SelectServingTeam
             } else {
                 StartSetScreenState::SelectLineupPlayers(0, None, None)
             },
             back_stack_count,
+            match_writer,
             set_writer,
+            team_reader,
+            team_writer,
             screen_key_bindings: ScreenKeyBindings::empty(),
         }
     }
 
+    /// Picks the starting setup of an already scouted set again.
+    pub fn edit(
+        settings: Settings,
+        current_match: MatchEntry,
+        set: SetEntry,
+        back_stack_count: Option<u8>,
+        match_writer: Arc<MW>,
+        set_writer: Arc<SSW>,
+        team_reader: Arc<TR>,
+        team_writer: Arc<TW>,
+    ) -> Self {
+        let mut screen = Self::new(
+            settings,
+            current_match,
+            set.set_number,
+            Some(set.serving_team),
+            back_stack_count,
+            match_writer,
+            set_writer,
+            team_reader,
+            team_writer,
+        );
+        screen.state = StartSetScreenState::SelectLineupPlayers(0, None, None);
+        screen.mid_set_start = set.mid_set_start;
+        screen.editing = Some(set);
+        screen
+    }
+
     fn get_available_players(
         &self,
         position_index: usize,
@@ -465,9 +625,8 @@ impl<SSW: SetWriter + Send + Sync + 'static> StartSetScreen<SSW> {
                     selected_ids.insert(initial_libero.id);
                 }
                 self.current_match
-                    .team
-                    .players
-                    .iter()
+                    .active_players()
+                    .into_iter()
                     .filter(|p| !selected_ids.contains(&p.id))
                     .cloned()
                     .collect()
@@ -577,26 +736,33 @@ impl<SSW: SetWriter + Send + Sync + 'static> StartSetScreen<SSW> {
             chunks[0],
         );
         self.render_lineup_selection_court(f, chunks[1], position_index, setter);
-        self.render_lineup_selection_footer(f, footer_area);
+        self.render_lineup_selection_footer(f, footer_area, setter.is_some() && libero.is_some());
     }
 
-    fn render_lineup_selection_footer(&mut self, f: &mut Frame, area: Rect) {
+    fn render_lineup_selection_footer(&mut self, f: &mut Frame, area: Rect, complete: bool) {
         let block = Block::default()
             .borders(Borders::NONE)
             .padding(Padding::new(1, 0, 0, 0));
 
-        let lineup_selection_actions = &[
+        let mut lineup_selection_actions = vec![
             Sba::Simple(ScreenActionEnum::Up),
             Sba::Simple(ScreenActionEnum::Down),
             Sba::Simple(ScreenActionEnum::Select),
-            Sba::Simple(ScreenActionEnum::Back),
+            Sba::Simple(ScreenActionEnum::LineupTemplates),
         ];
+        if complete {
+            lineup_selection_actions.push(Sba::Simple(ScreenActionEnum::SaveLineupTemplate));
+        }
+        if self.editing.is_none() {
+            lineup_selection_actions.push(Sba::Simple(ScreenActionEnum::MidSetStart));
+        }
+        lineup_selection_actions.push(Sba::Simple(ScreenActionEnum::Back));
         self.screen_key_bindings = self
             .settings
             .keybindings
-            .slice(Sba::keys(lineup_selection_actions));
+            .slice(Sba::keys(&lineup_selection_actions));
         let footer_entries =
-            get_keybinding_actions(&self.settings.keybindings, lineup_selection_actions);
+            get_keybinding_actions(&self.settings.keybindings, &lineup_selection_actions);
         let paragraph = Paragraph::new(
             footer_entries
                 .iter()
@@ -701,6 +867,16 @@ impl<SSW: SetWriter + Send + Sync + 'static> StartSetScreen<SSW> {
                 self.current_match.opponent, self.current_match.team.name
             )
         };
+        let title = match &self.mid_set_start {
+            Some(start) => format!(
+                "{} ({} {}-{})",
+                title,
+                current_labels().scouted_from,
+                start.score_us,
+                start.score_them
+            ),
+            None => title,
+        };
         let content = Paragraph::new(title)
             .style(
                 Style::default()
@@ -711,11 +887,714 @@ impl<SSW: SetWriter + Send + Sync + 'static> StartSetScreen<SSW> {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(format!("set {:?}", self.set_number)),
+                    .title(match self.editing {
+                        Some(_) => format!(
+                            "set {:?} - {}",
+                            self.set_number,
+                            current_labels().edit_set_setup
+                        ),
+                        None => format!("set {:?}", self.set_number),
+                    }),
             );
         f.render_widget(content, area);
     }
 
+    /* mid-set start */
+
+    fn open_mid_set_start(
+        &mut self,
+        current_player_position: usize,
+        setter: Option<Uuid>,
+        libero: Option<Uuid>,
+    ) -> AppAction {
+        let labels = current_labels();
+        let numeric = |value: &str, c: char| c.is_ascii_digit() && value.len() < 2;
+        let start = self.mid_set_start;
+        self.score_us = TextBox::with_validator(
+            labels.our_score.to_owned(),
+            true,
+            start.map(|s| s.score_us.to_string()).as_deref(),
+            numeric,
+        );
+        self.score_them = TextBox::with_validator(
+            labels.their_score.to_owned(),
+            false,
+            start.map(|s| s.score_them.to_string()).as_deref(),
+            numeric,
+        );
+        self.mid_set_rotation = Select::new(
+            labels.setter_position.to_owned(),
+            RotationEnum::ALL.to_vec(),
+            Some(start.map_or(RotationEnum::One, |s| s.rotation)),
+            false,
+        );
+        self.mid_set_serving_team = Select::new(
+            labels.serving_team.to_owned(),
+            TeamSideEnum::ALL.to_vec(),
+            start.map(|s| s.serving_team).or(self.serving_team),
+            false,
+        );
+        self.mid_set_field = 0;
+        self.state = StartSetScreenState::MidSetStart(current_player_position, setter, libero);
+        AppAction::None
+    }
+
+    fn update_mid_set_writing_modes(&mut self) {
+        self.score_us.writing_mode = self.mid_set_field == 0;
+        self.score_them.writing_mode = self.mid_set_field == 1;
+        self.mid_set_rotation.writing_mode = self.mid_set_field == 2;
+        self.mid_set_serving_team.writing_mode = self.mid_set_field == 3;
+    }
+
+    fn handle_mid_set_start_key(
+        &mut self,
+        action: Option<ScreenActionEnum>,
+        key: KeyEvent,
+        previous: StartSetScreenState,
+    ) -> AppAction {
+        use KeyCode::*;
+        match (action, key.code) {
+            (Some(ScreenActionEnum::Back), _) => {
+                self.state = previous;
+                AppAction::None
+            }
+            (Some(ScreenActionEnum::Confirm), _) => {
+                self.confirm_mid_set_start(previous);
+                AppAction::None
+            }
+            (Some(ScreenActionEnum::Next), _) => {
+                self.mid_set_field = (self.mid_set_field + 1) % 4;
+                self.update_mid_set_writing_modes();
+                AppAction::None
+            }
+            (Some(ScreenActionEnum::Previous), _) => {
+                self.mid_set_field = (self.mid_set_field + 3) % 4;
+                self.update_mid_set_writing_modes();
+                AppAction::None
+            }
+            (_, Up) => {
+                match self.mid_set_field {
+                    2 => self.mid_set_rotation.handle_up(),
+                    3 => self.mid_set_serving_team.handle_up(),
+                    _ => {}
+                }
+                AppAction::None
+            }
+            (_, Down) => {
+                match self.mid_set_field {
+                    2 => self.mid_set_rotation.handle_down(),
+                    3 => self.mid_set_serving_team.handle_down(),
+                    _ => {}
+                }
+                AppAction::None
+            }
+            (_, Char(c)) => {
+                match self.mid_set_field {
+                    0 => self.score_us.handle_char(c),
+                    1 => self.score_them.handle_char(c),
+                    _ => {}
+                }
+                AppAction::None
+            }
+            (_, Backspace) => {
+                match self.mid_set_field {
+                    0 => self.score_us.handle_backspace(),
+                    1 => self.score_them.handle_backspace(),
+                    _ => {}
+                }
+                AppAction::None
+            }
+            _ => AppAction::None,
+        }
+    }
+
+    fn confirm_mid_set_start(&mut self, previous: StartSetScreenState) {
+        let score = |text_box: &TextBox| {
+            text_box
+                .get_selected_value()
+                .and_then(|value| value.parse::<u8>().ok())
+                .unwrap_or(0)
+        };
+        let (Some(rotation), Some(serving_team)) = (
+            self.mid_set_rotation.get_selected_value(),
+            self.mid_set_serving_team.get_selected_value(),
+        ) else {
+            return;
+        };
+        let start = MidSetStartEntry {
+            score_us: score(&self.score_us),
+            score_them: score(&self.score_them),
+            rotation,
+            serving_team,
+        };
+        if !start.is_valid(self.set_number) {
+            self.notify_message
+                .set_error(current_labels().invalid_mid_set_start.to_string());
+            return;
+        }
+        // joining at 0-0 missed nothing: it is just a regular start
+        self.mid_set_start = (start.score_us > 0 || start.score_them > 0).then_some(start);
+        self.state = previous;
+    }
+
+    /// Stores the mid-set start into the descriptor of a just created set.
+    async fn save_mid_set_start(&self, mut set_entry: SetEntry) -> Result<SetEntry, AppError> {
+        if self.mid_set_start.is_some() {
+            set_entry.mid_set_start = self.mid_set_start;
+            self.set_writer
+                .update(&self.current_match, &set_entry)
+                .await?;
+        }
+        Ok(set_entry)
+    }
+
+    fn render_mid_set_start(&mut self, f: &mut Frame, area: Rect, footer_area: Rect) {
+        let labels = current_labels();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(labels.mid_set_start);
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+            ])
+            .split(inner);
+        self.score_us.render(f, chunks[0]);
+        self.score_them.render(f, chunks[1]);
+        self.mid_set_rotation.render(f, chunks[2]);
+        self.mid_set_serving_team.render(f, chunks[3]);
+        let hint = Paragraph::new(labels.mid_set_start_hint)
+            .style(Style::default().fg(Color::Cyan))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan))
+                    .title(labels.hint),
+            );
+        f.render_widget(hint, chunks[4]);
+        let block = Block::default()
+            .borders(Borders::NONE)
+            .padding(Padding::new(1, 0, 0, 0));
+        let mid_set_start_actions = &[
+            Sba::Simple(ScreenActionEnum::Next),
+            Sba::Simple(ScreenActionEnum::Previous),
+            Sba::Simple(ScreenActionEnum::Confirm),
+            Sba::Simple(ScreenActionEnum::Back),
+        ];
+        self.screen_key_bindings = self
+            .settings
+            .keybindings
+            .slice(Sba::keys(mid_set_start_actions));
+        let footer_entries =
+            get_keybinding_actions(&self.settings.keybindings, mid_set_start_actions);
+        let paragraph = Paragraph::new(
+            footer_entries
+                .iter()
+                .map(|(key, desc)| format!("{} = {}", key, desc))
+                .collect::<Vec<_>>()
+                .join(" | "),
+        )
+        .block(block);
+        f.render_widget(paragraph, footer_area);
+    }
+
+    /* set setup editing */
+
+    async fn save_setup(
+        &mut self,
+        original: SetEntry,
+        lineup: [Uuid; 6],
+        libero: Uuid,
+        fallback_libero: Option<Uuid>,
+        setter: Uuid,
+    ) -> AppAction {
+        match original
+            .with_setup(lineup, libero, fallback_libero, setter)
+            .and_then(|set| set.setup_conflicts().map(|conflicts| (set, conflicts)))
+        {
+            Ok((set, conflicts)) if conflicts.is_empty() => {
+                match self.set_writer.update(&self.current_match, &set).await {
+                    Ok(_) => AppAction::Back(true, self.back_stack_count),
+                    Err(_) => {
+                        self.notify_message
+                            .set_error(current_labels().could_not_save_set.to_string());
+                        AppAction::None
+                    }
+                }
+            }
+            Ok((_, conflicts)) => {
+                self.setup_conflicts = conflicts;
+                self.state = StartSetScreenState::SetupConflicts;
+                AppAction::None
+            }
+            Err(_) => {
+                self.notify_message
+                    .set_error(current_labels().could_not_compute_snapshot.to_string());
+                AppAction::None
+            }
+        }
+    }
+
+    fn handle_setup_conflicts_key(&mut self, action: Option<ScreenActionEnum>) -> AppAction {
+        if action == Some(ScreenActionEnum::Back) {
+            // start over with the lineup selection
+            self.lineup.clear();
+            self.initial_setter = None;
+            self.initial_libero = None;
+            self.setup_conflicts.clear();
+            self.list_state.select(self.default_select(0, None, None));
+            self.state = StartSetScreenState::SelectLineupPlayers(0, None, None);
+        }
+        AppAction::None
+    }
+
+    fn render_setup_conflicts(&mut self, f: &mut Frame, area: Rect, footer_area: Rect) {
+        let labels = current_labels();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(1)])
+            .split(area);
+        let hint = Paragraph::new(labels.setup_conflicts_hint)
+            .style(Style::default().fg(Color::Cyan))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan))
+                    .title(labels.hint),
+            );
+        f.render_widget(hint, chunks[0]);
+        let header = Row::new(vec!["#", labels.event_type, labels.description]).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+        let players = &self.current_match.players;
+        let rows: Vec<Row> = self
+            .setup_conflicts
+            .iter()
+            .map(|conflict| {
+                // lineup errors refer to players by id
+                let reason = players.iter().fold(conflict.reason.clone(), |reason, p| {
+                    reason.replace(&p.id.to_string(), &format!("{} {}", p.number, p.name))
+                });
+                Row::new(vec![
+                    (conflict.event_index + 1).to_string(),
+                    conflict.event_type.to_string(),
+                    reason,
+                ])
+            })
+            .collect();
+        let table = Table::new(
+            rows,
+            vec![
+                Constraint::Length(8),
+                Constraint::Length(8),
+                Constraint::Min(1),
+            ],
+        )
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(labels.setup_conflicts),
+        );
+        f.render_widget(table, chunks[1]);
+        let block = Block::default()
+            .borders(Borders::NONE)
+            .padding(Padding::new(1, 0, 0, 0));
+        let setup_conflicts_actions = &[Sba::Simple(ScreenActionEnum::Back)];
+        self.screen_key_bindings = self
+            .settings
+            .keybindings
+            .slice(Sba::keys(setup_conflicts_actions));
+        let footer_entries =
+            get_keybinding_actions(&self.settings.keybindings, setup_conflicts_actions);
+        let paragraph = Paragraph::new(
+            footer_entries
+                .iter()
+                .map(|(key, desc)| format!("{} = {}", key, desc))
+                .collect::<Vec<_>>()
+                .join(" | "),
+        )
+        .block(block);
+        f.render_widget(paragraph, footer_area);
+    }
+
+    /* lineup templates */
+
+    fn lineup_choices(&self) -> Vec<LineupChoice> {
+        let labels = current_labels();
+        let mut choices = vec![];
+        if let Some(previous) = self
+            .current_match
+            .sets
+            .iter()
+            .find(|s| s.set_number + 1 == self.set_number)
+        {
+            let lineup = LineupTemplateEntry {
+                name: String::new(),
+                positions: previous.initial_positions,
+                setter: previous.setter,
+                libero: previous.libero,
+            };
+            choices.extend((0..6).map(|rotations| LineupChoice {
+                label: match rotations {
+                    0 => labels.same_as_previous_set.to_string(),
+                    n => labels.previous_set_rotated_by.replace("{}", &n.to_string()),
+                },
+                lineup: lineup.rotated(rotations),
+                fallback_libero: previous.fallback_libero,
+                saved: false,
+            }));
+        }
+        choices.extend(
+            self.current_match
+                .team
+                .lineups
+                .iter()
+                .map(|lineup| LineupChoice {
+                    label: lineup.name.clone(),
+                    lineup: lineup.clone(),
+                    fallback_libero: None,
+                    saved: true,
+                }),
+        );
+        choices
+    }
+
+    fn open_lineup_templates(
+        &mut self,
+        current_player_position: usize,
+        setter: Option<Uuid>,
+        libero: Option<Uuid>,
+    ) -> AppAction {
+        if self.lineup_choices().is_empty() {
+            self.notify_message
+                .set_error(current_labels().no_lineup_templates.to_string());
+            return AppAction::None;
+        }
+        self.templates_state.select(Some(0));
+        self.state = StartSetScreenState::SelectTemplate(current_player_position, setter, libero);
+        AppAction::None
+    }
+
+    async fn handle_lineup_templates_key(
+        &mut self,
+        action: Option<ScreenActionEnum>,
+        previous: StartSetScreenState,
+    ) -> AppAction {
+        let choices = self.lineup_choices();
+        let selected = self
+            .templates_state
+            .selected()
+            .and_then(|i| choices.get(i).cloned());
+        match (action, selected) {
+            (Some(ScreenActionEnum::Back), _) => {
+                self.state = previous;
+                AppAction::None
+            }
+            (Some(ScreenActionEnum::Up), _) => {
+                self.templates_state.select_previous();
+                AppAction::None
+            }
+            (Some(ScreenActionEnum::Down), _) => {
+                let last = choices.len().saturating_sub(1);
+                self.templates_state.select(Some(
+                    self.templates_state
+                        .selected()
+                        .map_or(0, |i| (i + 1).min(last)),
+                ));
+                AppAction::None
+            }
+            (Some(ScreenActionEnum::Select), Some(choice)) => self.apply_lineup(choice).await,
+            (Some(ScreenActionEnum::Delete), Some(choice)) if choice.saved => {
+                let name = choice.lineup.name;
+                if self
+                    .save_lineups(|lineups| lineups.retain(|l| l.name != name))
+                    .await
+                {
+                    if self.lineup_choices().is_empty() {
+                        self.state = previous;
+                    } else {
+                        let last = self.lineup_choices().len() - 1;
+                        self.templates_state
+                            .select(self.templates_state.selected().map(|i| i.min(last)));
+                    }
+                }
+                AppAction::None
+            }
+            _ => AppAction::None,
+        }
+    }
+
+    /// A fallback libero needs at least 8 players on the roster of the match season.
+    fn can_have_fallback_libero(&self) -> bool {
+        self.current_match.active_players().len() >= 8
+    }
+
+    /// Fills the whole lineup, leaving the fallback libero to be picked.
+    async fn apply_lineup(&mut self, choice: LineupChoice) -> AppAction {
+        let current_match = &self.current_match;
+        let available = |id: &Uuid| {
+            current_match
+                .find_player(*id)
+                .filter(|p| !p.deleted)
+                .cloned()
+        };
+        let players: Option<Vec<PlayerEntry>> =
+            choice.lineup.positions.iter().map(available).collect();
+        let (Some(players), Some(libero)) = (players, available(&choice.lineup.libero)) else {
+            self.notify_message
+                .set_error(current_labels().lineup_template_not_available.to_string());
+            return AppAction::None;
+        };
+        let Some(setter) = players
+            .iter()
+            .find(|p| p.id == choice.lineup.setter)
+            .cloned()
+        else {
+            self.notify_message
+                .set_error(current_labels().lineup_template_not_available.to_string());
+            return AppAction::None;
+        };
+        self.lineup = players;
+        self.initial_setter = Some(setter.clone());
+        self.initial_libero = Some(libero.clone());
+        self.state = StartSetScreenState::SelectLineupPlayers(6, Some(setter.id), Some(libero.id));
+        let available_players = self.get_available_players(6, Some(setter.id), Some(libero.id));
+        if !self.can_have_fallback_libero() {
+            // cannot have a second libero with less than 8 players
+            return self
+                .handle_fallback_libero_selection(None, available_players)
+                .await;
+        }
+        self.list_state.select(
+            choice
+                .fallback_libero
+                .and_then(|id| available_players.iter().position(|p| p.id == id))
+                .or(self.default_select(6, Some(setter.id), Some(libero.id))),
+        );
+        AppAction::None
+    }
+
+    async fn save_lineups(&mut self, update: impl FnOnce(&mut Vec<LineupTemplateEntry>)) -> bool {
+        // the team is read again, it might have changed since the match was opened:
+        // the update is applied to the stored templates, not to the ones loaded with the match
+        let saved = match self
+            .team_reader
+            .read_single(&self.current_match.team.id)
+            .await
+        {
+            Ok(mut team) => {
+                update(&mut team.lineups);
+                self.team_writer.save(TeamInput::Existing(team)).await
+            }
+            Err(e) => Err(e),
+        };
+        match saved {
+            Ok(team) => {
+                self.current_match.team.lineups = team.lineups;
+                self.notify_message
+                    .set_info(current_labels().operation_successful.to_string());
+                true
+            }
+            Err(_) => {
+                self.notify_message
+                    .set_error(current_labels().could_not_save_team.to_string());
+                false
+            }
+        }
+    }
+
+    fn open_save_template(&mut self) -> AppAction {
+        self.template_name = TextBox::new(current_labels().template_name.to_owned(), true, None);
+        self.state = StartSetScreenState::SaveTemplate;
+        AppAction::None
+    }
+
+    async fn handle_save_template_key(
+        &mut self,
+        action: Option<ScreenActionEnum>,
+        key: KeyEvent,
+    ) -> AppAction {
+        let (Some(setter), Some(libero)) = (
+            self.initial_setter.as_ref().map(|p| p.id),
+            self.initial_libero.as_ref().map(|p| p.id),
+        ) else {
+            return AppAction::None;
+        };
+        let previous = StartSetScreenState::SelectLineupPlayers(6, Some(setter), Some(libero));
+        match (action, key.code) {
+            (Some(ScreenActionEnum::Back), _) => {
+                self.state = previous;
+            }
+            (Some(ScreenActionEnum::Confirm), _) => {
+                let Some(name) = self
+                    .template_name
+                    .get_selected_value()
+                    .map(|n| n.trim().to_string())
+                    .filter(|n| !n.is_empty())
+                else {
+                    self.notify_message
+                        .set_error(current_labels().template_name_cannot_be_empty.to_string());
+                    return AppAction::None;
+                };
+                let Ok(positions) = self
+                    .lineup
+                    .iter()
+                    .map(|p| p.id)
+                    .collect::<Vec<_>>()
+                    .try_into()
+                else {
+                    return AppAction::None;
+                };
+                let template = LineupTemplateEntry {
+                    name,
+                    positions,
+                    setter,
+                    libero,
+                };
+                let saved = self
+                    .save_lineups(|lineups| {
+                        lineups.retain(|l| !l.name.eq_ignore_ascii_case(&template.name));
+                        lineups.push(template);
+                    })
+                    .await;
+                if saved {
+                    self.state = previous;
+                }
+            }
+            (_, KeyCode::Char(c)) => self.template_name.handle_char(c),
+            (_, KeyCode::Backspace) => self.template_name.handle_backspace(),
+            _ => {}
+        }
+        AppAction::None
+    }
+
+    fn render_lineup_templates(&mut self, f: &mut Frame, area: Rect, footer_area: Rect) {
+        let labels = current_labels();
+        let current_match = &self.current_match;
+        let choices = self.lineup_choices();
+        let rows: Vec<Row> = choices
+            .iter()
+            .map(|choice| {
+                let numbers = choice
+                    .lineup
+                    .positions
+                    .iter()
+                    .map(|id| {
+                        current_match
+                            .find_player(*id)
+                            .map_or("?".to_string(), |p| p.number.to_string())
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" - ");
+                let setter = current_match
+                    .find_player(choice.lineup.setter)
+                    .map_or("?".to_string(), |p| p.number.to_string());
+                Row::new(vec![
+                    choice.label.clone(),
+                    numbers,
+                    format!("{} {}", labels.setter_prefix, setter),
+                ])
+            })
+            .collect();
+        let table = Table::new(
+            rows,
+            vec![
+                Constraint::Percentage(40),
+                Constraint::Percentage(40),
+                Constraint::Percentage(20),
+            ],
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(labels.lineup_templates),
+        )
+        .row_highlight_style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .add_modifier(Modifier::REVERSED),
+        )
+        .highlight_symbol(">> ");
+        f.render_stateful_widget(table, area, &mut self.templates_state);
+        let mut lineup_templates_actions = vec![
+            Sba::Simple(ScreenActionEnum::Up),
+            Sba::Simple(ScreenActionEnum::Down),
+            Sba::Simple(ScreenActionEnum::Select),
+        ];
+        if self
+            .templates_state
+            .selected()
+            .and_then(|i| choices.get(i))
+            .is_some_and(|c| c.saved)
+        {
+            lineup_templates_actions.push(Sba::Simple(ScreenActionEnum::Delete));
+        }
+        lineup_templates_actions.push(Sba::Simple(ScreenActionEnum::Back));
+        self.render_footer(f, footer_area, &lineup_templates_actions);
+    }
+
+    fn render_save_template(&mut self, f: &mut Frame, area: Rect, footer_area: Rect) {
+        let labels = current_labels();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(labels.save_lineup_template);
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+            ])
+            .split(inner);
+        self.template_name.render(f, chunks[0]);
+        let hint = Paragraph::new(labels.save_lineup_template_hint)
+            .style(Style::default().fg(Color::Cyan))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan))
+                    .title(labels.hint),
+            );
+        f.render_widget(hint, chunks[1]);
+        self.render_footer(
+            f,
+            footer_area,
+            &[
+                Sba::Simple(ScreenActionEnum::Confirm),
+                Sba::Simple(ScreenActionEnum::Back),
+            ],
+        );
+    }
+
+    fn render_footer(&mut self, f: &mut Frame, area: Rect, actions: &[Sba]) {
+        let block = Block::default()
+            .borders(Borders::NONE)
+            .padding(Padding::new(1, 0, 0, 0));
+        self.screen_key_bindings = self.settings.keybindings.slice(Sba::keys(actions));
+        let footer_entries = get_keybinding_actions(&self.settings.keybindings, actions);
+        let paragraph = Paragraph::new(
+            footer_entries
+                .iter()
+                .map(|(key, desc)| format!("{} = {}", key, desc))
+                .collect::<Vec<_>>()
+                .join(" | "),
+        )
+        .block(block);
+        f.render_widget(paragraph, area);
+    }
+
     fn render_serving_team_button(f: &mut Frame, label: &str, area: Rect, selected: bool) {
         fn centered_line_rect(area: Rect, line_height: u16) -> Rect {
             let h = line_height.min(area.height);
@@ -0,0 +1,205 @@
+use crate::{
+    localization::current_labels,
+    providers::set_writer::SetWriter,
+    screens::{
+        components::{
+            navigation_footer::NavigationFooter, notify_banner::NotifyBanner, text_box::TextBox,
+        },
+        screen::{get_keybinding_actions, AppAction, Renderable, Sba, ScreenAsync},
+    },
+    shapes::{
+        enums::{FriendlyName, ScreenActionEnum},
+        keybinding::ScreenKeyBindings,
+        r#match::MatchEntry,
+        set::SetEntry,
+        settings::Settings,
+        snapshot::EventEntry,
+        timing::format_seconds,
+        video::parse_position,
+    },
+};
+use async_trait::async_trait;
+use chrono::Local;
+use crokey::crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::sync::Arc;
+
+/// Aligns the video of a set to the events, by entering the position where a known event is seen.
+#[derive(Debug)]
+pub struct SyncVideoScreen<SSW: SetWriter + Send + Sync> {
+    position: TextBox,
+    current_match: MatchEntry,
+    set: SetEntry,
+    event: EventEntry,
+    notify_message: NotifyBanner,
+    back: bool,
+    footer: NavigationFooter,
+    footer_entries: Vec<(String, String)>,
+    screen_key_bindings: ScreenKeyBindings,
+    set_writer: Arc<SSW>,
+}
+
+impl<SSW: SetWriter + Send + Sync> Renderable for SyncVideoScreen<SSW> {
+    fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
+        let area = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(4), // 0: event to look for
+                Constraint::Length(3), // 1: video position
+                Constraint::Min(1),
+            ])
+            .split(body);
+        self.notify_message.render(f, footer_right);
+        f.render_widget(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(current_labels().sync_video),
+            body,
+        );
+        f.render_widget(Paragraph::new(self.event_description()), area[0]);
+        self.position.render(f, area[1]);
+        self.footer
+            .render(f, footer_left, self.footer_entries.clone());
+    }
+}
+
+#[async_trait]
+impl<SSW: SetWriter + Send + Sync> ScreenAsync for SyncVideoScreen<SSW> {
+    async fn handle_key(&mut self, key: KeyEvent) -> AppAction {
+        if let Some(key_combination) = self.screen_key_bindings.transform(key) {
+            match (
+                self.screen_key_bindings.get(key_combination),
+                key.code,
+                &self.notify_message.has_value(),
+            ) {
+                (_, _, true) => self.handle_error_reset(),
+                (_, KeyCode::Char(c), _) => {
+                    self.position.handle_char(c);
+                    AppAction::None
+                }
+                (_, KeyCode::Backspace, _) => {
+                    self.position.handle_backspace();
+                    AppAction::None
+                }
+                (Some(ScreenActionEnum::Back), _, _) => AppAction::Back(true, Some(1)),
+                (Some(ScreenActionEnum::Confirm), _, _) => self.handle_enter().await,
+                _ => AppAction::None,
+            }
+        } else {
+            AppAction::None
+        }
+    }
+
+    async fn refresh_data(&mut self) {}
+}
+
+impl<SSW: SetWriter + Send + Sync> SyncVideoScreen<SSW> {
+    pub fn new(
+        settings: Settings,
+        current_match: MatchEntry,
+        set: SetEntry,
+        event: EventEntry,
+        set_writer: Arc<SSW>,
+    ) -> Self {
+        // start from where the event currently falls
+        let current_position = set
+            .video
+            .as_ref()
+            .map(|v| v.position(event.timestamp))
+            .filter(|p| *p >= 0.0)
+            .map(|p| format_seconds(p as i64));
+        let position = TextBox::with_validator(
+            current_labels().video_position.to_owned(),
+            true,
+            current_position.as_deref(),
+            |current: &str, c: char| {
+                current.len() < 12 && (c.is_ascii_digit() || c == ':' || c == '.')
+            },
+        );
+        let actions = &[
+            Sba::Simple(ScreenActionEnum::Confirm),
+            Sba::Simple(ScreenActionEnum::Back),
+        ];
+        let kb = &settings.keybindings;
+        let footer_entries = get_keybinding_actions(kb, actions);
+        let screen_key_bindings = kb.slice(Sba::keys(actions));
+        SyncVideoScreen {
+            position,
+            current_match,
+            set,
+            event,
+            notify_message: NotifyBanner::new(),
+            back: false,
+            footer: NavigationFooter::new(),
+            footer_entries,
+            screen_key_bindings,
+            set_writer,
+        }
+    }
+
+    fn event_description(&self) -> String {
+        let labels = current_labels();
+        let player = self
+            .event
+            .player
+            .and_then(|p| self.current_match.find_player(p))
+            .map(|p| format!(" {} {}", p.number, p.name))
+            .unwrap_or_default();
+        format!(
+            "{}\nset {}, {}{} ({})",
+            labels.sync_video_hint,
+            self.set.set_number,
+            self.event.event_type.friendly_name(labels),
+            player,
+            self.event
+                .timestamp
+                .with_timezone(&Local)
+                .format("%H:%M:%S")
+        )
+    }
+
+    fn handle_error_reset(&mut self) -> AppAction {
+        self.notify_message.reset();
+        if self.back {
+            AppAction::Back(true, Some(1))
+        } else {
+            AppAction::None
+        }
+    }
+
+    async fn handle_enter(&mut self) -> AppAction {
+        let Some(position) = self
+            .position
+            .get_selected_value()
+            .and_then(|p| parse_position(&p))
+        else {
+            self.notify_message
+                .set_error(current_labels().invalid_video_position.to_string());
+            return AppAction::None;
+        };
+        let Some(video) = self.set.video.as_mut() else {
+            self.notify_message
+                .set_error(current_labels().no_video.to_string());
+            return AppAction::None;
+        };
+        video.sync(&self.event, position);
+        match self.set_writer.update(&self.current_match, &self.set).await {
+            Ok(_) => {
+                self.notify_message
+                    .set_info(current_labels().operation_successful.to_string());
+                self.back = true;
+                AppAction::None
+            }
+            Err(_) => {
+                self.notify_message
+                    .set_error(current_labels().could_not_save_set.to_string());
+                AppAction::None
+            }
+        }
+    }
+}
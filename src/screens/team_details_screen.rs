@@ -5,6 +5,8 @@ use crate::{
     providers::{
         match_reader::MatchReader,
         match_writer::MatchWriter,
+        opponent_reader::OpponentReader,
+        opponent_writer::OpponentWriter,
         set_writer::SetWriter,
         settings_reader::SettingsReader,
         settings_writer::SettingsWriter,
@@ -12,6 +14,7 @@ use crate::{
         team_writer::{PlayerInput, TeamWriter},
     },
     screens::{
+        attack_combinations_screen::AttackCombinationsScreen,
         components::{
             navigation_footer::NavigationFooter, notify_dialogue::NotifyDialogue,
             team_header::TeamHeader,
@@ -24,8 +27,11 @@ use crate::{
         screen::{get_keybinding_actions, AppAction, Renderable, Sba, ScreenAsync},
     },
     shapes::{
-        enums::ScreenActionEnum, keybinding::ScreenKeyBindings, player::PlayerEntry,
-        settings::Settings, team::TeamEntry,
+        enums::{FriendlyName, ScreenActionEnum},
+        keybinding::ScreenKeyBindings,
+        player::PlayerEntry,
+        settings::Settings,
+        team::TeamEntry,
     },
 };
 use async_trait::async_trait;
@@ -45,6 +51,8 @@ pub struct TeamDetailsScreen<
     TW: TeamWriter + Send + Sync + 'static,
     MR: MatchReader + Send + Sync + 'static,
     MW: MatchWriter + Send + Sync + 'static,
+    OR: OpponentReader + Send + Sync + 'static,
+    OW: OpponentWriter + Send + Sync + 'static,
     SSW: SetWriter + Send + Sync + 'static,
     SR: SettingsReader + Send + Sync + 'static,
     SW: SettingsWriter + Send + Sync + 'static,
@@ -60,6 +68,8 @@ pub struct TeamDetailsScreen<
     team_writer: Arc<TW>,
     match_reader: Arc<MR>,
     match_writer: Arc<MW>,
+    opponent_reader: Arc<OR>,
+    opponent_writer: Arc<OW>,
     set_writer: Arc<SSW>,
     settings_reader: Arc<SR>,
     settings_writer: Arc<SW>,
@@ -72,10 +82,12 @@ impl<
         TW: TeamWriter + Send + Sync + 'static,
         MR: MatchReader + Send + Sync + 'static,
         MW: MatchWriter + Send + Sync + 'static,
+        OR: OpponentReader + Send + Sync + 'static,
+        OW: OpponentWriter + Send + Sync + 'static,
         SSW: SetWriter + Send + Sync + 'static,
         SR: SettingsReader + Send + Sync + 'static,
         SW: SettingsWriter + Send + Sync + 'static,
-    > ScreenAsync for TeamDetailsScreen<TR, TW, MR, MW, SSW, SR, SW>
+    > ScreenAsync for TeamDetailsScreen<TR, TW, MR, MW, OR, OW, SSW, SR, SW>
 {
     async fn handle_key(&mut self, key: KeyEvent) -> AppAction {
         if let Some(key_combination) = self.screen_key_bindings.transform(key) {
@@ -151,6 +163,14 @@ impl<
                         self.team_writer.clone(),
                     )))
                 }
+                (Some(ScreenActionEnum::AttackCombinations), _, _, _) => {
+                    AppAction::SwitchScreen(Box::new(AttackCombinationsScreen::new(
+                        self.settings.clone(),
+                        self.team.clone(),
+                        self.team_reader.clone(),
+                        self.team_writer.clone(),
+                    )))
+                }
                 (Some(ScreenActionEnum::MatchList), _, _, _) => {
                     let match_list = self.match_reader.read_all(&self.team).await;
                     match match_list {
@@ -167,8 +187,12 @@ impl<
                             self.team.clone(),
                             ml,
                             self.base_path.clone(),
+                            self.team_reader.clone(),
+                            self.team_writer.clone(),
                             self.match_reader.clone(),
                             self.match_writer.clone(),
+                            self.opponent_reader.clone(),
+                            self.opponent_writer.clone(),
                             self.set_writer.clone(),
                             self.settings_reader.clone(),
                             self.settings_writer.clone(),
@@ -241,10 +265,12 @@ impl<
         TW: TeamWriter + Send + Sync,
         MR: MatchReader + Send + Sync,
         MW: MatchWriter + Send + Sync,
+        OR: OpponentReader + Send + Sync,
+        OW: OpponentWriter + Send + Sync,
         SSW: SetWriter + Send + Sync,
         SR: SettingsReader + Send + Sync,
         SW: SettingsWriter + Send + Sync,
-    > Renderable for TeamDetailsScreen<TR, TW, MR, MW, SSW, SR, SW>
+    > Renderable for TeamDetailsScreen<TR, TW, MR, MW, OR, OW, SSW, SR, SW>
 {
     fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
         self.notifier.render(f, footer_right);
@@ -266,11 +292,23 @@ impl<
                 Constraint::Length(7),
                 Constraint::Length(30),
                 Constraint::Length(20),
+                Constraint::Length(20),
+                Constraint::Length(8),
+                Constraint::Length(10),
+                Constraint::Length(12),
             ],
         )
         .header(
-            Row::new(vec!["#", current_labels().name, current_labels().role])
-                .style(Style::default().add_modifier(Modifier::BOLD)),
+            Row::new(vec![
+                "#",
+                current_labels().name,
+                current_labels().role,
+                current_labels().secondary_role,
+                "cm",
+                current_labels().dominant_hand,
+                current_labels().serve_type,
+            ])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
         )
         .block(
             Block::default()
@@ -281,6 +319,10 @@ impl<
             Constraint::Length(7),
             Constraint::Length(30),
             Constraint::Length(20),
+            Constraint::Length(20),
+            Constraint::Length(8),
+            Constraint::Length(10),
+            Constraint::Length(12),
         ]);
         if self.team.active_players().is_empty() {
             self.render_no_players_yet(f, container[1]);
@@ -304,10 +346,12 @@ impl<
         TW: TeamWriter + Send + Sync,
         MR: MatchReader + Send + Sync,
         MW: MatchWriter + Send + Sync,
+        OR: OpponentReader + Send + Sync,
+        OW: OpponentWriter + Send + Sync,
         SSW: SetWriter + Send + Sync,
         SR: SettingsReader + Send + Sync,
         SW: SettingsWriter + Send + Sync,
-    > TeamDetailsScreen<TR, TW, MR, MW, SSW, SR, SW>
+    > TeamDetailsScreen<TR, TW, MR, MW, OR, OW, SSW, SR, SW>
 {
     pub fn new(
         settings: Settings,
@@ -317,6 +361,8 @@ impl<
         team_writer: Arc<TW>,
         match_reader: Arc<MR>,
         match_writer: Arc<MW>,
+        opponent_reader: Arc<OR>,
+        opponent_writer: Arc<OW>,
         set_writer: Arc<SSW>,
         settings_reader: Arc<SR>,
         settings_writer: Arc<SW>,
@@ -333,6 +379,8 @@ impl<
             team_writer,
             match_reader,
             match_writer,
+            opponent_reader,
+            opponent_writer,
             set_writer,
             settings_reader,
             settings_writer,
@@ -360,6 +408,7 @@ impl<
             Sba::Simple(ScreenActionEnum::EditTeam),
             Sba::Simple(ScreenActionEnum::NewPlayer),
             Sba::Simple(ScreenActionEnum::MatchList),
+            Sba::Simple(ScreenActionEnum::AttackCombinations),
             Sba::Simple(ScreenActionEnum::Back),
             Sba::Simple(ScreenActionEnum::Quit),
         ];
@@ -427,8 +476,23 @@ impl<
             .map(|(i, p)| {
                 let mut row = Row::new(vec![
                     p.number.to_string(),
-                    p.name.clone(),
+                    if p.captain {
+                        format!("{} (C)", p.name)
+                    } else {
+                        p.name.clone()
+                    },
                     p.role.map_or_else(|| "-".to_string(), |r| r.to_string()),
+                    p.secondary_role
+                        .map_or_else(|| "-".to_string(), |r| r.to_string()),
+                    p.height.map_or_else(|| "-".to_string(), |h| h.to_string()),
+                    p.dominant_hand
+                        .map(|h| h.friendly_name(current_labels()))
+                        .unwrap_or("-")
+                        .to_string(),
+                    p.serve_type
+                        .map(|t| t.friendly_name(current_labels()))
+                        .unwrap_or("-")
+                        .to_string(),
                 ]);
                 if i == selected_player {
                     row = row.style(
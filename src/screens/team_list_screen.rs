@@ -3,9 +3,9 @@ use std::{fmt::Debug, path::PathBuf, sync::Arc};
 use crate::{
     localization::current_labels,
     providers::{
-        match_reader::MatchReader, match_writer::MatchWriter, set_writer::SetWriter,
-        settings_reader::SettingsReader, settings_writer::SettingsWriter, team_reader::TeamReader,
-        team_writer::TeamWriter,
+        match_reader::MatchReader, match_writer::MatchWriter, opponent_reader::OpponentReader,
+        opponent_writer::OpponentWriter, set_writer::SetWriter, settings_reader::SettingsReader,
+        settings_writer::SettingsWriter, team_reader::TeamReader, team_writer::TeamWriter,
     },
     screens::{
         components::{navigation_footer::NavigationFooter, notify_banner::NotifyBanner},
@@ -40,6 +40,8 @@ pub struct TeamListScreen<
     SW: SettingsWriter + Send + Sync,
     MR: MatchReader + Send + Sync,
     MW: MatchWriter + Send + Sync,
+    OR: OpponentReader + Send + Sync,
+    OW: OpponentWriter + Send + Sync,
     SSW: SetWriter + Send + Sync,
     SR: SettingsReader + Send + Sync,
 > {
@@ -54,6 +56,8 @@ pub struct TeamListScreen<
     settings_writer: Arc<SW>,
     match_reader: Arc<MR>,
     match_writer: Arc<MW>,
+    opponent_reader: Arc<OR>,
+    opponent_writer: Arc<OW>,
     set_writer: Arc<SSW>,
     settings_reader: Arc<SR>,
     screen_key_bindings: ScreenKeyBindings,
@@ -66,9 +70,11 @@ impl<
         SW: SettingsWriter + Send + Sync + 'static,
         MR: MatchReader + Send + Sync + 'static,
         MW: MatchWriter + Send + Sync + 'static,
+        OR: OpponentReader + Send + Sync + 'static,
+        OW: OpponentWriter + Send + Sync + 'static,
         SSW: SetWriter + Send + Sync + 'static,
         SR: SettingsReader + Send + Sync + 'static,
-    > ScreenAsync for TeamListScreen<TR, TW, SW, MR, MW, SSW, SR>
+    > ScreenAsync for TeamListScreen<TR, TW, SW, MR, MW, OR, OW, SSW, SR>
 {
     async fn refresh_data(&mut self) {
         if let Ok(s) = self.settings_reader.read().await {
@@ -125,6 +131,8 @@ impl<
                             self.team_writer.clone(),
                             self.match_reader.clone(),
                             self.match_writer.clone(),
+                            self.opponent_reader.clone(),
+                            self.opponent_writer.clone(),
                             self.set_writer.clone(),
                             self.settings_reader.clone(),
                             self.settings_writer.clone(),
@@ -187,9 +195,11 @@ impl<
         SW: SettingsWriter + Send + Sync + 'static,
         MR: MatchReader + Send + Sync + 'static,
         MW: MatchWriter + Send + Sync + 'static,
+        OR: OpponentReader + Send + Sync + 'static,
+        OW: OpponentWriter + Send + Sync + 'static,
         SSW: SetWriter + Send + Sync + 'static,
         SR: SettingsReader + Send + Sync + 'static,
-    > Renderable for TeamListScreen<TR, TW, SW, MR, MW, SSW, SR>
+    > Renderable for TeamListScreen<TR, TW, SW, MR, MW, OR, OW, SSW, SR>
 {
     fn render(&mut self, f: &mut Frame, body: Rect, footer_left: Rect, footer_right: Rect) {
         self.notify_message.render(f, footer_right);
@@ -231,9 +241,11 @@ impl<
         SW: SettingsWriter + Send + Sync,
         MR: MatchReader + Send + Sync,
         MW: MatchWriter + Send + Sync,
+        OR: OpponentReader + Send + Sync,
+        OW: OpponentWriter + Send + Sync,
         SSW: SetWriter + Send + Sync,
         SR: SettingsReader + Send + Sync,
-    > TeamListScreen<TR, TW, SW, MR, MW, SSW, SR>
+    > TeamListScreen<TR, TW, SW, MR, MW, OR, OW, SSW, SR>
 {
     pub fn new(
         settings: Settings,
@@ -244,6 +256,8 @@ impl<
         settings_writer: Arc<SW>,
         match_reader: Arc<MR>,
         match_writer: Arc<MW>,
+        opponent_reader: Arc<OR>,
+        opponent_writer: Arc<OW>,
         set_writer: Arc<SSW>,
         settings_reader: Arc<SR>,
     ) -> Self {
@@ -259,6 +273,8 @@ impl<
             settings_writer,
             match_reader,
             match_writer,
+            opponent_reader,
+            opponent_writer,
             set_writer,
             settings_reader,
             screen_key_bindings: ScreenKeyBindings::empty(),
@@ -0,0 +1,320 @@
+use crate::{
+    localization::current_labels,
+    shapes::{
+        enums::{EvalEnum, EventTypeEnum, LineupWarningEnum, TeamSideEnum},
+        player::PlayerEntry,
+        snapshot::{EventEntry, Snapshot},
+        team::TeamEntry,
+    },
+};
+use chrono::{DateTime, Duration, Utc};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Event types written with two letters, the same keys typed in the step by step input.
+const TWO_LETTER_CODES: [(&str, EventTypeEnum, Option<TeamSideEnum>); 6] = [
+    ("OS", EventTypeEnum::OS, None),
+    ("OE", EventTypeEnum::OE, None),
+    ("CL", EventTypeEnum::CL, None),
+    ("CS", EventTypeEnum::CS, None),
+    ("MU", EventTypeEnum::SA, Some(TeamSideEnum::Us)),
+    ("MT", EventTypeEnum::SA, Some(TeamSideEnum::Them)),
+];
+
+/// A single code of a code line, with the players still given by jersey number.
+///
+/// Codes are written as `[numbers][type][extras][evaluation]`, for example `7P+`, `12AX1#`
+/// (attack with the `X1` combination), `4.9BT-` (touched block by 4 and 9), `3R15`
+/// (15 replaces 3), `5CS`, `OE` or `MU` (point awarded to us). The server can be left out
+/// of a serve: the player in position 1 is used.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeEntry {
+    pub event_type: EventTypeEnum,
+    /// Jersey numbers of the player and, for blocks, of the other blockers.
+    pub numbers: Vec<u8>,
+    pub eval: Option<EvalEnum>,
+    /// Jersey number of the player coming in, for substitutions.
+    pub replacement: Option<u8>,
+    pub combination: Option<String>,
+    pub touched: bool,
+    pub awarded_to: Option<TeamSideEnum>,
+}
+
+/// A code that could not be turned into an event, with the index of its token in the line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeLineError {
+    pub token: usize,
+    pub message: String,
+}
+
+/// An event read from a code line, with the lineup rules it breaks.
+#[derive(Debug, Clone)]
+pub struct CodeLineEvent {
+    pub event: EventEntry,
+    pub warnings: Vec<LineupWarningEnum>,
+}
+
+/// Parses a single code, ignoring case.
+pub fn parse_code(token: &str) -> Result<CodeEntry, String> {
+    let labels = current_labels();
+    let invalid = || labels.invalid_code.replace("{}", token);
+    let code = token.to_uppercase();
+    let type_start = code
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(code.len());
+    let (numbers, rest) = code.split_at(type_start);
+    let numbers = parse_numbers(numbers).ok_or_else(invalid)?;
+    let (event_type, awarded_to, mut rest) = split_event_type(rest).ok_or_else(invalid)?;
+    let mut entry = CodeEntry {
+        event_type,
+        numbers,
+        eval: None,
+        replacement: None,
+        combination: None,
+        touched: false,
+        awarded_to,
+    };
+    match event_type {
+        EventTypeEnum::A => {
+            let end = rest
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len());
+            let (combination, remaining) = rest.split_at(end);
+            if !combination.is_empty() {
+                entry.combination = Some(combination.to_string());
+            }
+            rest = remaining;
+        }
+        EventTypeEnum::B => {
+            if let Some(remaining) = rest.strip_prefix('T') {
+                entry.touched = true;
+                rest = remaining;
+            }
+        }
+        EventTypeEnum::R => {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let (replacement, remaining) = rest.split_at(end);
+            entry.replacement = Some(replacement.parse().map_err(|_| invalid())?);
+            rest = remaining;
+        }
+        _ => {}
+    }
+    if event_type.requires_evaluation() {
+        let eval = rest
+            .get(..1)
+            .and_then(|e| EvalEnum::from_str(e).ok())
+            .ok_or_else(|| labels.code_missing_evaluation.replace("{}", token))?;
+        if !event_type.available_evals().contains(&eval) {
+            return Err(labels.code_evaluation_not_available.replace("{}", token));
+        }
+        entry.eval = Some(eval);
+        rest = &rest[1..];
+    }
+    if !rest.is_empty() {
+        return Err(invalid());
+    }
+    let players = match event_type {
+        // the server is inferred when left out
+        EventTypeEnum::S => 0..=1,
+        EventTypeEnum::B => 1..=3,
+        e if e.requires_player() => 1..=1,
+        _ => 0..=0,
+    };
+    if entry.numbers.is_empty() && !players.contains(&0) {
+        return Err(labels.code_missing_player.replace("{}", token));
+    }
+    if !players.contains(&entry.numbers.len()) {
+        return Err(invalid());
+    }
+    Ok(entry)
+}
+
+/// Parses a line of codes separated by spaces.
+pub fn parse_code_line(line: &str) -> Result<Vec<CodeEntry>, CodeLineError> {
+    line.split_whitespace()
+        .enumerate()
+        .map(|(token, code)| parse_code(code).map_err(|message| CodeLineError { token, message }))
+        .collect()
+}
+
+/// Turns a code line into events, adding each one to a copy of the snapshot so that the
+/// following codes are checked against the lineup and the options it leaves.
+///
+/// Jersey numbers are looked up in `players`, the team players with the numbers of the match
+/// season; `team` gives the attack combinations.
+///
+/// Events breaking the tracked lineup come with their warnings, to be confirmed or fixed
+/// before they are added; the following codes are read as if they were accepted. The events
+/// are a millisecond apart, after the last one of the snapshot, so that rally timings and
+/// video clips keep their order.
+pub fn resolve_code_line(
+    line: &str,
+    team: &TeamEntry,
+    players: &[PlayerEntry],
+    snapshot: &Snapshot,
+    available_options: &[EventTypeEnum],
+    set_number: u8,
+) -> Result<Vec<CodeLineEvent>, CodeLineError> {
+    let labels = current_labels();
+    let codes = parse_code_line(line)?;
+    let mut snapshot = snapshot.clone();
+    let mut options = available_options.to_vec();
+    let mut events = vec![];
+    let now = Utc::now();
+    let start = snapshot
+        .last_event
+        .as_ref()
+        .map_or(now, |e| now.max(e.timestamp + Duration::milliseconds(1)));
+    for (token, code) in codes.iter().enumerate() {
+        let error = |message: String| CodeLineError { token, message };
+        if snapshot.get_set_winner(set_number).is_some() {
+            return Err(error(labels.set_over.to_string()));
+        }
+        let timestamp = start + Duration::milliseconds(token as i64);
+        let event = to_event(code, team, players, &snapshot, &options, timestamp).map_err(error)?;
+        let attack_zone = event
+            .combination
+            .as_ref()
+            .and_then(|c| team.find_combination(c))
+            .map(|c| c.zone);
+        let warnings = snapshot.current_lineup.check_event(&event, attack_zone);
+        options = snapshot
+            .add_event(&event, &options)
+            .map_err(|_| error(labels.could_not_add_event.to_string()))?;
+        events.push(CodeLineEvent { event, warnings });
+    }
+    Ok(events)
+}
+
+fn parse_numbers(numbers: &str) -> Option<Vec<u8>> {
+    if numbers.is_empty() {
+        return Some(vec![]);
+    }
+    numbers.split('.').map(|n| n.parse().ok()).collect()
+}
+
+fn split_event_type(code: &str) -> Option<(EventTypeEnum, Option<TeamSideEnum>, &str)> {
+    if let Some((prefix, event_type, side)) = TWO_LETTER_CODES
+        .iter()
+        .find(|(prefix, _, _)| code.starts_with(prefix))
+    {
+        return Some((*event_type, *side, &code[prefix.len()..]));
+    }
+    let event_type = code
+        .get(..1)
+        .and_then(|t| EventTypeEnum::from_str(t).ok())?;
+    Some((event_type, None, &code[1..]))
+}
+
+fn to_event(
+    code: &CodeEntry,
+    team: &TeamEntry,
+    season_players: &[PlayerEntry],
+    snapshot: &Snapshot,
+    available_options: &[EventTypeEnum],
+    timestamp: DateTime<Utc>,
+) -> Result<EventEntry, String> {
+    let labels = current_labels();
+    let lineup = &snapshot.current_lineup;
+    // score adjustments are always available, they follow the official scoresheet
+    if code.event_type != EventTypeEnum::SA && !available_options.contains(&code.event_type) {
+        return Err(labels
+            .event_is_not_available
+            .replace("{}", &code.event_type.to_string()));
+    }
+    let find_active = |number: u8| {
+        season_players
+            .iter()
+            .find(|p| !p.deleted && p.number == number)
+    };
+    let mut players: Vec<Uuid> = vec![];
+    let mut target_player = None;
+    if code.event_type == EventTypeEnum::R {
+        let number = code.numbers.first().copied().unwrap_or_default();
+        let replaceable: Vec<Uuid> = lineup
+            .get_replaceable_lineup_choices()
+            .into_iter()
+            .filter_map(|(_, (_, id))| id)
+            .collect();
+        let replaced = find_active(number)
+            .map(|p| p.id)
+            .filter(|id| replaceable.contains(id))
+            .ok_or_else(|| {
+                labels
+                    .player_cannot_be_replaced
+                    .replace("{}", &number.to_string())
+            })?;
+        let number = code.replacement.unwrap_or_default();
+        let replacement = lineup
+            .get_available_replacements(season_players, replaced)
+            .into_iter()
+            .map(|(_, p)| p)
+            .find(|p: &&PlayerEntry| p.number == number)
+            .ok_or_else(|| {
+                labels
+                    .invalid_replacement
+                    .replace("{}", &number.to_string())
+            })?;
+        players.push(replaced);
+        target_player = Some(replacement.id);
+    } else {
+        let on_court: Vec<Uuid> = lineup
+            .get_lineup_choices()
+            .into_iter()
+            .filter_map(|(_, (_, id))| id)
+            .collect();
+        for number in code.numbers.iter() {
+            let player = find_active(*number)
+                .map(|p| p.id)
+                .filter(|id| on_court.contains(id))
+                .ok_or_else(|| {
+                    labels
+                        .player_not_on_court
+                        .replace("{}", &number.to_string())
+                })?;
+            if (lineup.get_current_libero() == player && !code.event_type.is_allowed_for_libero())
+                || players.contains(&player)
+            {
+                return Err(labels
+                    .player_not_allowed_for_event
+                    .replace("{}", &number.to_string()));
+            }
+            players.push(player);
+        }
+    }
+    let mut player = players.first().copied();
+    let mut serve_type = None;
+    if code.event_type == EventTypeEnum::S {
+        // player is inferred when serving
+        player = player.or(lineup.get_serving_player());
+        serve_type = player
+            .and_then(|p| team.find_player(p))
+            .and_then(|p| p.serve_type);
+    }
+    let combination = code
+        .combination
+        .as_ref()
+        .map(|c| {
+            team.find_combination(c)
+                .map(|found| found.code.clone())
+                .ok_or_else(|| labels.unknown_attack_combination.replace("{}", c))
+        })
+        .transpose()?;
+    Ok(EventEntry {
+        timestamp,
+        event_type: code.event_type,
+        player,
+        eval: code.eval,
+        target_player,
+        second_blocker: players.get(1).copied(),
+        third_blocker: players.get(2).copied(),
+        touched: code.touched,
+        combination,
+        serve_type,
+        notes: None,
+        tags: vec![],
+        awarded_to: code.awarded_to,
+    })
+}
@@ -11,6 +11,16 @@ pub trait FriendlyName {
     fn friendly_name(&self, labels: &Labels) -> &'static str;
 }
 
+/// Lets a select offer an empty choice for optional values.
+impl<T: FriendlyName> FriendlyName for Option<T> {
+    fn friendly_name(&self, labels: &Labels) -> &'static str {
+        match self {
+            Some(value) => value.friendly_name(labels),
+            None => labels.not_set,
+        }
+    }
+}
+
 /// Represents the two possible phases of play in volleyball.
 ///
 /// In volleyball, the game alternates between two fundamental phases:
@@ -92,6 +102,20 @@ impl fmt::Display for TeamSideEnum {
     }
 }
 
+impl FriendlyName for TeamSideEnum {
+    fn friendly_name(&self, labels: &Labels) -> &'static str {
+        use TeamSideEnum::*;
+        match self {
+            Us => labels.us,
+            Them => labels.them,
+        }
+    }
+}
+
+impl TeamSideEnum {
+    pub const ALL: [TeamSideEnum; 2] = [TeamSideEnum::Us, TeamSideEnum::Them];
+}
+
 impl FromStr for TeamSideEnum {
     type Err = AppError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -148,6 +172,17 @@ impl FromStr for TeamSideEnum {
 ///
 /// - **CS**: Change Setter
 ///   A setter change performed by either team.
+///
+/// - **E**: Set
+///   The setter touch that builds our attack; its evaluation describes
+///   the options left to the attacker.
+///
+/// - **X**: Free Ball
+///   A ball we send over the net without attacking it.
+///
+/// - **SA**: Score Adjustment
+///   A point awarded outside of the scouted rallies (missed rally, sanction,
+///   challenge) to align the score with the official scoresheet.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum EventTypeEnum {
@@ -162,6 +197,9 @@ pub enum EventTypeEnum {
     R,
     CL,
     CS,
+    E,
+    X,
+    SA,
 }
 
 impl fmt::Display for EventTypeEnum {
@@ -179,6 +217,9 @@ impl fmt::Display for EventTypeEnum {
             R => "R",
             CL => "CL",
             CS => "CS",
+            E => "E",
+            X => "X",
+            SA => "SA",
         };
         write!(f, "{}", label)
     }
@@ -187,12 +228,24 @@ impl fmt::Display for EventTypeEnum {
 impl EventTypeEnum {
     pub fn requires_evaluation(&self) -> bool {
         use EventTypeEnum::*;
-        matches!(self, S | P | A | D | B)
+        matches!(self, S | P | A | D | B | E | X)
+    }
+
+    /// Whether the event is a touch of the ball by one of our players.
+    pub fn is_contact(&self) -> bool {
+        use EventTypeEnum::*;
+        matches!(self, S | P | A | D | B | E | X)
     }
 
     pub fn requires_player(&self) -> bool {
         use EventTypeEnum::*;
-        matches!(self, A | B | P | F | D | R | S | CS)
+        matches!(self, A | B | P | F | D | R | S | CS | E | X)
+    }
+
+    /// Whether the libero can be the player of the event.
+    pub fn is_allowed_for_libero(&self) -> bool {
+        use EventTypeEnum::*;
+        !matches!(self, B | CS)
     }
 
     pub fn available_evals(&self) -> Vec<EvalEnum> {
@@ -201,6 +254,8 @@ impl EventTypeEnum {
         match self {
             S | A | B => vec![Perfect, Positive, Over, Negative, Error],
             D | P => vec![Perfect, Positive, Exclamative, Over, Negative, Error],
+            E => vec![Perfect, Positive, Negative, Error],
+            X => vec![Positive, Negative, Error],
             _ => vec![],
         }
     }
@@ -214,9 +269,12 @@ impl EventTypeEnum {
         use EvalEnum::*;
         use EventTypeEnum::*;
         match (&self, eval) {
-            (A, Some(Error)) | (S, Some(Error)) | (B, Some(Over)) | (F, _) => {
-                Some(ErrorTypeEnum::Unforced)
-            }
+            (A, Some(Error))
+            | (S, Some(Error))
+            | (B, Some(Over))
+            | (E, Some(Error))
+            | (X, Some(Error))
+            | (F, _) => Some(ErrorTypeEnum::Unforced),
             (A, Some(Over)) | (B, Some(Error)) | (P, Some(Error)) | (D, Some(Error)) => {
                 Some(ErrorTypeEnum::Forced)
             }
@@ -240,6 +298,9 @@ impl FriendlyName for EventTypeEnum {
             R => labels.substitution,
             CL => labels.change_libero,
             CS => labels.change_setter,
+            E => labels.set_touch,
+            X => labels.free_ball,
+            SA => labels.score_adjustment,
         }
     }
 }
@@ -260,6 +321,9 @@ impl FromStr for EventTypeEnum {
             "R" => Ok(R),
             "CL" => Ok(CL),
             "CS" => Ok(CS),
+            "E" => Ok(E),
+            "X" => Ok(X),
+            "SA" => Ok(SA),
             _ => Err(AppError::IO(IOError::Msg(format!(
                 "invalid event type: {}",
                 s
@@ -418,6 +482,12 @@ impl EvalEnum {
             (Positive, S) => Some(labels.opponent_with_limited_attack_options.to_string()),
             (Negative, S) => Some(labels.opponent_with_full_attack_options.to_string()),
             (Over, S) => Some(labels.ball_goes_straight_back_to_our_court.to_string()),
+
+            (Perfect, E) => Some(labels.attacker_facing_single_block.to_string()),
+            (Negative, E) => Some(labels.limited_attack_options.to_string()),
+
+            (Positive, X) => Some(labels.opponent_with_limited_attack_options.to_string()),
+            (Negative, X) => Some(labels.opponent_with_full_attack_options.to_string()),
             _ => None,
         }
     }
@@ -458,6 +528,15 @@ impl EvalEnum {
             (Error, S) => labels.error.to_string(),
             (Over, S) => labels.overpass.to_string(),
 
+            (Perfect, E) => labels.perfect.to_string(),
+            (Positive, E) => labels.positive.to_string(),
+            (Negative, E) => labels.negative.to_string(),
+            (Error, E) => labels.error.to_string(),
+
+            (Positive, X) => labels.positive.to_string(),
+            (Negative, X) => labels.negative.to_string(),
+            (Error, X) => labels.error.to_string(),
+
             _ => event.to_string(),
         }
     }
@@ -507,6 +586,37 @@ impl fmt::Display for ZoneEnum {
     }
 }
 
+impl FriendlyName for ZoneEnum {
+    fn friendly_name(&self, _: &Labels) -> &'static str {
+        use ZoneEnum::*;
+        match self {
+            One => "1",
+            Two => "2",
+            Three => "3",
+            Four => "4",
+            Five => "5",
+            Six => "6",
+            Seven => "7",
+            Eight => "8",
+            Nine => "9",
+        }
+    }
+}
+
+impl ZoneEnum {
+    pub const ALL: [ZoneEnum; 9] = [
+        ZoneEnum::One,
+        ZoneEnum::Two,
+        ZoneEnum::Three,
+        ZoneEnum::Four,
+        ZoneEnum::Five,
+        ZoneEnum::Six,
+        ZoneEnum::Seven,
+        ZoneEnum::Eight,
+        ZoneEnum::Nine,
+    ];
+}
+
 impl FromStr for ZoneEnum {
     type Err = AppError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -635,6 +745,20 @@ impl fmt::Display for RotationEnum {
     }
 }
 
+impl FriendlyName for RotationEnum {
+    fn friendly_name(&self, _: &Labels) -> &'static str {
+        use RotationEnum::*;
+        match self {
+            One => "1",
+            Two => "2",
+            Three => "3",
+            Four => "4",
+            Five => "5",
+            Six => "6",
+        }
+    }
+}
+
 impl FromStr for RotationEnum {
     type Err = AppError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -820,10 +944,60 @@ pub enum ScreenActionEnum {
     ScrollDown,
     Reset,
     Undo,
+    Redo,
+    LiveStats,
+    Sort,
+    HeadToHead,
+    AttackCombinations,
+    Rallies,
+    AttachVideo,
+    SyncVideo,
+    ExportClips,
+    Notes,
+    MidSetStart,
+    FixLineup,
+    EditSetup,
+    LineupTemplates,
+    SaveLineupTemplate,
+    CodeLine,
+    Serve,
+    Reception,
+    Attack,
+    Defense,
+    Block,
+    SetTouch,
+    FreeBall,
+    Fault,
+    Substitution,
+    OpponentEvent,
+    LineupChange,
+    ScoreAdjustment,
+    OpponentScore,
+    OpponentError,
+    ChangeLibero,
+    ChangeSetter,
+    PointToUs,
+    PointToThem,
+    EvalPerfect,
+    EvalPositive,
+    EvalExclamative,
+    EvalOver,
+    EvalError,
+    EvalNegative,
+    EventOption,
+    PlayerSlot1,
+    PlayerSlot2,
+    PlayerSlot3,
+    PlayerSlot4,
+    PlayerSlot5,
+    PlayerSlot6,
+    PlayerSlot7,
+    PlayerSlot8,
+    PlayerSlot9,
 }
 
 impl ScreenActionEnum {
-    pub const ALL: [ScreenActionEnum; 29] = [
+    pub const ALL: [ScreenActionEnum; 79] = [
         ScreenActionEnum::Back,
         ScreenActionEnum::Confirm,
         ScreenActionEnum::Next,
@@ -853,8 +1027,145 @@ impl ScreenActionEnum {
         ScreenActionEnum::ScrollDown,
         ScreenActionEnum::Reset,
         ScreenActionEnum::Undo,
+        ScreenActionEnum::Redo,
+        ScreenActionEnum::LiveStats,
+        ScreenActionEnum::Sort,
+        ScreenActionEnum::HeadToHead,
+        ScreenActionEnum::AttackCombinations,
+        ScreenActionEnum::Rallies,
+        ScreenActionEnum::AttachVideo,
+        ScreenActionEnum::SyncVideo,
+        ScreenActionEnum::ExportClips,
+        ScreenActionEnum::Notes,
+        ScreenActionEnum::MidSetStart,
+        ScreenActionEnum::FixLineup,
+        ScreenActionEnum::EditSetup,
+        ScreenActionEnum::LineupTemplates,
+        ScreenActionEnum::SaveLineupTemplate,
+        ScreenActionEnum::CodeLine,
+        ScreenActionEnum::Serve,
+        ScreenActionEnum::Reception,
+        ScreenActionEnum::Attack,
+        ScreenActionEnum::Defense,
+        ScreenActionEnum::Block,
+        ScreenActionEnum::SetTouch,
+        ScreenActionEnum::FreeBall,
+        ScreenActionEnum::Fault,
+        ScreenActionEnum::Substitution,
+        ScreenActionEnum::OpponentEvent,
+        ScreenActionEnum::LineupChange,
+        ScreenActionEnum::ScoreAdjustment,
+        ScreenActionEnum::OpponentScore,
+        ScreenActionEnum::OpponentError,
+        ScreenActionEnum::ChangeLibero,
+        ScreenActionEnum::ChangeSetter,
+        ScreenActionEnum::PointToUs,
+        ScreenActionEnum::PointToThem,
+        ScreenActionEnum::EvalPerfect,
+        ScreenActionEnum::EvalPositive,
+        ScreenActionEnum::EvalExclamative,
+        ScreenActionEnum::EvalOver,
+        ScreenActionEnum::EvalError,
+        ScreenActionEnum::EvalNegative,
+        ScreenActionEnum::EventOption,
+        ScreenActionEnum::PlayerSlot1,
+        ScreenActionEnum::PlayerSlot2,
+        ScreenActionEnum::PlayerSlot3,
+        ScreenActionEnum::PlayerSlot4,
+        ScreenActionEnum::PlayerSlot5,
+        ScreenActionEnum::PlayerSlot6,
+        ScreenActionEnum::PlayerSlot7,
+        ScreenActionEnum::PlayerSlot8,
+        ScreenActionEnum::PlayerSlot9,
     ];
 
+    /// Keys choosing the event while scouting: the last three are followed by a second key,
+    /// from `OPPONENT_KEYS`, `LINEUP_CHANGE_KEYS` and `SCORE_ADJUSTMENT_KEYS`.
+    pub const EVENT_KEYS: [ScreenActionEnum; 12] = [
+        ScreenActionEnum::Serve,
+        ScreenActionEnum::Reception,
+        ScreenActionEnum::Attack,
+        ScreenActionEnum::Defense,
+        ScreenActionEnum::Block,
+        ScreenActionEnum::SetTouch,
+        ScreenActionEnum::FreeBall,
+        ScreenActionEnum::Fault,
+        ScreenActionEnum::Substitution,
+        ScreenActionEnum::OpponentEvent,
+        ScreenActionEnum::LineupChange,
+        ScreenActionEnum::ScoreAdjustment,
+    ];
+
+    pub const OPPONENT_KEYS: [ScreenActionEnum; 2] = [
+        ScreenActionEnum::OpponentScore,
+        ScreenActionEnum::OpponentError,
+    ];
+
+    pub const LINEUP_CHANGE_KEYS: [ScreenActionEnum; 2] = [
+        ScreenActionEnum::ChangeLibero,
+        ScreenActionEnum::ChangeSetter,
+    ];
+
+    pub const SCORE_ADJUSTMENT_KEYS: [ScreenActionEnum; 2] =
+        [ScreenActionEnum::PointToUs, ScreenActionEnum::PointToThem];
+
+    /// Keys typed after the player, while the event is being evaluated.
+    pub const EVAL_KEYS: [ScreenActionEnum; 7] = [
+        ScreenActionEnum::EvalPerfect,
+        ScreenActionEnum::EvalPositive,
+        ScreenActionEnum::EvalExclamative,
+        ScreenActionEnum::EvalOver,
+        ScreenActionEnum::EvalError,
+        ScreenActionEnum::EvalNegative,
+        ScreenActionEnum::EventOption,
+    ];
+
+    /// Keys choosing a player by their place in the list shown while scouting.
+    pub const PLAYER_SLOTS: [ScreenActionEnum; 9] = [
+        ScreenActionEnum::PlayerSlot1,
+        ScreenActionEnum::PlayerSlot2,
+        ScreenActionEnum::PlayerSlot3,
+        ScreenActionEnum::PlayerSlot4,
+        ScreenActionEnum::PlayerSlot5,
+        ScreenActionEnum::PlayerSlot6,
+        ScreenActionEnum::PlayerSlot7,
+        ScreenActionEnum::PlayerSlot8,
+        ScreenActionEnum::PlayerSlot9,
+    ];
+
+    /// Whether the action is one of the keys typed to scout events.
+    pub fn is_scouting(&self) -> bool {
+        Self::EVENT_KEYS
+            .iter()
+            .chain(Self::OPPONENT_KEYS.iter())
+            .chain(Self::LINEUP_CHANGE_KEYS.iter())
+            .chain(Self::SCORE_ADJUSTMENT_KEYS.iter())
+            .chain(Self::EVAL_KEYS.iter())
+            .chain(Self::PLAYER_SLOTS.iter())
+            .any(|a| a == self)
+    }
+
+    pub fn eval(&self) -> Option<EvalEnum> {
+        use ScreenActionEnum::*;
+        match self {
+            EvalPerfect => Some(EvalEnum::Perfect),
+            EvalPositive => Some(EvalEnum::Positive),
+            EvalExclamative => Some(EvalEnum::Exclamative),
+            EvalOver => Some(EvalEnum::Over),
+            EvalError => Some(EvalEnum::Error),
+            EvalNegative => Some(EvalEnum::Negative),
+            _ => None,
+        }
+    }
+
+    /// The 1-based place of the player chosen by the action.
+    pub fn player_slot(&self) -> Option<u8> {
+        Self::PLAYER_SLOTS
+            .iter()
+            .position(|a| a == self)
+            .map(|i| i as u8 + 1)
+    }
+
     pub fn with_desc(self) -> (ScreenActionEnum, String) {
         use ScreenActionEnum::*;
         match self {
@@ -893,6 +1204,98 @@ impl ScreenActionEnum {
             Delete => (Delete, current_labels().delete.to_string()),
             Reset => (Reset, current_labels().reset.to_string()),
             Undo => (Undo, current_labels().undo.to_string()),
+            Redo => (Redo, current_labels().redo.to_string()),
+            LiveStats => (LiveStats, current_labels().live_stats.to_string()),
+            Sort => (Sort, current_labels().sort.to_string()),
+            HeadToHead => (HeadToHead, current_labels().head_to_head.to_string()),
+            AttackCombinations => (
+                AttackCombinations,
+                current_labels().attack_combinations.to_string(),
+            ),
+            Rallies => (Rallies, current_labels().rally_browser.to_string()),
+            AttachVideo => (AttachVideo, current_labels().attach_video.to_string()),
+            SyncVideo => (SyncVideo, current_labels().sync_video.to_string()),
+            ExportClips => (ExportClips, current_labels().export_clips.to_string()),
+            Notes => (Notes, current_labels().notes.to_string()),
+            MidSetStart => (MidSetStart, current_labels().mid_set_start.to_string()),
+            FixLineup => (FixLineup, current_labels().fix_lineup.to_string()),
+            EditSetup => (EditSetup, current_labels().edit_set_setup.to_string()),
+            LineupTemplates => (
+                LineupTemplates,
+                current_labels().lineup_templates.to_string(),
+            ),
+            SaveLineupTemplate => (
+                SaveLineupTemplate,
+                current_labels().save_lineup_template.to_string(),
+            ),
+            CodeLine => (CodeLine, current_labels().code_line.to_string()),
+            Serve => (Serve, current_labels().serve.to_string()),
+            Reception => (Reception, current_labels().reception.to_string()),
+            Attack => (Attack, current_labels().attack.to_string()),
+            Defense => (Defense, current_labels().defense.to_string()),
+            Block => (Block, current_labels().block.to_string()),
+            SetTouch => (SetTouch, current_labels().set_touch.to_string()),
+            FreeBall => (FreeBall, current_labels().free_ball.to_string()),
+            Fault => (Fault, current_labels().fault.to_string()),
+            Substitution => (Substitution, current_labels().substitution.to_string()),
+            OpponentEvent => (OpponentEvent, current_labels().opponent_event.to_string()),
+            LineupChange => (LineupChange, current_labels().lineup_change.to_string()),
+            ScoreAdjustment => (
+                ScoreAdjustment,
+                current_labels().score_adjustment.to_string(),
+            ),
+            OpponentScore => (OpponentScore, current_labels().opponent_score.to_string()),
+            OpponentError => (OpponentError, current_labels().opponent_error.to_string()),
+            ChangeLibero => (ChangeLibero, current_labels().change_libero.to_string()),
+            ChangeSetter => (ChangeSetter, current_labels().change_setter.to_string()),
+            PointToUs => (PointToUs, current_labels().point_to_us.to_string()),
+            PointToThem => (PointToThem, current_labels().point_to_them.to_string()),
+            EvalPerfect => (
+                EvalPerfect,
+                current_labels()
+                    .eval_key
+                    .replace("{}", &EvalEnum::Perfect.to_string()),
+            ),
+            EvalPositive => (
+                EvalPositive,
+                current_labels()
+                    .eval_key
+                    .replace("{}", &EvalEnum::Positive.to_string()),
+            ),
+            EvalExclamative => (
+                EvalExclamative,
+                current_labels()
+                    .eval_key
+                    .replace("{}", &EvalEnum::Exclamative.to_string()),
+            ),
+            EvalOver => (
+                EvalOver,
+                current_labels()
+                    .eval_key
+                    .replace("{}", &EvalEnum::Over.to_string()),
+            ),
+            EvalError => (
+                EvalError,
+                current_labels()
+                    .eval_key
+                    .replace("{}", &EvalEnum::Error.to_string()),
+            ),
+            EvalNegative => (
+                EvalNegative,
+                current_labels()
+                    .eval_key
+                    .replace("{}", &EvalEnum::Negative.to_string()),
+            ),
+            EventOption => (EventOption, current_labels().event_option.to_string()),
+            PlayerSlot1 => (PlayerSlot1, current_labels().player_slot.replace("{}", "1")),
+            PlayerSlot2 => (PlayerSlot2, current_labels().player_slot.replace("{}", "2")),
+            PlayerSlot3 => (PlayerSlot3, current_labels().player_slot.replace("{}", "3")),
+            PlayerSlot4 => (PlayerSlot4, current_labels().player_slot.replace("{}", "4")),
+            PlayerSlot5 => (PlayerSlot5, current_labels().player_slot.replace("{}", "5")),
+            PlayerSlot6 => (PlayerSlot6, current_labels().player_slot.replace("{}", "6")),
+            PlayerSlot7 => (PlayerSlot7, current_labels().player_slot.replace("{}", "7")),
+            PlayerSlot8 => (PlayerSlot8, current_labels().player_slot.replace("{}", "8")),
+            PlayerSlot9 => (PlayerSlot9, current_labels().player_slot.replace("{}", "9")),
         }
     }
 }
@@ -929,6 +1332,56 @@ impl fmt::Display for ScreenActionEnum {
             Delete => "delete",
             Reset => "reset",
             Undo => "undo",
+            Redo => "redo",
+            LiveStats => "live-stats",
+            Sort => "sort",
+            HeadToHead => "head-to-head",
+            AttackCombinations => "attack-combinations",
+            Rallies => "rallies",
+            AttachVideo => "attach-video",
+            SyncVideo => "sync-video",
+            ExportClips => "export-clips",
+            Notes => "notes",
+            MidSetStart => "mid-set-start",
+            FixLineup => "fix-lineup",
+            EditSetup => "edit-setup",
+            LineupTemplates => "lineup-templates",
+            SaveLineupTemplate => "save-lineup-template",
+            CodeLine => "code-line",
+            Serve => "serve",
+            Reception => "reception",
+            Attack => "attack",
+            Defense => "defense",
+            Block => "block",
+            SetTouch => "set-touch",
+            FreeBall => "free-ball",
+            Fault => "fault",
+            Substitution => "substitution",
+            OpponentEvent => "opponent-event",
+            LineupChange => "lineup-change",
+            ScoreAdjustment => "score-adjustment",
+            OpponentScore => "opponent-score",
+            OpponentError => "opponent-error",
+            ChangeLibero => "change-libero",
+            ChangeSetter => "change-setter",
+            PointToUs => "point-to-us",
+            PointToThem => "point-to-them",
+            EvalPerfect => "eval-perfect",
+            EvalPositive => "eval-positive",
+            EvalExclamative => "eval-exclamative",
+            EvalOver => "eval-over",
+            EvalError => "eval-error",
+            EvalNegative => "eval-negative",
+            EventOption => "event-option",
+            PlayerSlot1 => "player-slot-1",
+            PlayerSlot2 => "player-slot-2",
+            PlayerSlot3 => "player-slot-3",
+            PlayerSlot4 => "player-slot-4",
+            PlayerSlot5 => "player-slot-5",
+            PlayerSlot6 => "player-slot-6",
+            PlayerSlot7 => "player-slot-7",
+            PlayerSlot8 => "player-slot-8",
+            PlayerSlot9 => "player-slot-9",
         };
         write!(f, "{}", label)
     }
@@ -1058,3 +1511,326 @@ impl FriendlyName for GenderEnum {
 impl GenderEnum {
     pub const ALL: [GenderEnum; 2] = [GenderEnum::Men, GenderEnum::Women];
 }
+
+/// The hand a player attacks and serves with.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum HandEnum {
+    Right,
+    Left,
+}
+
+impl fmt::Display for HandEnum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use HandEnum::*;
+        let label = match self {
+            Right => "right",
+            Left => "left",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+impl FriendlyName for HandEnum {
+    fn friendly_name(&self, labels: &Labels) -> &'static str {
+        use HandEnum::*;
+        match self {
+            Right => labels.right_handed,
+            Left => labels.left_handed,
+        }
+    }
+}
+
+impl HandEnum {
+    pub const ALL: [HandEnum; 2] = [HandEnum::Right, HandEnum::Left];
+}
+
+/// How many players took part in a block.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum BlockCompositionEnum {
+    Solo,
+    Double,
+    Triple,
+}
+
+impl fmt::Display for BlockCompositionEnum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use BlockCompositionEnum::*;
+        let label = match self {
+            Solo => "solo",
+            Double => "double",
+            Triple => "triple",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+impl FriendlyName for BlockCompositionEnum {
+    fn friendly_name(&self, labels: &Labels) -> &'static str {
+        use BlockCompositionEnum::*;
+        match self {
+            Solo => labels.solo_block,
+            Double => labels.double_block,
+            Triple => labels.triple_block,
+        }
+    }
+}
+
+impl BlockCompositionEnum {
+    pub const ALL: [BlockCompositionEnum; 3] = [
+        BlockCompositionEnum::Solo,
+        BlockCompositionEnum::Double,
+        BlockCompositionEnum::Triple,
+    ];
+
+    pub fn from_blockers(count: usize) -> Self {
+        match count {
+            0 | 1 => BlockCompositionEnum::Solo,
+            2 => BlockCompositionEnum::Double,
+            _ => BlockCompositionEnum::Triple,
+        }
+    }
+}
+
+/// How fast the ball is set for an attack combination.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum TempoEnum {
+    Quick,
+    Half,
+    High,
+}
+
+impl fmt::Display for TempoEnum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TempoEnum::*;
+        let label = match self {
+            Quick => "quick",
+            Half => "half",
+            High => "high",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+impl FriendlyName for TempoEnum {
+    fn friendly_name(&self, labels: &Labels) -> &'static str {
+        use TempoEnum::*;
+        match self {
+            Quick => labels.quick_tempo,
+            Half => labels.half_tempo,
+            High => labels.high_tempo,
+        }
+    }
+}
+
+impl TempoEnum {
+    pub const ALL: [TempoEnum; 3] = [TempoEnum::Quick, TempoEnum::Half, TempoEnum::High];
+}
+
+/// How a player serves.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum ServeTypeEnum {
+    Float,
+    JumpFloat,
+    JumpSpin,
+    Underhand,
+}
+
+impl fmt::Display for ServeTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ServeTypeEnum::*;
+        let label = match self {
+            Float => "float",
+            JumpFloat => "jump-float",
+            JumpSpin => "jump-spin",
+            Underhand => "underhand",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+impl FriendlyName for ServeTypeEnum {
+    fn friendly_name(&self, labels: &Labels) -> &'static str {
+        use ServeTypeEnum::*;
+        match self {
+            Float => labels.float_serve,
+            JumpFloat => labels.jump_float_serve,
+            JumpSpin => labels.jump_spin_serve,
+            Underhand => labels.underhand_serve,
+        }
+    }
+}
+
+impl ServeTypeEnum {
+    pub const ALL: [ServeTypeEnum; 4] = [
+        ServeTypeEnum::Float,
+        ServeTypeEnum::JumpFloat,
+        ServeTypeEnum::JumpSpin,
+        ServeTypeEnum::Underhand,
+    ];
+
+    /// The type following this one, wrapping around.
+    pub fn next(&self) -> ServeTypeEnum {
+        let index = Self::ALL.iter().position(|t| t == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Rallies grouped by the number of contacts of our team.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum RallyLengthEnum {
+    Short,
+    Medium,
+    Long,
+}
+
+impl fmt::Display for RallyLengthEnum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use RallyLengthEnum::*;
+        let label = match self {
+            Short => "0-2",
+            Medium => "3-5",
+            Long => "6+",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+impl FriendlyName for RallyLengthEnum {
+    fn friendly_name(&self, labels: &Labels) -> &'static str {
+        use RallyLengthEnum::*;
+        match self {
+            Short => labels.short_rally,
+            Medium => labels.medium_rally,
+            Long => labels.long_rally,
+        }
+    }
+}
+
+impl RallyLengthEnum {
+    pub const ALL: [RallyLengthEnum; 3] = [
+        RallyLengthEnum::Short,
+        RallyLengthEnum::Medium,
+        RallyLengthEnum::Long,
+    ];
+
+    pub fn from_contacts(contacts: usize) -> Self {
+        match contacts {
+            0..=2 => RallyLengthEnum::Short,
+            3..=5 => RallyLengthEnum::Medium,
+            _ => RallyLengthEnum::Long,
+        }
+    }
+}
+
+/// File formats a list of video clips can be exported to.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum ClipListFormatEnum {
+    Edl,
+    FfmpegConcat,
+    Xml,
+}
+
+impl fmt::Display for ClipListFormatEnum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ClipListFormatEnum::*;
+        let label = match self {
+            Edl => "edl",
+            FfmpegConcat => "ffmpeg-concat",
+            Xml => "xml",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+impl FriendlyName for ClipListFormatEnum {
+    fn friendly_name(&self, labels: &Labels) -> &'static str {
+        use ClipListFormatEnum::*;
+        match self {
+            Edl => labels.edl_format,
+            FfmpegConcat => labels.ffmpeg_concat_format,
+            Xml => labels.xml_timeline_format,
+        }
+    }
+}
+
+impl ClipListFormatEnum {
+    pub const ALL: [ClipListFormatEnum; 3] = [
+        ClipListFormatEnum::Edl,
+        ClipListFormatEnum::FfmpegConcat,
+        ClipListFormatEnum::Xml,
+    ];
+
+    pub fn extension(&self) -> &'static str {
+        use ClipListFormatEnum::*;
+        match self {
+            Edl => "edl",
+            FfmpegConcat => "ffconcat",
+            Xml => "xml",
+        }
+    }
+}
+
+/// What a note written while scouting refers to.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum NoteTargetEnum {
+    Event,
+    Set,
+    Match,
+}
+
+impl FriendlyName for NoteTargetEnum {
+    fn friendly_name(&self, labels: &Labels) -> &'static str {
+        use NoteTargetEnum::*;
+        match self {
+            Event => labels.last_event,
+            Set => labels.set_word,
+            Match => labels.match_word,
+        }
+    }
+}
+
+impl NoteTargetEnum {
+    pub const ALL: [NoteTargetEnum; 3] = [
+        NoteTargetEnum::Event,
+        NoteTargetEnum::Set,
+        NoteTargetEnum::Match,
+    ];
+}
+
+/// A rotation or libero rule a scouted event breaks with respect to the tracked lineup,
+/// usually a sign that the lineup differs from the one on court.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum LineupWarningEnum {
+    WrongServer,
+    BackRowAttack,
+    BackRowBlock,
+    LiberoServe,
+    LiberoAttack,
+}
+
+impl FriendlyName for LineupWarningEnum {
+    fn friendly_name(&self, labels: &Labels) -> &'static str {
+        use LineupWarningEnum::*;
+        match self {
+            WrongServer => labels.wrong_server,
+            BackRowAttack => labels.back_row_attack,
+            BackRowBlock => labels.back_row_block,
+            LiberoServe => labels.libero_serve,
+            LiberoAttack => labels.libero_attack,
+        }
+    }
+}
+
+impl LineupWarningEnum {
+    /// Tag added to the events accepted despite the warning.
+    pub fn tag(&self) -> &'static str {
+        use LineupWarningEnum::*;
+        match self {
+            WrongServer => "wrong-server",
+            BackRowAttack => "back-row-attack",
+            BackRowBlock => "back-row-block",
+            LiberoServe => "libero-serve",
+            LiberoAttack => "libero-attack",
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use {
     crate::shapes::enums::ScreenActionEnum,
-    crokey::{crossterm::event::KeyEvent, *},
+    crokey::{
+        crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+        *,
+    },
     serde::{Deserialize, Serialize},
     std::{
         collections::{hash_map, HashMap, HashSet},
@@ -43,7 +46,14 @@ impl ScreenKeyBindings {
         self.map.entry(ck).or_insert(action_enum);
     }
     pub fn get(&self, key: KeyCombination) -> Option<&ScreenActionEnum> {
-        self.map.get(&key)
+        self.map.get(&key).or_else(|| match key {
+            // symbols typed with shift on most layouts, like `#` or `+`, are bound without it
+            KeyCombination {
+                codes: OneToThree::One(KeyCode::Char(c)),
+                modifiers: KeyModifiers::SHIFT,
+            } if !c.is_alphabetic() => self.map.get(&char_key(c)),
+            _ => None,
+        })
     }
 }
 
@@ -94,10 +104,96 @@ impl Default for KeyBindings {
         bindings.set(ScreenActionEnum::ReportAnIssue, key!(i));
         bindings.set(ScreenActionEnum::Select, key!(enter));
         bindings.set(ScreenActionEnum::Reset, key!(r));
+        bindings.set(ScreenActionEnum::Sort, key!(o));
+        bindings.set(ScreenActionEnum::HeadToHead, key!(h));
+        bindings.set(ScreenActionEnum::AttackCombinations, key!(c));
+        bindings.set(ScreenActionEnum::Rallies, key!(r));
+        bindings.set(ScreenActionEnum::AttachVideo, key!(v));
+        bindings.set(ScreenActionEnum::SyncVideo, key!(y));
+        bindings.set(ScreenActionEnum::ExportClips, key!(x));
+        bindings.set(ScreenActionEnum::Notes, key!(n));
+        bindings.set(ScreenActionEnum::MidSetStart, key!(j));
+        bindings.set(ScreenActionEnum::FixLineup, key!(f));
+        bindings.set(ScreenActionEnum::EditSetup, key!(e));
+        bindings.set(ScreenActionEnum::LineupTemplates, key!(t));
+        bindings.set(ScreenActionEnum::SaveLineupTemplate, key!(s));
+        bindings.set(ScreenActionEnum::Undo, key!(ctrl - z));
+        bindings.set(ScreenActionEnum::Redo, key!(ctrl - y));
+        bindings.set(ScreenActionEnum::LiveStats, key!(tab));
+        bindings.set(ScreenActionEnum::CodeLine, key!(i));
+        bindings.set(ScreenActionEnum::Serve, key!(s));
+        bindings.set(ScreenActionEnum::Reception, key!(p));
+        bindings.set(ScreenActionEnum::Attack, key!(a));
+        bindings.set(ScreenActionEnum::Defense, key!(d));
+        bindings.set(ScreenActionEnum::Block, key!(b));
+        bindings.set(ScreenActionEnum::SetTouch, key!(e));
+        bindings.set(ScreenActionEnum::FreeBall, key!(x));
+        bindings.set(ScreenActionEnum::Fault, key!(f));
+        bindings.set(ScreenActionEnum::Substitution, key!(r));
+        bindings.set(ScreenActionEnum::OpponentEvent, key!(o));
+        bindings.set(ScreenActionEnum::LineupChange, key!(c));
+        bindings.set(ScreenActionEnum::ScoreAdjustment, key!(m));
+        bindings.set(ScreenActionEnum::OpponentScore, key!(s));
+        bindings.set(ScreenActionEnum::OpponentError, key!(e));
+        bindings.set(ScreenActionEnum::ChangeLibero, key!(l));
+        bindings.set(ScreenActionEnum::ChangeSetter, key!(s));
+        bindings.set(ScreenActionEnum::PointToUs, key!(u));
+        bindings.set(ScreenActionEnum::PointToThem, key!(t));
+        bindings.set(ScreenActionEnum::EvalPerfect, char_key('#'));
+        bindings.set(ScreenActionEnum::EvalPositive, char_key('+'));
+        bindings.set(ScreenActionEnum::EvalExclamative, char_key('!'));
+        bindings.set(ScreenActionEnum::EvalOver, char_key('/'));
+        bindings.set(ScreenActionEnum::EvalError, char_key('='));
+        bindings.set(ScreenActionEnum::EvalNegative, char_key('-'));
+        bindings.set(ScreenActionEnum::EventOption, key!(t));
+        for (slot, action) in ScreenActionEnum::PLAYER_SLOTS.iter().enumerate() {
+            bindings.set(*action, char_key(char::from(b'1' + slot as u8)));
+        }
         bindings
     }
 }
 
+fn char_key(c: char) -> KeyCombination {
+    KeyCombination::one_key(KeyCode::Char(c), KeyModifiers::NONE)
+}
+
+/// Groups of actions sharing the keyboard at some step of the scouting screen: a key can go
+/// to only one action of each group.
+fn scouting_key_groups() -> Vec<Vec<ScreenActionEnum>> {
+    use ScreenActionEnum::*;
+    let event_step = [Undo, Redo, LiveStats, CodeLine, Notes, Back, Quit];
+    let player_step = [Undo, Redo, LiveStats, Back, Quit];
+    vec![
+        vec![Confirm, FixLineup, Back, Quit],
+        vec![Next, Previous, Confirm, Back, Quit],
+        vec![Confirm, Back, Quit],
+        vec![Undo, LiveStats, Back, Quit],
+        [ScreenActionEnum::EVENT_KEYS.as_slice(), &event_step].concat(),
+        [ScreenActionEnum::OPPONENT_KEYS.as_slice(), &event_step].concat(),
+        [ScreenActionEnum::LINEUP_CHANGE_KEYS.as_slice(), &event_step].concat(),
+        [
+            ScreenActionEnum::SCORE_ADJUSTMENT_KEYS.as_slice(),
+            &event_step,
+        ]
+        .concat(),
+        [
+            ScreenActionEnum::EVAL_KEYS.as_slice(),
+            &ScreenActionEnum::PLAYER_SLOTS,
+            &player_step,
+        ]
+        .concat(),
+    ]
+}
+
+/// Whether the key types into the code of an attack combination, which is entered at the
+/// attack evaluation step: plain letters and digits cannot evaluate events.
+pub fn types_combination_code(ck: &KeyCombination) -> bool {
+    matches!(ck.codes, OneToThree::One(KeyCode::Char(c)) if c.is_ascii_alphanumeric())
+        && !ck
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+}
+
 impl KeyBindings {
     pub fn set<A: Into<ScreenActionEnum>>(&mut self, action: A, ck: KeyCombination) -> bool {
         self.default_bindings
@@ -134,6 +230,47 @@ impl KeyBindings {
         }
     }
 
+    /// Gives the default keys to the actions missing from the bindings, like the ones added
+    /// after the settings were saved.
+    pub fn with_missing_defaults(mut self) -> Self {
+        for (action, cks) in KeyBindings::default().default_bindings {
+            self.default_bindings.entry(action).or_insert(cks);
+        }
+        self
+    }
+
+    /// Actions that would share the key combination with the given one while scouting.
+    pub fn conflicts(
+        &self,
+        action: &ScreenActionEnum,
+        ck: &KeyCombination,
+    ) -> Vec<ScreenActionEnum> {
+        let mut conflicts: Vec<ScreenActionEnum> = vec![];
+        for group in scouting_key_groups()
+            .into_iter()
+            .filter(|g| g.contains(action))
+        {
+            for other in group {
+                if other != *action
+                    && !conflicts.contains(&other)
+                    && self.keybindings_for(&other).contains(ck)
+                {
+                    conflicts.push(other);
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Whether any key of the action is shared with another action while scouting, as it can
+    /// happen when the settings are edited by hand.
+    pub fn has_conflicts(&self, action: &ScreenActionEnum) -> bool {
+        self.keybindings_for(action).iter().any(|ck| {
+            !self.conflicts(action, ck).is_empty()
+                || (action.eval().is_some() && types_combination_code(ck))
+        })
+    }
+
     pub fn keybindings_for(&self, action: &ScreenActionEnum) -> HashSet<KeyCombination> {
         self.default_bindings
             .get(action)
@@ -204,3 +341,92 @@ fn test_deserialize_keybindings() {
         Some((key!(shift - tab), "previous".into()))
     );
 }
+
+#[test]
+fn test_scouting_key_conflicts() {
+    let mut kb = KeyBindings::default();
+    // the same key is used after different first keys
+    assert!(kb
+        .conflicts(&ScreenActionEnum::OpponentScore, &key!(s))
+        .is_empty());
+    assert!(ScreenActionEnum::ALL.iter().all(|a| !kb.has_conflicts(a)));
+    assert_eq!(
+        kb.conflicts(&ScreenActionEnum::Serve, &key!(n)),
+        vec![ScreenActionEnum::Notes]
+    );
+    assert_eq!(
+        kb.conflicts(&ScreenActionEnum::EvalNegative, &char_key('1')),
+        vec![ScreenActionEnum::PlayerSlot1]
+    );
+    // screen actions not available while evaluating are not in the way
+    assert!(kb
+        .conflicts(&ScreenActionEnum::EvalNegative, &key!(n))
+        .is_empty());
+    kb.set(ScreenActionEnum::Attack, key!(s));
+    assert!(kb.has_conflicts(&ScreenActionEnum::Serve));
+    // keys of the other steps of the scouting screen
+    assert_eq!(
+        kb.conflicts(&ScreenActionEnum::FixLineup, &key!(enter)),
+        vec![ScreenActionEnum::Confirm]
+    );
+    assert_eq!(
+        kb.conflicts(&ScreenActionEnum::Next, &key!(esc)),
+        vec![ScreenActionEnum::Back]
+    );
+    assert_eq!(
+        kb.conflicts(&ScreenActionEnum::Undo, &key!(tab)),
+        vec![ScreenActionEnum::LiveStats]
+    );
+    // letters and digits type the attack combination codes
+    assert!(types_combination_code(&key!(a)));
+    assert!(types_combination_code(&char_key('7')));
+    assert!(!types_combination_code(&key!(ctrl - a)));
+    assert!(!types_combination_code(&char_key('#')));
+    kb.set(ScreenActionEnum::EvalOver, key!(o));
+    assert!(kb.has_conflicts(&ScreenActionEnum::EvalOver));
+}
+
+#[test]
+fn test_missing_default_keybindings() {
+    let json = r#"{ "quit": ["q"], "serve": [] }"#;
+    let kb = serde_json::from_str::<KeyBindings>(json)
+        .unwrap()
+        .with_missing_defaults();
+    assert_eq!(
+        kb.keybindings_for(&ScreenActionEnum::Back),
+        [key!(esc)].into()
+    );
+    assert_eq!(
+        kb.keybindings_for(&ScreenActionEnum::Quit),
+        [key!(q)].into()
+    );
+    // removed keys are not given back
+    assert!(kb.keybindings_for(&ScreenActionEnum::Serve).is_empty());
+    assert_eq!(
+        kb.keybindings_for(&ScreenActionEnum::EvalPerfect),
+        [char_key('#')].into()
+    );
+    // symbols are saved and read back as they are
+    let saved = serde_json::to_string(&kb).unwrap();
+    let kb = serde_json::from_str::<KeyBindings>(&saved).unwrap();
+    for action in ScreenActionEnum::EVAL_KEYS {
+        assert_eq!(
+            kb.keybindings_for(&action),
+            KeyBindings::default().keybindings_for(&action)
+        );
+    }
+}
+
+#[test]
+fn test_shifted_symbol_keys() {
+    let kb = KeyBindings::default();
+    let slice = kb.slice(ScreenActionEnum::EVAL_KEYS.iter().collect());
+    let shifted = |c| KeyCombination::one_key(KeyCode::Char(c), KeyModifiers::SHIFT);
+    assert_eq!(
+        slice.get(shifted('#')),
+        Some(&ScreenActionEnum::EvalPerfect)
+    );
+    // shifted letters are different keys
+    assert_eq!(slice.get(shifted('T')), None);
+    assert_eq!(slice.get(key!(t)), Some(&ScreenActionEnum::EventOption));
+}
@@ -2,10 +2,11 @@ use crate::{
     constants::MAX_SUBSTITUTIONS,
     errors::{AppError, SnapshotError},
     shapes::{
-        enums::{EvalEnum, EventTypeEnum, PhaseEnum, RoleEnum},
+        enums::{
+            EvalEnum, EventTypeEnum, LineupWarningEnum, PhaseEnum, RoleEnum, TeamSideEnum, ZoneEnum,
+        },
         player::PlayerEntry,
         snapshot::EventEntry,
-        team::TeamEntry,
     },
 };
 use std::collections::HashSet;
@@ -93,7 +94,9 @@ impl Lineup {
             (S, Some(Error), _) => Some(SideOut),
             (B | A, Some(Perfect), SideOut) => Some(Break),
             (B | A, Some(Error | Over), Break) => Some(SideOut),
-            (D, Some(Error), Break) => Some(SideOut),
+            (D | E | X, Some(Error), Break) => Some(SideOut),
+            (SA, _, SideOut) if event.awarded_to == Some(TeamSideEnum::Us) => Some(Break),
+            (SA, _, Break) if event.awarded_to == Some(TeamSideEnum::Them) => Some(SideOut),
             _ => None,
         }
     }
@@ -161,6 +164,10 @@ impl Lineup {
         self.fallback_libero
     }
 
+    pub fn get_current_setter(&self) -> Uuid {
+        self.current_setter
+    }
+
     pub fn set_current_setter(&mut self, new_setter: &Uuid) -> Result<(), AppError> {
         if self.find_position(new_setter).is_some() {
             self.current_setter = *new_setter;
@@ -312,6 +319,55 @@ impl Lineup {
         self.get(index)
     }
 
+    fn is_libero(&self, player_id: &Uuid) -> bool {
+        self.current_libero == *player_id || self.fallback_libero == Some(*player_id)
+    }
+
+    /// Rotation and libero rules the event breaks with respect to this lineup.
+    ///
+    /// `attack_zone` is the zone of the attack combination, if known: back-row players
+    /// may attack, but only from behind the attack line. An attack with an unknown zone
+    /// is assumed to be at the net.
+    pub fn check_event(
+        &self,
+        event: &EventEntry,
+        attack_zone: Option<ZoneEnum>,
+    ) -> Vec<LineupWarningEnum> {
+        use LineupWarningEnum::*;
+        let mut warnings = vec![];
+        let at_net = matches!(
+            attack_zone,
+            None | Some(ZoneEnum::Two | ZoneEnum::Three | ZoneEnum::Four)
+        );
+        match (event.event_type, event.player) {
+            (EventTypeEnum::S, Some(server)) => {
+                if self.get_serving_player() != Some(server) {
+                    warnings.push(WrongServer);
+                }
+                if self.is_libero(&server) {
+                    warnings.push(LiberoServe);
+                }
+            }
+            (EventTypeEnum::A, Some(attacker)) => {
+                if self.is_libero(&attacker) {
+                    // the libero attacks from the back zone only, below the net height
+                    if at_net {
+                        warnings.push(LiberoAttack);
+                    }
+                } else if at_net && self.is_back_row_player(&attacker) {
+                    warnings.push(BackRowAttack);
+                }
+            }
+            (EventTypeEnum::B, _)
+                if event.blockers().iter().any(|b| self.is_back_row_player(b)) =>
+            {
+                warnings.push(BackRowBlock);
+            }
+            _ => {}
+        }
+        warnings
+    }
+
     pub fn get_serving_player(&self) -> Option<Uuid> {
         let serving_index = 0; // the player in position 1 (index 0) is always the server
         self.get(serving_index)
@@ -339,6 +395,10 @@ impl Lineup {
         phase != PhaseEnum::Break || (rotation != 1 && rotation != 4)
     }
 
+    pub fn get_on_court_players(&self) -> [Uuid; 6] {
+        self.players
+    }
+
     pub fn get_involved_players(&self) -> Vec<Uuid> {
         let mut set: HashSet<Uuid> = self.players.iter().cloned().collect();
         set.insert(self.current_libero);
@@ -529,9 +589,11 @@ impl Lineup {
             .collect()
     }
 
+    /// Players that can replace the given one, from `players` with the numbers and roles of
+    /// the match season.
     pub fn get_available_replacements<'a>(
         &self,
-        team: &'a TeamEntry,
+        players: &'a [PlayerEntry],
         replaced_id: Uuid,
     ) -> Vec<(u8, &'a PlayerEntry)> {
         // current lineup
@@ -557,7 +619,7 @@ impl Lineup {
                 // still open
                 let s = subs[0];
                 if s.replacement == replaced_id {
-                    if let Some(player) = team.players.iter().find(|p| p.id == s.replaced) {
+                    if let Some(player) = players.iter().find(|p| p.id == s.replaced) {
                         return vec![(1, player)];
                     }
                 }
@@ -578,10 +640,11 @@ impl Lineup {
             .iter()
             .flat_map(|s| vec![s.replaced, s.replacement])
             .collect();
-        team.players
+        players
             .iter()
             .filter(|p| {
-                !options.contains(&p.id)
+                !p.deleted
+                    && !options.contains(&p.id)
                     && !excluded.contains(&p.id)
                     && p.id != self.get_current_libero()
             })
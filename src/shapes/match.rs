@@ -2,6 +2,7 @@ use crate::{
     errors::AppError,
     shapes::{
         enums::{EventTypeEnum, TeamSideEnum},
+        player::PlayerEntry,
         set::SetEntry,
         snapshot::Snapshot,
         team::TeamEntry,
@@ -9,6 +10,7 @@ use crate::{
 };
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 #[derive(Debug)]
 pub struct MatchStatus {
@@ -23,17 +25,40 @@ pub struct MatchStatus {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MatchEntry {
     pub opponent: String,
+    #[serde(default)]
+    pub opponent_id: Option<Uuid>,
     pub date: DateTime<FixedOffset>,
+    /// The team season the match was played in; matches created before seasons were
+    /// introduced have none and use the current roster.
+    #[serde(default)]
+    pub season: Option<u16>,
     #[serde(skip_serializing, skip_deserializing)]
     pub id: String,
     #[serde(skip_serializing, skip_deserializing)]
     pub team: TeamEntry,
+    /// The team players with the numbers and roles of the match season, see
+    /// `TeamEntry::season_players`; `team` keeps the current ones.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub players: Vec<PlayerEntry>,
     pub home: bool,
     #[serde(skip_serializing, skip_deserializing)]
     pub sets: Vec<SetEntry>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl MatchEntry {
+    pub fn find_player(&self, player_id: Uuid) -> Option<&PlayerEntry> {
+        self.players.iter().find(|p| p.id == player_id)
+    }
+
+    /// Players on the roster of the match season.
+    pub fn active_players(&self) -> Vec<&PlayerEntry> {
+        self.players.iter().filter(|p| !p.deleted).collect()
+    }
+
     pub fn get_status(&self) -> Result<MatchStatus, AppError> {
         let mut us_wins = 0;
         let mut them_wins = 0;
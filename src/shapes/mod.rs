@@ -1,11 +1,17 @@
+pub mod code_line;
 pub mod enums;
 pub mod keybinding;
 pub mod lineup;
 pub mod r#match;
+pub mod opponent;
 pub mod player;
+pub mod rally;
 pub mod set;
 pub mod settings;
 pub mod snapshot;
 pub mod stats;
 pub mod symbol;
+pub mod tags;
 pub mod team;
+pub mod timing;
+pub mod video;
@@ -0,0 +1,50 @@
+use crate::shapes::{
+    enums::{GenderEnum, TeamClassificationEnum},
+    r#match::MatchEntry,
+};
+use serde::{Deserialize, Serialize};
+use std::iter::once;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct OpponentEntry {
+    pub id: Uuid,
+    pub name: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub classification: Option<TeamClassificationEnum>,
+    #[serde(default)]
+    pub gender: Option<GenderEnum>,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+impl OpponentEntry {
+    /// Normalizes a name so that "V. Rossi", "v rossi" and "V  Rossi" compare equal.
+    pub fn normalize(name: &str) -> String {
+        name.split(|c: char| c.is_whitespace() || c == '.')
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_lowercase())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Whether the given free-text name refers to this opponent, either by name or by alias.
+    pub fn matches(&self, name: &str) -> bool {
+        let name = Self::normalize(name);
+        !name.is_empty()
+            && once(&self.name)
+                .chain(self.aliases.iter())
+                .any(|candidate| Self::normalize(candidate) == name)
+    }
+
+    /// Whether the match was played against this opponent; matches created before the
+    /// registry existed are resolved by name.
+    pub fn played(&self, m: &MatchEntry) -> bool {
+        match m.opponent_id {
+            Some(id) => id == self.id,
+            None => self.matches(&m.opponent),
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use crate::shapes::enums::RoleEnum;
+use crate::shapes::enums::{HandEnum, RoleEnum, ServeTypeEnum};
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -10,6 +11,20 @@ pub struct PlayerEntry {
     pub number: u8,
     #[serde(default)]
     pub deleted: bool,
+    #[serde(default)]
+    pub birth_date: Option<NaiveDate>,
+    /// Height in centimeters.
+    #[serde(default)]
+    pub height: Option<u16>,
+    #[serde(default)]
+    pub dominant_hand: Option<HandEnum>,
+    #[serde(default)]
+    pub secondary_role: Option<RoleEnum>,
+    #[serde(default)]
+    pub captain: bool,
+    /// The serve type used when a serve is not given a different one while scouting.
+    #[serde(default)]
+    pub serve_type: Option<ServeTypeEnum>,
 }
 
 impl Default for PlayerEntry {
@@ -20,6 +35,12 @@ impl Default for PlayerEntry {
             role: None,
             number: 0,
             deleted: false,
+            birth_date: None,
+            height: None,
+            dominant_hand: None,
+            secondary_role: None,
+            captain: false,
+            serve_type: None,
         }
     }
 }
@@ -0,0 +1,58 @@
+use crate::shapes::{
+    enums::{PhaseEnum, RallyLengthEnum, TeamSideEnum},
+    snapshot::EventEntry,
+};
+use chrono::Duration;
+
+/// A rally of a set, from its first event to the one that assigned the point.
+///
+/// Substitutions and libero/setter changes happen between rallies and are not part of any.
+#[derive(Debug, Clone)]
+pub struct RallyEntry {
+    pub serving_team: TeamSideEnum,
+    pub rotation: u8,
+    pub events: Vec<EventEntry>,
+    /// `None` while the rally is still in progress.
+    pub winner: Option<TeamSideEnum>,
+    /// Score at the end of the rally (us, them).
+    pub score: (u8, u8),
+}
+
+impl RallyEntry {
+    pub fn new(serving_team: TeamSideEnum, rotation: u8) -> Self {
+        RallyEntry {
+            serving_team,
+            rotation,
+            events: vec![],
+            winner: None,
+            score: (0, 0),
+        }
+    }
+
+    pub fn phase(&self) -> PhaseEnum {
+        match self.serving_team {
+            TeamSideEnum::Us => PhaseEnum::Break,
+            TeamSideEnum::Them => PhaseEnum::SideOut,
+        }
+    }
+
+    /// Number of times our players touched the ball.
+    pub fn contacts(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|e| e.event_type.is_contact())
+            .count()
+    }
+
+    pub fn length(&self) -> RallyLengthEnum {
+        RallyLengthEnum::from_contacts(self.contacts())
+    }
+
+    /// Time between the first and the last event, as they were scouted.
+    pub fn duration(&self) -> Option<Duration> {
+        match (self.events.first(), self.events.last()) {
+            (Some(first), Some(last)) => Some(last.timestamp - first.timestamp),
+            _ => None,
+        }
+    }
+}
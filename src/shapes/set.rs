@@ -1,8 +1,11 @@
 use crate::{
-    errors::{AppError, MatchError},
+    constants::{DEFAULT_SET_TARGET_SCORE, TIE_BREAK_SET_TARGET_SCORE},
+    errors::{AppError, MatchError, SnapshotError},
     shapes::{
-        enums::{EventTypeEnum, PhaseEnum, TeamSideEnum},
+        enums::{EventTypeEnum, PhaseEnum, RotationEnum, TeamSideEnum},
         snapshot::{EventEntry, Snapshot},
+        stats::Stats,
+        video::VideoEntry,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -19,6 +22,58 @@ pub struct SetEntry {
     pub setter: Uuid,
     #[serde(skip_serializing, skip_deserializing)]
     pub events: Vec<EventEntry>,
+    #[serde(default)]
+    pub video: Option<VideoEntry>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Set when scouting joined a set already in progress.
+    #[serde(default)]
+    pub mid_set_start: Option<MidSetStartEntry>,
+}
+
+/// Where the scouting of a set joined late (or resumed after a crash) started from.
+///
+/// The rallies played before are not scouted: the snapshot starts from this score,
+/// with the initial lineup rotated until the setter is in `rotation`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct MidSetStartEntry {
+    pub score_us: u8,
+    pub score_them: u8,
+    pub rotation: RotationEnum,
+    pub serving_team: TeamSideEnum,
+}
+
+impl MidSetStartEntry {
+    /// Whether the set would still be in progress at this score.
+    pub fn is_valid(&self, set_number: u8) -> bool {
+        let target_score = if set_number == 5 {
+            TIE_BREAK_SET_TARGET_SCORE
+        } else {
+            DEFAULT_SET_TARGET_SCORE
+        };
+        let (us, them) = (self.score_us, self.score_them);
+        !(us >= target_score && us >= them + 2 || them >= target_score && them >= us + 2)
+    }
+
+    /// The initial positions rotated so that the setter is in the starting rotation.
+    pub fn positions(&self, initial_positions: [Uuid; 6], setter: &Uuid) -> [Uuid; 6] {
+        let mut positions = initial_positions;
+        if let Some(current) = positions.iter().position(|p| p == setter) {
+            // the lineup rotates clockwise, moving each player one index down
+            positions.rotate_left((current + 6 - self.rotation as usize) % 6);
+        }
+        positions
+    }
+}
+
+/// A recorded event rejected when replaying a set from its starting setup.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetupConflict {
+    pub event_index: usize,
+    pub event_type: EventTypeEnum,
+    pub reason: String,
 }
 
 impl SetEntry {
@@ -49,9 +104,84 @@ impl SetEntry {
                     fallback_libero,
                     setter: *s,
                     events: vec![],
+                    video: None,
+                    notes: None,
+                    tags: vec![],
+                    mid_set_start: None,
+                }),
+            }
+        }
+    }
+
+    /// The same set with a different starting setup, keeping everything recorded so far.
+    pub fn with_setup(
+        &self,
+        initial_positions: [Uuid; 6],
+        libero: Uuid,
+        fallback_libero: Option<Uuid>,
+        setter: Uuid,
+    ) -> Result<SetEntry, AppError> {
+        let mut set = SetEntry::new(
+            self.set_number,
+            self.serving_team,
+            initial_positions,
+            libero,
+            fallback_libero,
+            setter,
+        )?;
+        set.events = self.events.clone();
+        set.video = self.video.clone();
+        set.notes = self.notes.clone();
+        set.tags = self.tags.clone();
+        set.mid_set_start = self.mid_set_start;
+        Ok(set)
+    }
+
+    /// Replays the events from the starting setup, collecting the ones it rejects.
+    ///
+    /// The replay goes on after a conflict, so later conflicts might follow from earlier ones.
+    pub fn setup_conflicts(&self) -> Result<Vec<SetupConflict>, AppError> {
+        let mut snapshot = Snapshot::new(self)?;
+        let mut available_options: Vec<EventTypeEnum> = vec![];
+        let mut conflicts = vec![];
+        for (event_index, event) in self.events.iter().enumerate() {
+            match snapshot.add_event(event, &available_options) {
+                Ok(options) => available_options = options,
+                Err(e) => conflicts.push(SetupConflict {
+                    event_index,
+                    event_type: event.event_type,
+                    reason: match e {
+                        AppError::Snapshot(SnapshotError::LineupError(reason)) => reason,
+                        e => e.to_string(),
+                    },
                 }),
             }
         }
+        Ok(conflicts)
+    }
+
+    /// Swaps two players of the starting lineup, to fix a lineup entered in the wrong order.
+    ///
+    /// Returns false, leaving the set untouched, when any of them was not in the starting
+    /// lineup or was involved in a substitution.
+    pub fn swap_initial_players(&mut self, first: Uuid, second: Uuid) -> bool {
+        let substituted = self.events.iter().any(|e| {
+            e.event_type == EventTypeEnum::R
+                && [e.player, e.target_player]
+                    .iter()
+                    .any(|p| *p == Some(first) || *p == Some(second))
+        });
+        let positions = &mut self.initial_positions;
+        match (
+            positions.iter().position(|p| *p == first),
+            positions.iter().position(|p| *p == second),
+        ) {
+            (Some(i), Some(j)) if !substituted => {
+                positions.swap(i, j);
+                true
+            }
+            _ => false,
+        }
     }
 
     pub fn has_events(&self) -> bool {
@@ -87,4 +217,30 @@ impl SetEntry {
         }
         Ok((snapshot, available_options))
     }
+
+    /// Stats contributed by the events carrying the given tag, counted as they were in the
+    /// whole set (so that efficiencies and phases stay consistent with the full snapshot).
+    /// Receptions, sets and free balls are credited to their own event even though their
+    /// outcome is only known with a later one.
+    pub fn compute_tagged_stats(&self, tag: &str) -> Result<Stats, AppError> {
+        let mut snapshot = Snapshot::new(self)?;
+        snapshot.filter_tag(tag);
+        let mut available_options: Vec<EventTypeEnum> = vec![];
+        let mut tagged = Stats::new();
+        for event in &self.events {
+            // stats setters only add to the current stats, so swapping them out isolates the
+            // contribution of a single event
+            let previous = std::mem::replace(&mut snapshot.stats, Stats::new());
+            available_options = snapshot.add_event(event, &available_options)?;
+            let contribution = std::mem::replace(&mut snapshot.stats, previous);
+            snapshot.stats.merge(&contribution);
+            if event.tags.iter().any(|t| t == tag) {
+                tagged.merge(&contribution);
+            }
+        }
+        if let Some(deferred) = snapshot.tagged_stats() {
+            tagged.merge(deferred);
+        }
+        Ok(tagged)
+    }
 }
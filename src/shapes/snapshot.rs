@@ -1,11 +1,16 @@
 use crate::{
     constants::{DEFAULT_SET_TARGET_SCORE, TIE_BREAK_SET_TARGET_SCORE},
-    errors::AppError,
+    errors::{AppError, MatchError},
     shapes::{
-        enums::{EvalEnum, EventTypeEnum, PhaseEnum, RoleEnum, TeamSideEnum, ZoneEnum},
+        enums::{
+            EvalEnum, EventTypeEnum, PhaseEnum, RoleEnum, ServeTypeEnum, TeamSideEnum, ZoneEnum,
+        },
         lineup::Lineup,
+        rally::RallyEntry,
         set::SetEntry,
         stats::Stats,
+        tags::csv_tags,
+        timing::SetTimingEntry,
     },
 };
 use chrono::{DateTime, Utc};
@@ -23,6 +28,67 @@ pub struct EventEntry {
     pub player: Option<Uuid>,
     pub eval: Option<EvalEnum>,
     pub target_player: Option<Uuid>,
+    // blockers taking part in a block besides `player`
+    #[serde(default)]
+    pub second_blocker: Option<Uuid>,
+    #[serde(default)]
+    pub third_blocker: Option<Uuid>,
+    // whether the block touched the ball
+    #[serde(default)]
+    pub touched: bool,
+    // code of the attack combination, from the team catalogue
+    #[serde(default)]
+    pub combination: Option<String>,
+    // serve type, the server's default unless changed while scouting
+    #[serde(default)]
+    pub serve_type: Option<ServeTypeEnum>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default, with = "csv_tags")]
+    pub tags: Vec<String>,
+    // team the point goes to, only for score adjustments
+    #[serde(default)]
+    pub awarded_to: Option<TeamSideEnum>,
+}
+
+impl EventEntry {
+    /// An event with no player, evaluation or any of the optional details.
+    pub fn new(timestamp: DateTime<Utc>, event_type: EventTypeEnum) -> Self {
+        Self {
+            timestamp,
+            event_type,
+            player: None,
+            eval: None,
+            target_player: None,
+            second_blocker: None,
+            third_blocker: None,
+            touched: false,
+            combination: None,
+            serve_type: None,
+            notes: None,
+            tags: vec![],
+            awarded_to: None,
+        }
+    }
+
+    /// Team a score adjustment gives the point to; `None` for any other event.
+    ///
+    /// Adjustments are not rallies, so they are kept out of `has_scored` and of the
+    /// stats built on it.
+    pub fn adjusted_side(&self) -> Option<TeamSideEnum> {
+        match self.event_type {
+            EventTypeEnum::SA => self.awarded_to,
+            _ => None,
+        }
+    }
+
+    /// All the players involved in a block, starting from the credited one.
+    pub fn blockers(&self) -> Vec<Uuid> {
+        [self.player, self.second_blocker, self.third_blocker]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
 }
 
 impl Display for EventEntry {
@@ -42,15 +108,39 @@ pub struct Snapshot {
     pub stats: Stats,
     pub current_lineup: Lineup,
     pub last_event: Option<EventEntry>,
-    pub partials: Vec<(u8, u8)>, // (us, them)
+    pub partials: Vec<(u8, u8)>,           // (us, them)
+    pub scouted_from: Option<(u8, u8)>,    // score the scouting joined at, for sets joined late
+    pub rallies: Vec<RallyEntry>,          // completed rallies
+    pub current_rally: Option<RallyEntry>, // rally in progress
+    rally_rotation: Option<u8>,            // rotation of the last completed rally
+    // stats known only once the rally or the attack is over, kept with the tags of their event
+    pending_reception: Option<(u8, Uuid, EvalEnum, Vec<String>)>, // reception of the current rally
+    pending_set: Option<(PhaseEnum, u8, Uuid, EvalEnum, Vec<String>)>, // set waiting for its attack
+    pending_freeball: Option<(PhaseEnum, u8, Uuid, EvalEnum, Vec<String>)>, // free ball of the rally
+    tag_filter: Option<(String, Stats)>, // tag whose deferred stats are collected apart
 }
 
 // snapshot should be SetSnapshot, and it should guarantees set invariants
 impl Snapshot {
     pub fn new(set_entry: &SetEntry) -> Result<Self, AppError> {
+        let (positions, serving_team, score_us, score_them) = match &set_entry.mid_set_start {
+            Some(start) if !start.is_valid(set_entry.set_number) => {
+                return Err(AppError::Match(MatchError::SetEntryError(format!(
+                    "set {} cannot start at {}-{}, the set would already be over",
+                    set_entry.set_number, start.score_us, start.score_them
+                ))))
+            }
+            Some(start) => (
+                start.positions(set_entry.initial_positions, &set_entry.setter),
+                start.serving_team,
+                start.score_us,
+                start.score_them,
+            ),
+            None => (set_entry.initial_positions, set_entry.serving_team, 0, 0),
+        };
         let current_lineup = Lineup::new(
-            set_entry.initial_positions,
-            match set_entry.serving_team {
+            positions,
+            match serving_team {
                 TeamSideEnum::Us => PhaseEnum::Break,
                 TeamSideEnum::Them => PhaseEnum::SideOut,
             },
@@ -59,15 +149,43 @@ impl Snapshot {
             set_entry.fallback_libero,
         )?;
         Ok(Snapshot {
-            score_us: 0,
-            score_them: 0,
+            score_us,
+            score_them,
+            scouted_from: set_entry
+                .mid_set_start
+                .map(|start| (start.score_us, start.score_them)),
             stats: Stats::new(),
             current_lineup,
             last_event: None,
             partials: vec![],
+            rallies: vec![],
+            current_rally: None,
+            rally_rotation: None,
+            pending_reception: None,
+            pending_set: None,
+            pending_freeball: None,
+            tag_filter: None,
         })
     }
 
+    /// Collects apart the deferred stats of the events carrying the given tag, instead of adding
+    /// them to `stats` with the event that completes them (see `SetEntry::compute_tagged_stats`).
+    pub fn filter_tag(&mut self, tag: &str) {
+        self.tag_filter = Some((tag.to_string(), Stats::new()));
+    }
+
+    /// Deferred stats collected for the filtered tag so far.
+    pub fn tagged_stats(&self) -> Option<&Stats> {
+        self.tag_filter.as_ref().map(|(_, stats)| stats)
+    }
+
+    fn deferred_stats(&mut self, tags: &[String]) -> Option<&mut Stats> {
+        match &mut self.tag_filter {
+            Some((tag, stats)) => tags.contains(tag).then_some(stats),
+            None => Some(&mut self.stats),
+        }
+    }
+
     fn get_attack_zone(&self, player_id: &Uuid) -> Option<ZoneEnum> {
         use PhaseEnum::*;
         use RoleEnum::*;
@@ -97,6 +215,33 @@ impl Snapshot {
         }
     }
 
+    /// Infers the zone the opponent attacked from, mirroring the net zone of the blockers.
+    ///
+    /// Wings decide the zone (the credited blocker first), while a middle blocker
+    /// alone means a central attack.
+    fn get_block_zone(&self, blockers: &[Uuid]) -> Option<ZoneEnum> {
+        use RoleEnum::*;
+        use ZoneEnum::*;
+        let net_zones: Vec<ZoneEnum> = blockers
+            .iter()
+            .filter_map(|id| match self.current_lineup.get_role(id) {
+                Ok(OutsideHitter) => Some(Four),
+                Ok(OppositeHitter | Setter) => Some(Two),
+                Ok(MiddleBlocker) => Some(Three),
+                _ => None,
+            })
+            .collect();
+        let net_zone = net_zones
+            .iter()
+            .find(|z| **z != Three)
+            .or(net_zones.first())?;
+        Some(match net_zone {
+            Four => Two,
+            Two => Four,
+            _ => Three,
+        })
+    }
+
     pub fn get_set_winner(&self, set_number: u8) -> Option<TeamSideEnum> {
         let target_score = if set_number == 5 {
             TIE_BREAK_SET_TARGET_SCORE
@@ -156,6 +301,7 @@ impl Snapshot {
                 attack_zone,
                 prev_eval,
                 current_eval,
+                self.current_lineup.get_current_setter(),
             );
         }
         Ok(())
@@ -170,6 +316,7 @@ impl Snapshot {
             | (D, Some(Error))
             | (P, Some(Error))
             | (S, Some(Error))
+            | (E | X, Some(Error))
             | (F, _)
             | (OS, _) => Some(TeamSideEnum::Them),
             _ => None,
@@ -177,7 +324,7 @@ impl Snapshot {
     }
 
     fn set_score_stats(&mut self, event: &EventEntry) {
-        if let Some(side) = self.has_scored(event) {
+        if let Some(side) = self.has_scored(event).or(event.adjusted_side()) {
             let (score, opponent_score) = match side {
                 TeamSideEnum::Us => (&mut self.score_us, self.score_them),
                 TeamSideEnum::Them => (&mut self.score_them, self.score_us),
@@ -193,7 +340,8 @@ impl Snapshot {
     fn set_phase_count_stats(&mut self, event: &EventEntry) -> Result<(), AppError> {
         let phase = self.current_lineup.get_current_phase();
         let rotation = self.current_lineup.get_current_rotation()?;
-        match &self.has_scored(event) {
+        let scored = self.has_scored(event);
+        match &scored {
             Some(TeamSideEnum::Us) => {
                 self.stats.scored_points.add(phase, rotation);
                 self.stats.phases.add(phase, rotation);
@@ -203,14 +351,33 @@ impl Snapshot {
             }
             _ => {}
         };
+        // a new pass through a rotation starts with its first completed rally
+        if scored.is_some() && self.rally_rotation != Some(rotation) {
+            self.stats.rotation_passes.add(phase, rotation);
+            self.rally_rotation = Some(rotation);
+        }
         Ok(())
     }
 
+    /// Timing of the set so far, including the rally in progress.
+    pub fn get_timing(&self, set_number: u8) -> Option<SetTimingEntry> {
+        let rallies: Vec<RallyEntry> = self
+            .rallies
+            .iter()
+            .chain(self.current_rally.iter())
+            .cloned()
+            .collect();
+        SetTimingEntry::from_rallies(set_number, &rallies)
+    }
+
     pub fn get_serving_team(&self) -> Option<TeamSideEnum> {
         use EvalEnum::*;
         use EventTypeEnum::*;
         use PhaseEnum::*;
         use TeamSideEnum::*;
+        if let Some(side) = self.last_event.as_ref().and_then(|e| e.adjusted_side()) {
+            return Some(side);
+        }
         match (
             self.last_event.as_ref().map(|e| e.event_type),
             self.last_event.as_ref().and_then(|e| e.eval),
@@ -219,7 +386,7 @@ impl Snapshot {
             (None, _, Break) | (Some(OE), _, _) | (Some(B | A | S), Some(Perfect), _) => Some(Us),
             (None, _, SideOut)
             | (Some(B | A), Some(Error | Over), _)
-            | (Some(D | P | S), Some(Error), _)
+            | (Some(D | P | S | E | X), Some(Error), _)
             | (Some(F | OS), _, _) => Some(Them),
             _ => None,
         }
@@ -302,7 +469,7 @@ impl Snapshot {
         let zone = event.player.and_then(|p| self.get_attack_zone(&p));
         let rotation = self.current_lineup.get_current_rotation()?;
         match (event.event_type, event.eval, event.player, zone) {
-            (B | D | P | S, Some(ev), Some(player), _) => {
+            (B | D | P | S | E | X, Some(ev), Some(player), _) => {
                 self.stats.events.add(
                     event.event_type,
                     self.current_lineup.get_current_phase(),
@@ -357,42 +524,54 @@ impl Snapshot {
             vec![OE, F, S, R, CS]
         };
         let options_map: HashMap<_, _> = [
-            // order: OS, OE, F, A, S, P, D, B, R, CL, CS
+            // order: OS, OE, F, A, S, P, D, B, R, CL, CS, E, X
             ((OS, None), serve_them.clone()),
             ((OE, None), serve_us.clone()),
             ((B, Some(Error)), serve_them.clone()),
             ((B, Some(Over)), serve_them.clone()),
             ((B, Some(Perfect)), serve_us.clone()),
-            ((B, Some(Positive)), vec![OE, F, A]),
+            ((B, Some(Positive)), vec![OE, F, A, E, X]),
             ((B, Some(Negative)), vec![OS, OE, F, A, D, B]),
             ((A, Some(Error)), serve_them.clone()),
             ((A, Some(Over)), serve_them.clone()),
             ((A, Some(Negative)), vec![OS, OE, F, A, D, B]),
             ((A, Some(Perfect)), serve_us.clone()),
-            ((A, Some(Positive)), vec![OE, F, A]),
+            ((A, Some(Positive)), vec![OE, F, A, E, X]),
             ((D, Some(Error)), serve_them.clone()),
             ((D, Some(Over)), vec![OS, OE, F, A, D, B]),
-            ((D, Some(Exclamative)), vec![OE, F, A]),
-            ((D, Some(Negative)), vec![OE, F, A]),
-            ((D, Some(Positive)), vec![OE, F, A]),
-            ((D, Some(Perfect)), vec![OE, F, A]),
+            ((D, Some(Exclamative)), vec![OE, F, A, E, X]),
+            ((D, Some(Negative)), vec![OE, F, A, E, X]),
+            ((D, Some(Positive)), vec![OE, F, A, E, X]),
+            ((D, Some(Perfect)), vec![OE, F, A, E, X]),
             ((F, None), serve_them.clone()),
             ((P, Some(Error)), serve_them.clone()),
             ((P, Some(Over)), vec![OS, OE, F, A, D, B]),
-            ((P, Some(Exclamative)), vec![OE, F, A]),
-            ((P, Some(Negative)), vec![OE, F, A]),
-            ((P, Some(Positive)), vec![OE, F, A]),
-            ((P, Some(Perfect)), vec![OE, F, A]),
+            ((P, Some(Exclamative)), vec![OE, F, A, E, X]),
+            ((P, Some(Negative)), vec![OE, F, A, E, X]),
+            ((P, Some(Positive)), vec![OE, F, A, E, X]),
+            ((P, Some(Perfect)), vec![OE, F, A, E, X]),
             ((S, Some(Error)), serve_them.clone()),
             ((S, Some(Perfect)), serve_us.clone()),
             ((S, Some(Positive)), vec![OS, OE, F, D, B]),
-            ((S, Some(Over)), vec![OE, A, F]),
+            ((S, Some(Over)), vec![OE, A, F, E, X]),
             ((S, Some(Negative)), vec![OS, OE, F, D, B]),
+            ((E, Some(Error)), serve_them.clone()),
+            ((E, Some(Perfect)), vec![OE, F, A, X]),
+            ((E, Some(Positive)), vec![OE, F, A, X]),
+            ((E, Some(Negative)), vec![OE, F, A, X]),
+            ((X, Some(Error)), serve_them.clone()),
+            ((X, Some(Positive)), vec![OS, OE, F, D, B]),
+            ((X, Some(Negative)), vec![OS, OE, F, D, B]),
         ]
         .into_iter()
         .collect();
         match (event.event_type, event.eval) {
             (R | CL | CS, _) => current_available_options.to_vec(),
+            (SA, _) => match event.awarded_to {
+                Some(TeamSideEnum::Us) => serve_us,
+                Some(TeamSideEnum::Them) => serve_them,
+                None => current_available_options.to_vec(),
+            },
             key => options_map.get(&key).cloned().unwrap_or_default(),
         }
     }
@@ -434,6 +613,7 @@ impl Snapshot {
         let rotation = self.current_lineup.get_current_rotation()?;
         let last_event_type = self.last_event.as_ref().map(|e| e.event_type);
         let last_event_eval = self.last_event.as_ref().and_then(|e| e.eval);
+        let last_event_player = self.last_event.as_ref().and_then(|e| e.player);
         match (
             event.event_type,
             event.eval,
@@ -445,6 +625,7 @@ impl Snapshot {
                 // our ace
                 self.stats.first_rally.add(
                     rotation,
+                    None,                   // receiver
                     None,                   // reception eval
                     Some(event.event_type), // finalizing event type
                     None,                   // finalizing event eval
@@ -454,6 +635,7 @@ impl Snapshot {
                 // opponent ace
                 self.stats.first_rally.add(
                     rotation,
+                    None,                   // receiver
                     None,                   // reception eval
                     Some(event.event_type), // finalizing event type
                     None,                   // finalizing event eval
@@ -463,6 +645,7 @@ impl Snapshot {
                 // reception error or slash
                 self.stats.first_rally.add(
                     rotation,
+                    event.player,           // receiver
                     event.eval,             // reception eval
                     Some(event.event_type), // finalizing event type
                     None,                   // finalizing event eval
@@ -472,6 +655,7 @@ impl Snapshot {
                 // attack (previous is a non error reception)
                 self.stats.first_rally.add(
                     rotation,
+                    last_event_player,      // receiver
                     last_event_eval,        // reception eval
                     Some(event.event_type), // finalizing event type
                     event.eval,             // finalizing event eval
@@ -481,6 +665,7 @@ impl Snapshot {
                 // opponent error (previous is a non error pass)
                 self.stats.first_rally.add(
                     rotation,
+                    last_event_player,      // receiver
                     last_event_eval,        // reception eval
                     Some(event.event_type), // finalizing event type
                     None,                   // finalizing event eval
@@ -490,6 +675,7 @@ impl Snapshot {
                 // fault after reception
                 self.stats.first_rally.add(
                     rotation,
+                    last_event_player,      // receiver
                     last_event_eval,        // reception eval
                     Some(event.event_type), // finalizing event type
                     None,                   // finalizing event eval
@@ -500,6 +686,189 @@ impl Snapshot {
         Ok(())
     }
 
+    fn set_reception_stats(&mut self, event: &EventEntry) -> Result<(), AppError> {
+        let rotation = self.current_lineup.get_current_rotation()?;
+        if let (EventTypeEnum::P, Some(player), Some(eval)) =
+            (event.event_type, event.player, event.eval)
+        {
+            self.pending_reception = Some((rotation, player, eval, event.tags.clone()));
+        }
+        if let Some(side) = self.has_scored(event) {
+            // the rally is over: credit the outcome to the reception, if any
+            if let Some((rotation, player, eval, tags)) = self.pending_reception.take() {
+                if let Some(stats) = self.deferred_stats(&tags) {
+                    stats
+                        .reception
+                        .add(rotation, player, eval, side == TeamSideEnum::Us);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn set_set_touch_stats(&mut self, event: &EventEntry) -> Result<(), AppError> {
+        use EventTypeEnum::*;
+        let phase = self.current_lineup.get_current_phase();
+        let rotation = self.current_lineup.get_current_rotation()?;
+        match (event.event_type, event.player, event.eval) {
+            (E, Some(player), Some(eval)) => {
+                self.pending_set = Some((phase, rotation, player, eval, event.tags.clone()));
+            }
+            (A, _, Some(attack_eval)) => {
+                // credit the attack outcome to the set that preceded it, if any
+                if let Some((phase, rotation, player, eval, tags)) = self.pending_set.take() {
+                    if let Some(stats) = self.deferred_stats(&tags) {
+                        stats.sets.add(phase, rotation, player, eval, attack_eval);
+                    }
+                }
+            }
+            (R | CL | CS, _, _) => {}
+            _ => self.pending_set = None,
+        }
+        Ok(())
+    }
+
+    fn set_block_stats(&mut self, event: &EventEntry) -> Result<(), AppError> {
+        if let (EventTypeEnum::B, Some(eval)) = (event.event_type, event.eval) {
+            let blockers = event.blockers();
+            if !blockers.is_empty() {
+                let zone = self.get_block_zone(&blockers);
+                self.stats.blocks.add(
+                    self.current_lineup.get_current_phase(),
+                    self.current_lineup.get_current_rotation()?,
+                    blockers,
+                    zone,
+                    eval,
+                    event.touched,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn set_attack_combination_stats(&mut self, event: &EventEntry) -> Result<(), AppError> {
+        if let (EventTypeEnum::A, Some(player), Some(eval), Some(code)) = (
+            event.event_type,
+            event.player,
+            event.eval,
+            &event.combination,
+        ) {
+            let pass_eval = self
+                .last_event
+                .as_ref()
+                .filter(|e| matches!(e.event_type, EventTypeEnum::P | EventTypeEnum::D))
+                .and_then(|e| e.eval);
+            self.stats.attack_combinations.add(
+                self.current_lineup.get_current_phase(),
+                self.current_lineup.get_current_rotation()?,
+                player,
+                code.clone(),
+                pass_eval,
+                eval,
+            );
+        }
+        Ok(())
+    }
+
+    fn set_serve_type_stats(&mut self, event: &EventEntry) -> Result<(), AppError> {
+        if let (EventTypeEnum::S, Some(player), Some(eval), Some(serve_type)) =
+            (event.event_type, event.player, event.eval, event.serve_type)
+        {
+            self.stats.serve_types.add(
+                self.current_lineup.get_current_rotation()?,
+                player,
+                serve_type,
+                eval,
+            );
+        }
+        Ok(())
+    }
+
+    fn set_freeball_stats(&mut self, event: &EventEntry) -> Result<(), AppError> {
+        let phase = self.current_lineup.get_current_phase();
+        let rotation = self.current_lineup.get_current_rotation()?;
+        if let (EventTypeEnum::X, Some(player), Some(eval)) =
+            (event.event_type, event.player, event.eval)
+        {
+            self.pending_freeball = Some((phase, rotation, player, eval, event.tags.clone()));
+        }
+        if let Some(side) = self.has_scored(event) {
+            if let Some((phase, rotation, player, eval, tags)) = self.pending_freeball.take() {
+                if let Some(stats) = self.deferred_stats(&tags) {
+                    stats
+                        .freeballs
+                        .add(phase, rotation, player, eval, side == TeamSideEnum::Us);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn set_rally_stats(&mut self, event: &EventEntry) -> Result<(), AppError> {
+        use EventTypeEnum::*;
+        if matches!(event.event_type, R | CL | CS | SA) {
+            return Ok(());
+        }
+        let rotation = self.current_lineup.get_current_rotation()?;
+        let serving_team = match self.current_lineup.get_current_phase() {
+            PhaseEnum::Break => TeamSideEnum::Us,
+            PhaseEnum::SideOut => TeamSideEnum::Them,
+        };
+        let scored = self.has_scored(event);
+        let mut rally = self
+            .current_rally
+            .take()
+            .unwrap_or_else(|| RallyEntry::new(serving_team, rotation));
+        rally.events.push(event.clone());
+        rally.score = (self.score_us, self.score_them);
+        match scored {
+            Some(side) => {
+                rally.winner = Some(side);
+                self.stats.rallies.add(
+                    rally.phase(),
+                    rally.rotation,
+                    rally.length(),
+                    side == TeamSideEnum::Us,
+                );
+                self.rallies.push(rally);
+            }
+            None => self.current_rally = Some(rally),
+        }
+        Ok(())
+    }
+
+    fn set_court_stats(&mut self, event: &EventEntry) -> Result<(), AppError> {
+        let rotation = self.current_lineup.get_current_rotation()?;
+        if let Some(side) = self.has_scored(event) {
+            let phase = self.current_lineup.get_current_phase();
+            let on_court = self.current_lineup.get_on_court_players();
+            for player in on_court {
+                self.stats
+                    .court
+                    .add(phase, rotation, player, side == TeamSideEnum::Us);
+            }
+            self.stats
+                .combinations
+                .add(phase, on_court, side == TeamSideEnum::Us);
+        }
+        Ok(())
+    }
+
+    fn set_libero_swaps_stats(&mut self, on_court_before: &[Uuid; 6]) -> Result<(), AppError> {
+        let rotation = self.current_lineup.get_current_rotation()?;
+        let on_court = self.current_lineup.get_on_court_players();
+        let liberos = [
+            Some(self.current_lineup.get_current_libero()),
+            self.current_lineup.get_fallback_libero(),
+        ];
+        for libero in liberos.into_iter().flatten() {
+            if on_court.contains(&libero) && !on_court_before.contains(&libero) {
+                self.stats.libero_swaps.add(rotation, libero);
+            }
+        }
+        Ok(())
+    }
+
     fn set_attack_stats(&mut self, event: &EventEntry) -> Result<(), AppError> {
         use EvalEnum::*;
         use EventTypeEnum::*;
@@ -555,7 +924,23 @@ impl Snapshot {
         self.set_counter_attack_stats(event)?;
         self.set_attack_stats(event)?;
         self.set_distribution_stats(event)?;
+        self.set_attack_combination_stats(event)?;
+        self.set_serve_type_stats(event)?;
         self.set_first_rally_stats(event)?;
+        self.set_reception_stats(event)?;
+        self.set_set_touch_stats(event)?;
+        self.set_freeball_stats(event)?;
+        self.set_block_stats(event)?;
+        self.set_court_stats(event)?;
+        self.set_rally_stats(event)?;
+        if event.event_type == EventTypeEnum::SA {
+            // whatever was left of the rally in progress did not count on the scoresheet
+            self.current_rally = None;
+            self.pending_reception = None;
+            self.pending_set = None;
+            self.pending_freeball = None;
+        }
+        let on_court_before = self.current_lineup.get_on_court_players();
         let available_options = self.get_available_options(event, current_available_options);
         if event.event_type == EventTypeEnum::R {
             // replace lineup entry
@@ -577,9 +962,15 @@ impl Snapshot {
             }
         }
         self.current_lineup.update(event)?;
+        self.set_libero_swaps_stats(&on_court_before)?;
+        // a set that keeps the ball alive only refines the touch before it, so the
+        // attack that follows is still related to the reception or the dig
+        let is_set_in_play =
+            event.event_type == EventTypeEnum::E && event.eval != Some(EvalEnum::Error);
         if event.event_type != EventTypeEnum::R
             && event.event_type != EventTypeEnum::CL
             && event.event_type != EventTypeEnum::CS
+            && !is_set_in_play
         {
             self.last_event = Some(event.clone());
         }
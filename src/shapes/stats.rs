@@ -1,4 +1,7 @@
-use crate::shapes::enums::{ErrorTypeEnum, EvalEnum, EventTypeEnum, PhaseEnum, ZoneEnum};
+use crate::shapes::enums::{
+    BlockCompositionEnum, ErrorTypeEnum, EvalEnum, EventTypeEnum, PhaseEnum, RallyLengthEnum,
+    ServeTypeEnum, ZoneEnum,
+};
 use std::collections::HashMap;
 use std::hash::Hash;
 use uuid::Uuid;
@@ -64,6 +67,26 @@ impl Metric {
                 EvalEnum::Error | EvalEnum::Over => -1,
                 _ => 0,
             },
+            // set
+            (EventTypeEnum::E, Metric::Positive) => match eval {
+                EvalEnum::Perfect | EvalEnum::Positive => 1,
+                _ => 0,
+            },
+            (EventTypeEnum::E, Metric::Efficiency) => match eval {
+                EvalEnum::Perfect | EvalEnum::Positive => 1,
+                EvalEnum::Error => -1,
+                _ => 0,
+            },
+            // free ball
+            (EventTypeEnum::X, Metric::Positive) => match eval {
+                EvalEnum::Positive => 1,
+                _ => 0,
+            },
+            (EventTypeEnum::X, Metric::Efficiency) => match eval {
+                EvalEnum::Positive => 1,
+                EvalEnum::Error => -1,
+                _ => 0,
+            },
             // default fallback
             _ => 0,
         }
@@ -196,6 +219,7 @@ impl CounterAttackStats {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FirstRallyStatsKey {
     pub rotation: u8,
+    pub player: Option<Uuid>, // receiver
     pub reception_eval: Option<EvalEnum>,
     pub finalizing_event_type: Option<EventTypeEnum>,
     pub finalizing_event_eval: Option<EvalEnum>,
@@ -212,12 +236,14 @@ impl FirstRallyStats {
     pub fn add(
         &mut self,
         rotation: u8,
+        player: Option<Uuid>,
         reception_eval: Option<EvalEnum>,
         finalizing_event_type: Option<EventTypeEnum>,
         finalizing_event_eval: Option<EvalEnum>,
     ) {
         let key = FirstRallyStatsKey {
             rotation,
+            player,
             reception_eval,
             finalizing_event_type,
             finalizing_event_eval,
@@ -234,12 +260,14 @@ impl FirstRallyStats {
     pub fn query(
         &self,
         rotation: Option<u8>,
+        player: Option<Uuid>,
         reception_eval: Option<EvalEnum>,
         finalizing_event_type: Option<EventTypeEnum>,
         finalizing_event_eval: Option<EvalEnum>,
     ) -> impl Iterator<Item = (&FirstRallyStatsKey, &u32)> {
         self.0.iter().filter(move |(k, _)| {
             rotation.is_none_or(|r| k.rotation == r)
+                && player.is_none_or(|p| k.player == Some(p))
                 && reception_eval.is_none_or(|re| k.reception_eval == Some(re))
                 && finalizing_event_type.is_none_or(|ft| k.finalizing_event_type == Some(ft))
                 && finalizing_event_eval.is_none_or(|fe| k.finalizing_event_eval == Some(fe))
@@ -247,6 +275,54 @@ impl FirstRallyStats {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReceptionStatsKey {
+    pub rotation: u8,
+    pub player: Uuid,
+    pub eval: EvalEnum,
+    pub sideout: bool, // whether the rally following the reception was won
+}
+
+#[derive(Debug, Clone)]
+pub struct ReceptionStats(pub HashMap<ReceptionStatsKey, u32>);
+
+impl ReceptionStats {
+    pub fn new() -> Self {
+        ReceptionStats(HashMap::new())
+    }
+
+    pub fn add(&mut self, rotation: u8, player: Uuid, eval: EvalEnum, sideout: bool) {
+        let key = ReceptionStatsKey {
+            rotation,
+            player,
+            eval,
+            sideout,
+        };
+        *self.0.entry(key).or_insert(0) += 1;
+    }
+
+    pub fn merge(&mut self, other: &ReceptionStats) {
+        for (k, v) in &other.0 {
+            *self.0.entry(k.clone()).or_insert(0) += v;
+        }
+    }
+
+    pub fn query(
+        &self,
+        rotation: Option<u8>,
+        player: Option<Uuid>,
+        eval: Option<EvalEnum>,
+        sideout: Option<bool>,
+    ) -> impl Iterator<Item = (&ReceptionStatsKey, &u32)> {
+        self.0.iter().filter(move |(k, _)| {
+            rotation.is_none_or(|r| k.rotation == r)
+                && player.is_none_or(|p| k.player == p)
+                && eval.is_none_or(|e| k.eval == e)
+                && sideout.is_none_or(|so| k.sideout == so)
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AttackStatsKey {
     pub phase: PhaseEnum,
@@ -320,6 +396,7 @@ pub struct DistributionsStatsKey {
     pub zone: ZoneEnum,
     pub eval: EvalEnum,
     pub attack_eval: EvalEnum,
+    pub setter: Uuid,
 }
 
 #[derive(Debug, Clone)]
@@ -338,6 +415,7 @@ impl DistributionStats {
         zone: ZoneEnum,
         prev_eval: EvalEnum,
         attack_eval: EvalEnum,
+        setter: Uuid,
     ) {
         let key = DistributionsStatsKey {
             phase,
@@ -346,6 +424,7 @@ impl DistributionStats {
             zone,
             eval: prev_eval,
             attack_eval,
+            setter,
         };
         *self.0.entry(key).or_insert(0) += 1;
     }
@@ -414,6 +493,53 @@ impl DistributionStats {
             None
         }
     }
+
+    /// Returns the zone the ball was set to most often, along with its share of
+    /// the distribution and the total number of balls set.
+    ///
+    /// The share of the top zone is a rough measure of the setter's predictability.
+    pub fn top_zone(
+        &self,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+        player: Option<Uuid>,
+        prev_eval_filter: Option<EvalEnum>,
+    ) -> Option<(ZoneEnum, f64, u32)> {
+        let mut total_balls = 0u32;
+        let mut balls_by_zone: HashMap<ZoneEnum, u32> = HashMap::new();
+        for (key, count) in self.query(phase, rotation, player, None, prev_eval_filter, None) {
+            total_balls += count;
+            *balls_by_zone.entry(key.zone).or_insert(0) += count;
+        }
+        balls_by_zone
+            .into_iter()
+            .max_by_key(|(zone, count)| (*count, std::cmp::Reverse(*zone as u8)))
+            .map(|(zone, count)| (zone, count as f64 / total_balls as f64 * 100.0, total_balls))
+    }
+
+    /// Summarizes the balls set while the given player was the setter: the total, the
+    /// most used zone with its share and the kill percentage of the resulting attacks.
+    pub fn setter_summary(&self, setter: Uuid) -> Option<(u32, ZoneEnum, f64, f64)> {
+        let mut total_balls = 0u32;
+        let mut kills = 0u32;
+        let mut balls_by_zone: HashMap<ZoneEnum, u32> = HashMap::new();
+        for (key, count) in self.0.iter().filter(|(k, _)| k.setter == setter) {
+            total_balls += count;
+            if key.attack_eval == EvalEnum::Perfect {
+                kills += count;
+            }
+            *balls_by_zone.entry(key.zone).or_insert(0) += count;
+        }
+        let (zone, count) = balls_by_zone
+            .into_iter()
+            .max_by_key(|(zone, count)| (*count, std::cmp::Reverse(*zone as u8)))?;
+        Some((
+            total_balls,
+            zone,
+            count as f64 / total_balls as f64 * 100.0,
+            kills as f64 / total_balls as f64 * 100.0,
+        ))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -574,180 +700,985 @@ impl CountStats {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct Stats {
-    pub events: EventsStats,
-    pub distribution: DistributionStats,
-    pub errors: ErrorsStats,
-    pub opponent_errors: OpponentErrorsStats,
-    pub possessions: CountStats,
-    pub phases: CountStats,
-    pub attack: AttackStats,
-    pub counter_attack: CounterAttackStats,
-    pub earned_points: PointsStats,
-    pub scored_points: PointsStats,
-    pub first_rally: FirstRallyStats,
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CourtStatsKey {
+    pub phase: PhaseEnum,
+    pub rotation: u8,
+    pub player: Uuid,
+    pub won: bool, // whether the rally was won
 }
 
-impl Stats {
+/// Rallies played by each player while on court.
+#[derive(Debug, Clone)]
+pub struct CourtStats(pub HashMap<CourtStatsKey, u32>);
+
+impl CourtStats {
     pub fn new() -> Self {
-        Stats {
-            events: EventsStats::new(),
-            distribution: DistributionStats::new(),
-            errors: ErrorsStats::new(),
-            opponent_errors: OpponentErrorsStats::new(),
-            possessions: CountStats::new(),
-            phases: CountStats::new(),
-            attack: AttackStats::new(),
-            counter_attack: CounterAttackStats::new(),
-            earned_points: PointsStats::new(),
-            scored_points: PointsStats::new(),
-            first_rally: FirstRallyStats::new(),
-        }
+        CourtStats(HashMap::new())
     }
 
-    pub fn merge(&mut self, other: &Stats) {
-        self.attack.merge(&other.attack);
-        self.counter_attack.merge(&other.counter_attack);
-        self.distribution.merge(&other.distribution);
-        self.errors.merge(&other.errors);
-        self.events.merge(&other.events);
-        self.opponent_errors.merge(&other.opponent_errors);
-        self.earned_points.merge(&other.earned_points);
-        self.scored_points.merge(&other.scored_points);
-        self.possessions.merge(&other.possessions);
-        self.phases.merge(&other.phases);
-        self.first_rally.merge(&other.first_rally);
+    pub fn add(&mut self, phase: PhaseEnum, rotation: u8, player: Uuid, won: bool) {
+        let key = CourtStatsKey {
+            phase,
+            rotation,
+            player,
+            won,
+        };
+        *self.0.entry(key).or_insert(0) += 1;
     }
 
-    #[allow(dead_code)]
-    pub fn number_of_possessions_per_earned_point(
-        &self,
-        phase: Option<PhaseEnum>,
-        rotation: Option<u8>,
-    ) -> Option<(f64, u32, u32)> {
-        let total: u32 = self
-            .possessions
-            .query(phase, rotation)
-            .map(|(_, v)| *v)
-            .sum();
-        let count: u32 = self
-            .earned_points
-            .query(phase, rotation)
-            .map(|(_, v)| *v)
-            .sum();
-        if count == 0 {
-            None
-        } else {
-            Some((total as f64 / count as f64, total, count))
+    pub fn merge(&mut self, other: &CourtStats) {
+        for (k, v) in &other.0 {
+            *self.0.entry(k.clone()).or_insert(0) += v;
         }
     }
 
-    pub fn number_of_phases_per_scored_point(
+    pub fn query(
         &self,
         phase: Option<PhaseEnum>,
         rotation: Option<u8>,
-    ) -> Option<(f64, u32, u32)> {
-        let total: u32 = self.phases.query(phase, rotation).map(|(_, v)| *v).sum();
-        let count: u32 = self
-            .scored_points
-            .query(phase, rotation)
-            .map(|(_, v)| *v)
-            .sum();
-        if count == 0 {
-            None
-        } else {
-            Some((total as f64 / count as f64, total, count))
-        }
+        player: Option<Uuid>,
+        won: Option<bool>,
+    ) -> impl Iterator<Item = (&CourtStatsKey, &u32)> {
+        self.0.iter().filter(move |(k, _)| {
+            phase.is_none_or(|p| k.phase == p)
+                && rotation.is_none_or(|r| k.rotation == r)
+                && player.is_none_or(|pl| k.player == pl)
+                && won.is_none_or(|w| k.won == w)
+        })
     }
+}
 
-    #[allow(dead_code)]
-    pub fn attack_efficiency(
-        &self,
-        player: Option<Uuid>,
-        phase: Option<PhaseEnum>,
-        rotation: Option<u8>,
-        zone: Option<ZoneEnum>,
-        prev_eval: EvalEnum,
-    ) -> Option<(f64, u32, i32)> {
-        let mut count: i32 = 0;
-        let mut total: u32 = 0;
-        for (key, inc) in self
-            .attack
-            .query(phase, rotation, player, zone, None, Some(prev_eval))
-        {
-            total += *inc;
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LiberoSwapsStatsKey {
+    pub rotation: u8,
+    pub player: Uuid,
+}
 
-            let score = match key.eval {
-                EvalEnum::Perfect => 1,
-                EvalEnum::Error | EvalEnum::Over => -1,
-                _ => 0,
-            };
-            count += score * (*inc as i32);
-        }
-        (total > 0).then_some(((count as f64) / (total as f64) * 100.0, total, count))
+/// Number of times each libero entered the court.
+#[derive(Debug, Clone)]
+pub struct LiberoSwapsStats(pub HashMap<LiberoSwapsStatsKey, u32>);
+
+impl LiberoSwapsStats {
+    pub fn new() -> Self {
+        LiberoSwapsStats(HashMap::new())
     }
 
-    pub fn counter_attack_conversion_rate(
-        &self,
-        player: Option<Uuid>,
-        phase: Option<PhaseEnum>,
-        rotation: Option<u8>,
-        zone: Option<ZoneEnum>,
-    ) -> Option<(f64, u32, u32)> {
-        let mut total: u32 = 0;
-        let mut count: u32 = 0;
-        for (key, incr) in self
-            .counter_attack
-            .query(phase, rotation, player, zone, None)
-        {
-            total += *incr;
-            if key.eval == EvalEnum::Perfect {
-                count += *incr;
-            }
-        }
-        if total == 0 {
-            None
-        } else {
-            Some((100.0 * count as f64 / total as f64, total, count))
-        }
+    pub fn add(&mut self, rotation: u8, player: Uuid) {
+        let key = LiberoSwapsStatsKey { rotation, player };
+        *self.0.entry(key).or_insert(0) += 1;
     }
 
-    pub fn scored_points(
-        &self,
-        event_type: EventTypeEnum,
-        player: Option<Uuid>,
-        phase: Option<PhaseEnum>,
-        rotation: Option<u8>,
-        zone: Option<ZoneEnum>,
-    ) -> Option<u32> {
-        if event_type.provides_direct_points() {
-            self.event_count(
-                event_type,
-                player,
-                phase,
-                rotation,
-                zone,
-                Some(EvalEnum::Perfect),
-            )
-        } else {
-            None
+    pub fn merge(&mut self, other: &LiberoSwapsStats) {
+        for (k, v) in &other.0 {
+            *self.0.entry(k.clone()).or_insert(0) += v;
         }
     }
 
-    pub fn total_scored_points(
+    pub fn query(
         &self,
-        player: Option<Uuid>,
-        phase: Option<PhaseEnum>,
         rotation: Option<u8>,
-        zone: Option<ZoneEnum>,
-    ) -> Option<u32> {
-        let serve_points = self.scored_points(EventTypeEnum::S, player, phase, rotation, zone);
-        let attack_points = self.scored_points(EventTypeEnum::A, player, phase, rotation, zone);
-        let block_points = self.scored_points(EventTypeEnum::B, player, phase, rotation, zone);
-        let total =
-            serve_points.unwrap_or(0) + attack_points.unwrap_or(0) + block_points.unwrap_or(0);
-        (total > 0).then_some(total)
+        player: Option<Uuid>,
+    ) -> impl Iterator<Item = (&LiberoSwapsStatsKey, &u32)> {
+        self.0.iter().filter(move |(k, _)| {
+            rotation.is_none_or(|r| k.rotation == r) && player.is_none_or(|pl| k.player == pl)
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CombinationStatsKey {
+    pub phase: PhaseEnum,
+    pub players: [Uuid; 6], // sorted, so the same six match regardless of rotation
+    pub won: bool,
+}
+
+/// Rallies played by each six-player combination on court.
+#[derive(Debug, Clone)]
+pub struct CombinationStats(pub HashMap<CombinationStatsKey, u32>);
+
+impl CombinationStats {
+    pub fn new() -> Self {
+        CombinationStats(HashMap::new())
+    }
+
+    pub fn add(&mut self, phase: PhaseEnum, mut players: [Uuid; 6], won: bool) {
+        players.sort();
+        let key = CombinationStatsKey {
+            phase,
+            players,
+            won,
+        };
+        *self.0.entry(key).or_insert(0) += 1;
+    }
+
+    pub fn merge(&mut self, other: &CombinationStats) {
+        for (k, v) in &other.0 {
+            *self.0.entry(k.clone()).or_insert(0) += v;
+        }
+    }
+
+    pub fn query(
+        &self,
+        phase: Option<PhaseEnum>,
+        player: Option<Uuid>,
+        won: Option<bool>,
+    ) -> impl Iterator<Item = (&CombinationStatsKey, &u32)> {
+        self.0.iter().filter(move |(k, _)| {
+            phase.is_none_or(|p| k.phase == p)
+                && player.is_none_or(|pl| k.players.contains(&pl))
+                && won.is_none_or(|w| k.won == w)
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SetStatsKey {
+    pub phase: PhaseEnum,
+    pub rotation: u8,
+    pub player: Uuid,
+    pub eval: EvalEnum,
+    pub attack_eval: EvalEnum,
+}
+
+/// Sets followed by an attack, keyed by the set quality and the attack outcome.
+#[derive(Debug, Clone)]
+pub struct SetStats(pub HashMap<SetStatsKey, u32>);
+
+impl SetStats {
+    pub fn new() -> Self {
+        SetStats(HashMap::new())
+    }
+
+    pub fn add(
+        &mut self,
+        phase: PhaseEnum,
+        rotation: u8,
+        player: Uuid,
+        eval: EvalEnum,
+        attack_eval: EvalEnum,
+    ) {
+        let key = SetStatsKey {
+            phase,
+            rotation,
+            player,
+            eval,
+            attack_eval,
+        };
+        *self.0.entry(key).or_insert(0) += 1;
+    }
+
+    pub fn merge(&mut self, other: &SetStats) {
+        for (k, v) in &other.0 {
+            *self.0.entry(k.clone()).or_insert(0) += v;
+        }
+    }
+
+    pub fn query(
+        &self,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+        player: Option<Uuid>,
+        eval: Option<EvalEnum>,
+    ) -> impl Iterator<Item = (&SetStatsKey, &u32)> {
+        self.0.iter().filter(move |(k, _)| {
+            phase.is_none_or(|p| k.phase == p)
+                && rotation.is_none_or(|r| k.rotation == r)
+                && player.is_none_or(|pl| k.player == pl)
+                && eval.is_none_or(|e| k.eval == e)
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FreeballStatsKey {
+    pub phase: PhaseEnum,
+    pub rotation: u8,
+    pub player: Uuid,
+    pub eval: EvalEnum,
+    pub won: bool, // whether the rally following the free ball was won
+}
+
+#[derive(Debug, Clone)]
+pub struct FreeballStats(pub HashMap<FreeballStatsKey, u32>);
+
+impl FreeballStats {
+    pub fn new() -> Self {
+        FreeballStats(HashMap::new())
+    }
+
+    pub fn add(&mut self, phase: PhaseEnum, rotation: u8, player: Uuid, eval: EvalEnum, won: bool) {
+        let key = FreeballStatsKey {
+            phase,
+            rotation,
+            player,
+            eval,
+            won,
+        };
+        *self.0.entry(key).or_insert(0) += 1;
+    }
+
+    pub fn merge(&mut self, other: &FreeballStats) {
+        for (k, v) in &other.0 {
+            *self.0.entry(k.clone()).or_insert(0) += v;
+        }
+    }
+
+    pub fn query(
+        &self,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+        player: Option<Uuid>,
+        eval: Option<EvalEnum>,
+    ) -> impl Iterator<Item = (&FreeballStatsKey, &u32)> {
+        self.0.iter().filter(move |(k, _)| {
+            phase.is_none_or(|p| k.phase == p)
+                && rotation.is_none_or(|r| k.rotation == r)
+                && player.is_none_or(|pl| k.player == pl)
+                && eval.is_none_or(|e| k.eval == e)
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlockStatsKey {
+    pub phase: PhaseEnum,
+    pub rotation: u8,
+    pub blockers: Vec<Uuid>,    // the credited blocker first
+    pub zone: Option<ZoneEnum>, // opponent attack zone
+    pub eval: EvalEnum,
+    pub touched: bool,
+}
+
+impl BlockStatsKey {
+    pub fn composition(&self) -> BlockCompositionEnum {
+        BlockCompositionEnum::from_blockers(self.blockers.len())
+    }
+}
+
+/// Blocks keyed by the players taking part, so a multiple block counts once for the team
+/// and once for each of its blockers.
+#[derive(Debug, Clone)]
+pub struct BlockStats(pub HashMap<BlockStatsKey, u32>);
+
+impl BlockStats {
+    pub fn new() -> Self {
+        BlockStats(HashMap::new())
+    }
+
+    pub fn add(
+        &mut self,
+        phase: PhaseEnum,
+        rotation: u8,
+        blockers: Vec<Uuid>,
+        zone: Option<ZoneEnum>,
+        eval: EvalEnum,
+        touched: bool,
+    ) {
+        let key = BlockStatsKey {
+            phase,
+            rotation,
+            blockers,
+            zone,
+            eval,
+            touched,
+        };
+        *self.0.entry(key).or_insert(0) += 1;
+    }
+
+    pub fn merge(&mut self, other: &BlockStats) {
+        for (k, v) in &other.0 {
+            *self.0.entry(k.clone()).or_insert(0) += v;
+        }
+    }
+
+    pub fn query(
+        &self,
+        player: Option<Uuid>,
+        rotation: Option<u8>,
+        zone: Option<ZoneEnum>,
+        composition: Option<BlockCompositionEnum>,
+        eval: Option<EvalEnum>,
+    ) -> impl Iterator<Item = (&BlockStatsKey, &u32)> {
+        self.0.iter().filter(move |(k, _)| {
+            player.is_none_or(|pl| k.blockers.contains(&pl))
+                && rotation.is_none_or(|r| k.rotation == r)
+                && zone.is_none_or(|z| k.zone == Some(z))
+                && composition.is_none_or(|c| k.composition() == c)
+                && eval.is_none_or(|e| k.eval == e)
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AttackCombinationStatsKey {
+    pub phase: PhaseEnum,
+    pub rotation: u8,
+    pub player: Uuid,
+    pub code: String,
+    pub pass_eval: Option<EvalEnum>, // quality of the pass or dig before the attack
+    pub eval: EvalEnum,
+}
+
+/// Attacks tagged with a combination code of the team catalogue.
+#[derive(Debug, Clone)]
+pub struct AttackCombinationStats(pub HashMap<AttackCombinationStatsKey, u32>);
+
+impl AttackCombinationStats {
+    pub fn new() -> Self {
+        AttackCombinationStats(HashMap::new())
+    }
+
+    pub fn add(
+        &mut self,
+        phase: PhaseEnum,
+        rotation: u8,
+        player: Uuid,
+        code: String,
+        pass_eval: Option<EvalEnum>,
+        eval: EvalEnum,
+    ) {
+        let key = AttackCombinationStatsKey {
+            phase,
+            rotation,
+            player,
+            code,
+            pass_eval,
+            eval,
+        };
+        *self.0.entry(key).or_insert(0) += 1;
+    }
+
+    pub fn merge(&mut self, other: &AttackCombinationStats) {
+        for (k, v) in &other.0 {
+            *self.0.entry(k.clone()).or_insert(0) += v;
+        }
+    }
+
+    pub fn query<'a>(
+        &'a self,
+        code: Option<&'a str>,
+        player: Option<Uuid>,
+        pass_eval: Option<EvalEnum>,
+    ) -> impl Iterator<Item = (&'a AttackCombinationStatsKey, &'a u32)> {
+        self.0.iter().filter(move |(k, _)| {
+            code.is_none_or(|c| k.code == c)
+                && player.is_none_or(|pl| k.player == pl)
+                && pass_eval.is_none_or(|e| k.pass_eval == Some(e))
+        })
+    }
+
+    /// The codes that were used at least once, sorted.
+    pub fn codes(&self) -> Vec<String> {
+        let mut codes: Vec<String> = self.0.keys().map(|k| k.code.clone()).collect();
+        codes.sort();
+        codes.dedup();
+        codes
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ServeTypeStatsKey {
+    pub rotation: u8,
+    pub player: Uuid,
+    pub serve_type: ServeTypeEnum,
+    pub eval: EvalEnum,
+}
+
+/// Serves split by the type of serve.
+#[derive(Debug, Clone)]
+pub struct ServeTypeStats(pub HashMap<ServeTypeStatsKey, u32>);
+
+impl ServeTypeStats {
+    pub fn new() -> Self {
+        ServeTypeStats(HashMap::new())
+    }
+
+    pub fn add(&mut self, rotation: u8, player: Uuid, serve_type: ServeTypeEnum, eval: EvalEnum) {
+        let key = ServeTypeStatsKey {
+            rotation,
+            player,
+            serve_type,
+            eval,
+        };
+        *self.0.entry(key).or_insert(0) += 1;
+    }
+
+    pub fn merge(&mut self, other: &ServeTypeStats) {
+        for (k, v) in &other.0 {
+            *self.0.entry(k.clone()).or_insert(0) += v;
+        }
+    }
+
+    pub fn query(
+        &self,
+        player: Option<Uuid>,
+        serve_type: Option<ServeTypeEnum>,
+    ) -> impl Iterator<Item = (&ServeTypeStatsKey, &u32)> {
+        self.0.iter().filter(move |(k, _)| {
+            player.is_none_or(|pl| k.player == pl) && serve_type.is_none_or(|t| k.serve_type == t)
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RallyStatsKey {
+    pub phase: PhaseEnum,
+    pub rotation: u8,
+    pub length: RallyLengthEnum,
+    pub won: bool,
+}
+
+/// Completed rallies, by number of contacts of our team.
+#[derive(Debug, Clone)]
+pub struct RallyStats(pub HashMap<RallyStatsKey, u32>);
+
+impl RallyStats {
+    pub fn new() -> Self {
+        RallyStats(HashMap::new())
+    }
+
+    pub fn add(&mut self, phase: PhaseEnum, rotation: u8, length: RallyLengthEnum, won: bool) {
+        let key = RallyStatsKey {
+            phase,
+            rotation,
+            length,
+            won,
+        };
+        *self.0.entry(key).or_insert(0) += 1;
+    }
+
+    pub fn merge(&mut self, other: &RallyStats) {
+        for (k, v) in &other.0 {
+            *self.0.entry(k.clone()).or_insert(0) += v;
+        }
+    }
+
+    pub fn query(
+        &self,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+        length: Option<RallyLengthEnum>,
+    ) -> impl Iterator<Item = (&RallyStatsKey, &u32)> {
+        self.0.iter().filter(move |(k, _)| {
+            phase.is_none_or(|p| k.phase == p)
+                && rotation.is_none_or(|r| k.rotation == r)
+                && length.is_none_or(|l| k.length == l)
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Stats {
+    pub events: EventsStats,
+    pub distribution: DistributionStats,
+    pub errors: ErrorsStats,
+    pub opponent_errors: OpponentErrorsStats,
+    pub possessions: CountStats,
+    pub phases: CountStats,
+    pub attack: AttackStats,
+    pub counter_attack: CounterAttackStats,
+    pub earned_points: PointsStats,
+    pub scored_points: PointsStats,
+    pub first_rally: FirstRallyStats,
+    pub rotation_passes: CountStats,
+    pub reception: ReceptionStats,
+    pub court: CourtStats,
+    pub libero_swaps: LiberoSwapsStats,
+    pub combinations: CombinationStats,
+    pub sets: SetStats,
+    pub freeballs: FreeballStats,
+    pub blocks: BlockStats,
+    pub attack_combinations: AttackCombinationStats,
+    pub serve_types: ServeTypeStats,
+    pub rallies: RallyStats,
+}
+
+impl Stats {
+    pub fn new() -> Self {
+        Stats {
+            events: EventsStats::new(),
+            distribution: DistributionStats::new(),
+            errors: ErrorsStats::new(),
+            opponent_errors: OpponentErrorsStats::new(),
+            possessions: CountStats::new(),
+            phases: CountStats::new(),
+            attack: AttackStats::new(),
+            counter_attack: CounterAttackStats::new(),
+            earned_points: PointsStats::new(),
+            scored_points: PointsStats::new(),
+            first_rally: FirstRallyStats::new(),
+            rotation_passes: CountStats::new(),
+            reception: ReceptionStats::new(),
+            court: CourtStats::new(),
+            libero_swaps: LiberoSwapsStats::new(),
+            combinations: CombinationStats::new(),
+            sets: SetStats::new(),
+            freeballs: FreeballStats::new(),
+            blocks: BlockStats::new(),
+            attack_combinations: AttackCombinationStats::new(),
+            serve_types: ServeTypeStats::new(),
+            rallies: RallyStats::new(),
+        }
+    }
+
+    pub fn merge(&mut self, other: &Stats) {
+        self.attack.merge(&other.attack);
+        self.counter_attack.merge(&other.counter_attack);
+        self.distribution.merge(&other.distribution);
+        self.errors.merge(&other.errors);
+        self.events.merge(&other.events);
+        self.opponent_errors.merge(&other.opponent_errors);
+        self.earned_points.merge(&other.earned_points);
+        self.scored_points.merge(&other.scored_points);
+        self.possessions.merge(&other.possessions);
+        self.phases.merge(&other.phases);
+        self.first_rally.merge(&other.first_rally);
+        self.rotation_passes.merge(&other.rotation_passes);
+        self.reception.merge(&other.reception);
+        self.court.merge(&other.court);
+        self.libero_swaps.merge(&other.libero_swaps);
+        self.combinations.merge(&other.combinations);
+        self.sets.merge(&other.sets);
+        self.freeballs.merge(&other.freeballs);
+        self.blocks.merge(&other.blocks);
+        self.attack_combinations.merge(&other.attack_combinations);
+        self.serve_types.merge(&other.serve_types);
+        self.rallies.merge(&other.rallies);
+    }
+
+    #[allow(dead_code)]
+    pub fn number_of_possessions_per_earned_point(
+        &self,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+    ) -> Option<(f64, u32, u32)> {
+        let total: u32 = self
+            .possessions
+            .query(phase, rotation)
+            .map(|(_, v)| *v)
+            .sum();
+        let count: u32 = self
+            .earned_points
+            .query(phase, rotation)
+            .map(|(_, v)| *v)
+            .sum();
+        if count == 0 {
+            None
+        } else {
+            Some((total as f64 / count as f64, total, count))
+        }
+    }
+
+    pub fn number_of_phases_per_scored_point(
+        &self,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+    ) -> Option<(f64, u32, u32)> {
+        let total: u32 = self.phases.query(phase, rotation).map(|(_, v)| *v).sum();
+        let count: u32 = self
+            .scored_points
+            .query(phase, rotation)
+            .map(|(_, v)| *v)
+            .sum();
+        if count == 0 {
+            None
+        } else {
+            Some((total as f64 / count as f64, total, count))
+        }
+    }
+
+    /// Computes the percentage of rallies won while playing in the given phase.
+    ///
+    /// With `PhaseEnum::SideOut` this is the side-out percentage, with
+    /// `PhaseEnum::Break` the break-point percentage.
+    ///
+    /// # Returns
+    /// - `Some((percentage, total, won))` if at least one rally was played.
+    /// - `None` otherwise.
+    pub fn phase_win_percentage(
+        &self,
+        phase: PhaseEnum,
+        rotation: Option<u8>,
+    ) -> Option<(f64, u32, u32)> {
+        let total: u32 = self
+            .phases
+            .query(Some(phase), rotation)
+            .map(|(_, v)| *v)
+            .sum();
+        let won: u32 = self
+            .scored_points
+            .query(Some(phase), rotation)
+            .map(|(_, v)| *v)
+            .sum();
+        (total > 0).then_some((100.0 * won as f64 / total as f64, total, won))
+    }
+
+    /// Returns the number of rallies won and lost, as `(won, lost)`.
+    pub fn points_won_lost(&self, phase: Option<PhaseEnum>, rotation: Option<u8>) -> (u32, u32) {
+        let total: u32 = self.phases.query(phase, rotation).map(|(_, v)| *v).sum();
+        let won: u32 = self
+            .scored_points
+            .query(phase, rotation)
+            .map(|(_, v)| *v)
+            .sum();
+        (won, total.saturating_sub(won))
+    }
+
+    /// Returns how many times the team went through the given rotation.
+    pub fn rotation_passes(&self, rotation: Option<u8>) -> Option<u32> {
+        let total: u32 = self
+            .rotation_passes
+            .query(None, rotation)
+            .map(|(_, v)| *v)
+            .sum();
+        (total > 0).then_some(total)
+    }
+
+    /// Computes the first-ball side-out percentage: the share of opponent serves
+    /// (excluding their serve errors) that we convert straight away, either with
+    /// a winning attack or with an opponent error right after the reception.
+    ///
+    /// # Returns
+    /// - `Some((percentage, total, won))` if at least one serve was received.
+    /// - `None` otherwise.
+    pub fn first_ball_sideout(&self, rotation: Option<u8>) -> Option<(f64, u32, u32)> {
+        let mut total: u32 = 0;
+        let mut won: u32 = 0;
+        for (key, incr) in self.first_rally.query(rotation, None, None, None, None) {
+            match (
+                key.reception_eval,
+                key.finalizing_event_type,
+                key.finalizing_event_eval,
+            ) {
+                // opponent serve error: no reception at all
+                (None, Some(EventTypeEnum::OE), _) => {}
+                (Some(_), Some(EventTypeEnum::A), Some(EvalEnum::Perfect))
+                | (Some(_), Some(EventTypeEnum::OE), _) => {
+                    total += *incr;
+                    won += *incr;
+                }
+                _ => total += *incr,
+            }
+        }
+        (total > 0).then_some((100.0 * won as f64 / total as f64, total, won))
+    }
+
+    /// Computes the side-out percentage of the rallies following a reception.
+    ///
+    /// # Parameters
+    /// - `player`: Optional filter for a specific receiver.
+    /// - `rotation`: Optional filter for a specific team rotation index (0–5).
+    /// - `eval`: Optional filter for the reception evaluation.
+    ///
+    /// # Returns
+    /// - `Some((percentage, total, won))` if at least one reception matched.
+    /// - `None` otherwise.
+    pub fn reception_sideout_percentage(
+        &self,
+        player: Option<Uuid>,
+        rotation: Option<u8>,
+        eval: Option<EvalEnum>,
+    ) -> Option<(f64, u32, u32)> {
+        let mut total: u32 = 0;
+        let mut won: u32 = 0;
+        for (key, incr) in self.reception.query(rotation, player, eval, None) {
+            total += *incr;
+            if key.sideout {
+                won += *incr;
+            }
+        }
+        (total > 0).then_some((100.0 * won as f64 / total as f64, total, won))
+    }
+
+    /// Computes the kill percentage of the first attack following a reception.
+    ///
+    /// # Parameters
+    /// - `player`: Optional filter for a specific receiver.
+    /// - `rotation`: Optional filter for a specific team rotation index (0–5).
+    /// - `eval`: Optional filter for the reception evaluation.
+    ///
+    /// # Returns
+    /// - `Some((percentage, total, kills))` if at least one attack matched.
+    /// - `None` otherwise.
+    pub fn reception_kill_percentage(
+        &self,
+        player: Option<Uuid>,
+        rotation: Option<u8>,
+        eval: Option<EvalEnum>,
+    ) -> Option<(f64, u32, u32)> {
+        let mut total: u32 = 0;
+        let mut kills: u32 = 0;
+        for (key, incr) in
+            self.first_rally
+                .query(rotation, player, eval, Some(EventTypeEnum::A), None)
+        {
+            total += *incr;
+            if key.finalizing_event_eval == Some(EvalEnum::Perfect) {
+                kills += *incr;
+            }
+        }
+        (total > 0).then_some((100.0 * kills as f64 / total as f64, total, kills))
+    }
+
+    /// Computes the kill percentage of the attacks following a set.
+    ///
+    /// # Parameters
+    /// - `player`: Optional filter for a specific setter.
+    /// - `phase`: Optional filter for a specific phase of play.
+    /// - `rotation`: Optional filter for a specific team rotation index (0–5).
+    /// - `eval`: Optional filter for the set evaluation.
+    ///
+    /// # Returns
+    /// - `Some((percentage, total, kills))` if at least one attack matched.
+    /// - `None` otherwise.
+    pub fn set_kill_percentage(
+        &self,
+        player: Option<Uuid>,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+        eval: Option<EvalEnum>,
+    ) -> Option<(f64, u32, u32)> {
+        let mut total: u32 = 0;
+        let mut kills: u32 = 0;
+        for (key, incr) in self.sets.query(phase, rotation, player, eval) {
+            total += *incr;
+            if key.attack_eval == EvalEnum::Perfect {
+                kills += *incr;
+            }
+        }
+        (total > 0).then_some((100.0 * kills as f64 / total as f64, total, kills))
+    }
+
+    /// Computes the percentage of rallies won after sending a free ball.
+    ///
+    /// # Returns
+    /// - `Some((percentage, total, won))` if at least one free ball matched.
+    /// - `None` otherwise.
+    pub fn freeball_won_percentage(
+        &self,
+        player: Option<Uuid>,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+        eval: Option<EvalEnum>,
+    ) -> Option<(f64, u32, u32)> {
+        let mut total: u32 = 0;
+        let mut won: u32 = 0;
+        for (key, incr) in self.freeballs.query(phase, rotation, player, eval) {
+            total += *incr;
+            if key.won {
+                won += *incr;
+            }
+        }
+        (total > 0).then_some((100.0 * won as f64 / total as f64, total, won))
+    }
+
+    /// Summarizes the attacks run with a combination, optionally after a given pass quality.
+    ///
+    /// # Returns
+    /// - `Some((total, kill percentage, efficiency))` if at least one attack matched.
+    /// - `None` otherwise.
+    pub fn attack_combination_summary(
+        &self,
+        code: Option<&str>,
+        player: Option<Uuid>,
+        pass_eval: Option<EvalEnum>,
+    ) -> Option<(u32, f64, f64)> {
+        let mut total: u32 = 0;
+        let mut score: i32 = 0;
+        let mut kills: u32 = 0;
+        for (key, incr) in self.attack_combinations.query(code, player, pass_eval) {
+            total += *incr;
+            if key.eval == EvalEnum::Perfect {
+                kills += *incr;
+            }
+            score += Metric::Efficiency.score(EventTypeEnum::A, &key.eval) * (*incr as i32);
+        }
+        (total > 0).then_some((
+            total,
+            100.0 * kills as f64 / total as f64,
+            100.0 * score as f64 / total as f64,
+        ))
+    }
+
+    /// Counts the blocks matching the filters; a player matches every block they took part in.
+    pub fn block_count(
+        &self,
+        player: Option<Uuid>,
+        rotation: Option<u8>,
+        zone: Option<ZoneEnum>,
+        composition: Option<BlockCompositionEnum>,
+        eval: Option<EvalEnum>,
+    ) -> Option<u32> {
+        let total: u32 = self
+            .blocks
+            .query(player, rotation, zone, composition, eval)
+            .map(|(_, incr)| *incr)
+            .sum();
+        (total > 0).then_some(total)
+    }
+
+    /// Counts the blocks matching the filters that touched the ball.
+    pub fn block_touch_count(
+        &self,
+        player: Option<Uuid>,
+        rotation: Option<u8>,
+        zone: Option<ZoneEnum>,
+    ) -> Option<u32> {
+        let total: u32 = self
+            .blocks
+            .query(player, rotation, zone, None, None)
+            .filter(|(key, _)| key.touched)
+            .map(|(_, incr)| *incr)
+            .sum();
+        (total > 0).then_some(total)
+    }
+
+    /// Returns the rallies played by a player while on court, as `(won, lost)`.
+    pub fn on_court_won_lost(
+        &self,
+        player: Uuid,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+    ) -> Option<(u32, u32)> {
+        let mut won: u32 = 0;
+        let mut lost: u32 = 0;
+        for (key, incr) in self.court.query(phase, rotation, Some(player), None) {
+            if key.won {
+                won += *incr;
+            } else {
+                lost += *incr;
+            }
+        }
+        (won + lost > 0).then_some((won, lost))
+    }
+
+    /// Returns how many times the given libero entered the court.
+    pub fn libero_swaps(&self, player: Uuid, rotation: Option<u8>) -> Option<u32> {
+        let total: u32 = self
+            .libero_swaps
+            .query(rotation, Some(player))
+            .map(|(_, v)| *v)
+            .sum();
+        (total > 0).then_some(total)
+    }
+
+    /// Returns `(players, won, lost)` for every six-player combination that played at least one rally.
+    pub fn combinations_won_lost(&self, phase: Option<PhaseEnum>) -> Vec<([Uuid; 6], u32, u32)> {
+        let mut map: HashMap<[Uuid; 6], (u32, u32)> = HashMap::new();
+        for (key, incr) in self.combinations.query(phase, None, None) {
+            let entry = map.entry(key.players).or_insert((0, 0));
+            if key.won {
+                entry.0 += *incr;
+            } else {
+                entry.1 += *incr;
+            }
+        }
+        map.into_iter().map(|(k, (w, l))| (k, w, l)).collect()
+    }
+
+    /// Returns `(players, won, lost)` for every pair of players that shared the court, derived from the combinations.
+    pub fn pairs_won_lost(&self, phase: Option<PhaseEnum>) -> Vec<([Uuid; 2], u32, u32)> {
+        let mut map: HashMap<[Uuid; 2], (u32, u32)> = HashMap::new();
+        for (players, won, lost) in self.combinations_won_lost(phase) {
+            for i in 0..players.len() {
+                for j in (i + 1)..players.len() {
+                    let entry = map.entry([players[i], players[j]]).or_insert((0, 0));
+                    entry.0 += won;
+                    entry.1 += lost;
+                }
+            }
+        }
+        map.into_iter().map(|(k, (w, l))| (k, w, l)).collect()
+    }
+
+    #[allow(dead_code)]
+    pub fn attack_efficiency(
+        &self,
+        player: Option<Uuid>,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+        zone: Option<ZoneEnum>,
+        prev_eval: EvalEnum,
+    ) -> Option<(f64, u32, i32)> {
+        let mut count: i32 = 0;
+        let mut total: u32 = 0;
+        for (key, inc) in self
+            .attack
+            .query(phase, rotation, player, zone, None, Some(prev_eval))
+        {
+            total += *inc;
+
+            let score = match key.eval {
+                EvalEnum::Perfect => 1,
+                EvalEnum::Error | EvalEnum::Over => -1,
+                _ => 0,
+            };
+            count += score * (*inc as i32);
+        }
+        (total > 0).then_some(((count as f64) / (total as f64) * 100.0, total, count))
+    }
+
+    pub fn counter_attack_conversion_rate(
+        &self,
+        player: Option<Uuid>,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+        zone: Option<ZoneEnum>,
+    ) -> Option<(f64, u32, u32)> {
+        let mut total: u32 = 0;
+        let mut count: u32 = 0;
+        for (key, incr) in self
+            .counter_attack
+            .query(phase, rotation, player, zone, None)
+        {
+            total += *incr;
+            if key.eval == EvalEnum::Perfect {
+                count += *incr;
+            }
+        }
+        if total == 0 {
+            None
+        } else {
+            Some((100.0 * count as f64 / total as f64, total, count))
+        }
+    }
+
+    pub fn scored_points(
+        &self,
+        event_type: EventTypeEnum,
+        player: Option<Uuid>,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+        zone: Option<ZoneEnum>,
+    ) -> Option<u32> {
+        if event_type.provides_direct_points() {
+            self.event_count(
+                event_type,
+                player,
+                phase,
+                rotation,
+                zone,
+                Some(EvalEnum::Perfect),
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn total_scored_points(
+        &self,
+        player: Option<Uuid>,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+        zone: Option<ZoneEnum>,
+    ) -> Option<u32> {
+        let serve_points = self.scored_points(EventTypeEnum::S, player, phase, rotation, zone);
+        let attack_points = self.scored_points(EventTypeEnum::A, player, phase, rotation, zone);
+        let block_points = self.scored_points(EventTypeEnum::B, player, phase, rotation, zone);
+        let total =
+            serve_points.unwrap_or(0) + attack_points.unwrap_or(0) + block_points.unwrap_or(0);
+        (total > 0).then_some(total)
     }
 
     pub fn errors(
@@ -763,7 +1694,7 @@ impl Stats {
         use EventTypeEnum::*;
         let evals = match event_type {
             A | B => vec![Error, Over],
-            P | D | S => vec![Error],
+            P | D | S | E | X => vec![Error],
             _ => vec![],
         };
         evals
@@ -792,12 +1723,17 @@ impl Stats {
         let dig_errors = self.errors(EventTypeEnum::D, error_type, player, phase, rotation, zone);
         let serve_errors = self.errors(EventTypeEnum::S, error_type, player, phase, rotation, zone);
         let block_errors = self.errors(EventTypeEnum::B, error_type, player, phase, rotation, zone);
+        let set_errors = self.errors(EventTypeEnum::E, error_type, player, phase, rotation, zone);
+        let freeball_errors =
+            self.errors(EventTypeEnum::X, error_type, player, phase, rotation, zone);
         let faults = self.event_count(EventTypeEnum::F, player, phase, rotation, zone, None);
         let total = pass_errors.unwrap_or(0)
             + attack_errors.unwrap_or(0)
             + dig_errors.unwrap_or(0)
             + serve_errors.unwrap_or(0)
-            + block_errors.unwrap_or(0);
+            + block_errors.unwrap_or(0)
+            + set_errors.unwrap_or(0)
+            + freeball_errors.unwrap_or(0);
         let total = if error_type != Some(ErrorTypeEnum::Forced) {
             total
         } else {
@@ -892,6 +1828,44 @@ impl Stats {
         (total > 0).then_some(((score as f64) / (total as f64) * 100.0, total, score))
     }
 
+    /// Same as [`Stats::event_positiveness`] for the serves of a given type.
+    pub fn serve_type_positiveness(
+        &self,
+        player: Option<Uuid>,
+        serve_type: Option<ServeTypeEnum>,
+        metric: Metric,
+    ) -> Option<(f64, u32, i32)> {
+        let mut score: i32 = 0;
+        let mut total: u32 = 0;
+        for (key, incr) in self.serve_types.query(player, serve_type) {
+            total += *incr;
+            score += metric.score(EventTypeEnum::S, &key.eval) * (*incr as i32);
+        }
+        (total > 0).then_some(((score as f64) / (total as f64) * 100.0, total, score))
+    }
+
+    /// Counts the completed rallies matching the filters and the percentage won.
+    ///
+    /// # Returns
+    /// - `Some((percentage, total, won))` if at least one rally matched.
+    /// - `None` otherwise.
+    pub fn rally_won_perc(
+        &self,
+        phase: Option<PhaseEnum>,
+        rotation: Option<u8>,
+        length: Option<RallyLengthEnum>,
+    ) -> Option<(f64, u32, u32)> {
+        let mut total: u32 = 0;
+        let mut won: u32 = 0;
+        for (key, incr) in self.rallies.query(phase, rotation, length) {
+            total += *incr;
+            if key.won {
+                won += *incr;
+            }
+        }
+        (total > 0).then_some((100.0 * won as f64 / total as f64, total, won))
+    }
+
     pub fn sideout_first_rally_positiveness(
         &self,
         rotation: Option<u8>,
@@ -903,7 +1877,7 @@ impl Stats {
         let mut total: u32 = 0;
         for (key, incr) in
             self.first_rally
-                .query(rotation, reception_eval, finalizing_event_type, None)
+                .query(rotation, None, reception_eval, finalizing_event_type, None)
         {
             total += *incr;
             let contrib = if let (Some(final_type), Some(final_eval)) = (
@@ -953,6 +1927,7 @@ impl Stats {
         let mut total = 0;
         for (_key, incr) in self.first_rally.query(
             rotation,
+            None,
             reception_eval,
             finalizing_event_type,
             finalizing_event_eval,
@@ -977,6 +1952,7 @@ impl Stats {
         let mut total_errors: u32 = 0;
         for (key, incr) in self.first_rally.query(
             rotation,
+            None,
             reception_eval,
             finalizing_event_type,
             finalizing_event_eval,
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Splits free text into tags: words separated by spaces or commas, lowercase, without the
+/// leading `#` and without duplicates.
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = vec![];
+    for tag in input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .map(|t| t.trim_start_matches('#').to_lowercase())
+        .filter(|t| !t.is_empty())
+    {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+pub fn format_tags(tags: &[String]) -> String {
+    tags.iter()
+        .map(|t| format!("#{}", t))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Tags are stored as a single space separated column in the events CSV files.
+pub mod csv_tags {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(tags: &[String], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&tags.join(" "))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<String>, D::Error> {
+        let value: Option<String> = Option::deserialize(deserializer)?;
+        Ok(value.map(|v| parse_tags(&v)).unwrap_or_default())
+    }
+}
@@ -1,10 +1,57 @@
 use crate::shapes::{
-    enums::{GenderEnum, TeamClassificationEnum},
+    enums::{GenderEnum, RoleEnum, TeamClassificationEnum, TempoEnum, ZoneEnum},
     player::PlayerEntry,
 };
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// A player's jersey number and role for a given season.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RosterEntry {
+    pub player: Uuid,
+    pub number: u8,
+    pub role: Option<RoleEnum>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SeasonEntry {
+    pub year: u16,
+    pub roster: Vec<RosterEntry>,
+}
+
+/// A named attack combination of the team's offensive system (e.g. quick in front, pipe).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AttackCombinationEntry {
+    pub code: String,
+    pub tempo: TempoEnum,
+    pub zone: ZoneEnum,
+    pub role: Option<RoleEnum>, // expected attacker
+}
+
+/// A named starting lineup, to be picked at the start of a set instead of each player.
+///
+/// The fallback libero is still picked when the set starts.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LineupTemplateEntry {
+    pub name: String,
+    pub positions: [Uuid; 6],
+    pub setter: Uuid,
+    pub libero: Uuid,
+}
+
+impl LineupTemplateEntry {
+    /// The same lineup after the given number of rotations.
+    pub fn rotated(&self, rotations: usize) -> LineupTemplateEntry {
+        let mut positions = self.positions;
+        // each rotation moves the player in position 2 to position 1
+        positions.rotate_left(rotations % 6);
+        LineupTemplateEntry {
+            positions,
+            ..self.clone()
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct TeamEntry {
     pub name: String,
@@ -16,6 +63,12 @@ pub struct TeamEntry {
     pub classification: Option<TeamClassificationEnum>,
     #[serde(default)]
     pub gender: Option<GenderEnum>,
+    #[serde(default)]
+    pub seasons: Vec<SeasonEntry>,
+    #[serde(default)]
+    pub combinations: Vec<AttackCombinationEntry>,
+    #[serde(default)]
+    pub lineups: Vec<LineupTemplateEntry>,
 }
 
 impl TeamEntry {
@@ -25,4 +78,56 @@ impl TeamEntry {
     pub fn active_players(&self) -> Vec<&PlayerEntry> {
         self.players.iter().filter(|p| !p.deleted).collect()
     }
+
+    /// Looks up an attack combination by code, ignoring case.
+    pub fn find_combination(&self, code: &str) -> Option<&AttackCombinationEntry> {
+        self.combinations
+            .iter()
+            .find(|c| c.code.eq_ignore_ascii_case(code))
+    }
+
+    /// Stores the active players as the roster of the current season; rosters of past
+    /// seasons are left untouched.
+    pub fn sync_current_season(&mut self) {
+        let roster = self
+            .active_players()
+            .iter()
+            .map(|p| RosterEntry {
+                player: p.id,
+                number: p.number,
+                role: p.role,
+            })
+            .collect();
+        match self.seasons.iter_mut().find(|s| s.year == self.year) {
+            Some(season) => season.roster = roster,
+            None => {
+                self.seasons.push(SeasonEntry {
+                    year: self.year,
+                    roster,
+                });
+                self.seasons.sort_by_key(|s| s.year);
+            }
+        }
+    }
+
+    /// Returns the players as they were in the given season: numbers and roles come from
+    /// that season's roster and players outside of it are marked as deleted. Unknown seasons
+    /// fall back to the current players.
+    pub fn season_players(&self, year: Option<u16>) -> Vec<PlayerEntry> {
+        let mut players = self.players.clone();
+        let Some(season) = year.and_then(|y| self.seasons.iter().find(|s| s.year == y)) else {
+            return players;
+        };
+        for player in players.iter_mut() {
+            match season.roster.iter().find(|r| r.player == player.id) {
+                Some(entry) => {
+                    player.number = entry.number;
+                    player.role = entry.role;
+                    player.deleted = false;
+                }
+                None => player.deleted = true,
+            }
+        }
+        players
+    }
 }
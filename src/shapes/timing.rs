@@ -0,0 +1,78 @@
+use crate::{constants::LONG_BREAK_SECONDS, shapes::rally::RallyEntry};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A pause between two rallies long enough to be a timeout, an injury or a similar stop.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LongBreakEntry {
+    pub start: DateTime<Utc>,
+    pub seconds: i64,
+    /// Score when the break started (us, them).
+    pub score: (u8, u8),
+}
+
+/// Timing of a set, derived from the timestamps of its events.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetTimingEntry {
+    pub set_number: u8,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub duration_seconds: i64,
+    /// Sum of the rally durations, from the first to the last event of each rally.
+    pub playing_seconds: i64,
+    pub avg_between_rallies_seconds: Option<f64>,
+    pub long_breaks: Vec<LongBreakEntry>,
+}
+
+impl SetTimingEntry {
+    /// Computes the timing of a set from its rallies, in order; `None` if no event was scouted.
+    pub fn from_rallies(set_number: u8, rallies: &[RallyEntry]) -> Option<Self> {
+        let start = rallies.first()?.events.first()?.timestamp;
+        let end = rallies.last()?.events.last()?.timestamp;
+        let playing_seconds = rallies
+            .iter()
+            .filter_map(|r| r.duration())
+            .map(|d| d.num_seconds())
+            .sum();
+        let mut gaps = vec![];
+        let mut long_breaks = vec![];
+        for pair in rallies.windows(2) {
+            let (Some(previous_end), Some(next_start)) =
+                (pair[0].events.last(), pair[1].events.first())
+            else {
+                continue;
+            };
+            let seconds = (next_start.timestamp - previous_end.timestamp).num_seconds();
+            if seconds > LONG_BREAK_SECONDS {
+                long_breaks.push(LongBreakEntry {
+                    start: previous_end.timestamp,
+                    seconds,
+                    score: pair[0].score,
+                });
+            } else {
+                gaps.push(seconds);
+            }
+        }
+        // long breaks would skew the pace of play, so they are left out of the average
+        let avg_between_rallies_seconds =
+            (!gaps.is_empty()).then(|| gaps.iter().sum::<i64>() as f64 / gaps.len() as f64);
+        Some(SetTimingEntry {
+            set_number,
+            start,
+            end,
+            duration_seconds: (end - start).num_seconds(),
+            playing_seconds,
+            avg_between_rallies_seconds,
+            long_breaks,
+        })
+    }
+}
+
+/// Formats a number of seconds as `m:ss`, or `h:mm:ss` from one hour up.
+pub fn format_seconds(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    match (seconds / 3600, seconds % 3600 / 60, seconds % 60) {
+        (0, m, s) => format!("{}:{:02}", m, s),
+        (h, m, s) => format!("{}:{:02}:{:02}", h, m, s),
+    }
+}
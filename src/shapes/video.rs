@@ -0,0 +1,136 @@
+use crate::{
+    constants::{CLIP_POST_ROLL_SECONDS, CLIP_PRE_ROLL_SECONDS},
+    shapes::{
+        enums::{EvalEnum, EventTypeEnum},
+        r#match::MatchEntry,
+        snapshot::EventEntry,
+    },
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// The video recording of a set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VideoEntry {
+    pub file: PathBuf,
+    /// Wall-clock time matching the beginning of the video, so that event timestamps can be
+    /// turned into video positions.
+    pub started_at: DateTime<Utc>,
+}
+
+impl VideoEntry {
+    pub fn new(file: PathBuf, started_at: DateTime<Utc>) -> Self {
+        VideoEntry { file, started_at }
+    }
+
+    /// Position of the given moment into the video, in seconds; negative if it happened before
+    /// the recording started.
+    pub fn position(&self, timestamp: DateTime<Utc>) -> f64 {
+        (timestamp - self.started_at).num_milliseconds() as f64 / 1000.0
+    }
+
+    /// Aligns the video to the events, given the position where a known event can be seen.
+    pub fn sync(&mut self, event: &EventEntry, position_seconds: f64) {
+        self.started_at =
+            event.timestamp - Duration::milliseconds((position_seconds * 1000.0).round() as i64);
+    }
+}
+
+/// Which events to cut out of the videos; unset criteria match every event.
+#[derive(Debug, Clone, Default)]
+pub struct ClipFilter {
+    pub player: Option<Uuid>,
+    pub event_type: Option<EventTypeEnum>,
+    pub eval: Option<EvalEnum>,
+}
+
+impl ClipFilter {
+    pub fn matches(&self, event: &EventEntry) -> bool {
+        self.player.is_none_or(|p| event.player == Some(p))
+            && self.event_type.is_none_or(|t| event.event_type == t)
+            && self.eval.is_none_or(|e| event.eval == Some(e))
+    }
+}
+
+/// A portion of a video, in seconds from its beginning.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClipEntry {
+    pub file: PathBuf,
+    pub start: f64,
+    pub end: f64,
+    pub label: String,
+}
+
+impl ClipEntry {
+    pub fn duration(&self) -> f64 {
+        self.end - self.start
+    }
+}
+
+/// Clips of the events matching the filter, in match order, from the sets having a video.
+///
+/// Clips of close events overlap, so they are merged into a single one.
+pub fn build_clips(m: &MatchEntry, filter: &ClipFilter) -> Vec<ClipEntry> {
+    let mut clips: Vec<ClipEntry> = vec![];
+    for set in &m.sets {
+        let Some(video) = &set.video else {
+            continue;
+        };
+        for event in set.events.iter().filter(|e| filter.matches(e)) {
+            let position = video.position(event.timestamp);
+            if position < 0.0 {
+                continue;
+            }
+            let label = clip_label(m, set.set_number, event);
+            let start = (position - CLIP_PRE_ROLL_SECONDS).max(0.0);
+            let end = position + CLIP_POST_ROLL_SECONDS;
+            match clips.last_mut() {
+                Some(last) if last.file == video.file && start <= last.end => {
+                    last.end = last.end.max(end);
+                    last.label = format!("{}, {}", last.label, label);
+                }
+                _ => clips.push(ClipEntry {
+                    file: video.file.clone(),
+                    start,
+                    end,
+                    label,
+                }),
+            }
+        }
+    }
+    clips
+}
+
+fn clip_label(m: &MatchEntry, set_number: u8, event: &EventEntry) -> String {
+    let player = event
+        .player
+        .and_then(|p| m.find_player(p))
+        .map(|p| format!(" {} {}", p.number, p.name))
+        .unwrap_or_default();
+    let eval = event.eval.map(|e| format!(" {}", e)).unwrap_or_default();
+    format!("set {}{} {}{}", set_number, player, event.event_type, eval)
+}
+
+/// Parses a video position written as `s`, `m:ss` or `h:mm:ss`, seconds possibly with decimals.
+pub fn parse_position(input: &str) -> Option<f64> {
+    let parts: Vec<&str> = input.trim().split(':').collect();
+    if parts.is_empty() || parts.len() > 3 {
+        return None;
+    }
+    let (seconds, others) = parts.split_last()?;
+    let seconds: f64 = seconds.parse().ok().filter(|s: &f64| *s >= 0.0)?;
+    if !others.is_empty() && seconds >= 60.0 {
+        return None;
+    }
+    let mut total = seconds;
+    for (i, part) in others.iter().rev().enumerate() {
+        let value: u32 = part.parse().ok()?;
+        if i == 0 && others.len() == 2 && value >= 60 {
+            return None;
+        }
+        total += value as f64 * 60f64.powi(i as i32 + 1);
+    }
+    Some(total)
+}
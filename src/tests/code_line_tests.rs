@@ -0,0 +1,215 @@
+#[cfg(test)]
+mod tests {
+    use crate::shapes::{
+        code_line::{parse_code, parse_code_line, resolve_code_line},
+        enums::{EvalEnum, EventTypeEnum, LineupWarningEnum, TeamSideEnum, TempoEnum, ZoneEnum},
+        player::PlayerEntry,
+        set::SetEntry,
+        snapshot::Snapshot,
+        team::{AttackCombinationEntry, TeamEntry},
+    };
+
+    // players numbered 1 to 6 in the starting positions, 7 is the libero, 8 on the bench
+    fn make_team() -> TeamEntry {
+        TeamEntry {
+            name: "test".to_string(),
+            players: (1..=8)
+                .map(|number| PlayerEntry {
+                    name: format!("player {}", number),
+                    number,
+                    ..Default::default()
+                })
+                .collect(),
+            combinations: vec![AttackCombinationEntry {
+                code: "X1".to_string(),
+                tempo: TempoEnum::Quick,
+                zone: ZoneEnum::Three,
+                role: None,
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn make_snapshot(team: &TeamEntry) -> (Snapshot, Vec<EventTypeEnum>) {
+        let ids: Vec<_> = team.players.iter().map(|p| p.id).collect();
+        let positions = [ids[0], ids[1], ids[2], ids[3], ids[4], ids[5]];
+        SetEntry::new(1, TeamSideEnum::Us, positions, ids[6], None, ids[0])
+            .expect("expected a valid set")
+            .compute_snapshot()
+            .expect("expected a valid snapshot")
+    }
+
+    #[test]
+    fn parse_codes() {
+        let code = parse_code("7p+").expect("expected a valid code");
+        assert_eq!(code.event_type, EventTypeEnum::P);
+        assert_eq!(code.numbers, vec![7]);
+        assert_eq!(code.eval, Some(EvalEnum::Positive));
+        let code = parse_code("12ax1#").expect("expected a valid code");
+        assert_eq!(code.event_type, EventTypeEnum::A);
+        assert_eq!(code.combination.as_deref(), Some("X1"));
+        assert_eq!(code.eval, Some(EvalEnum::Perfect));
+        let code = parse_code("4.9BT-").expect("expected a valid code");
+        assert_eq!(code.numbers, vec![4, 9]);
+        assert!(code.touched);
+        let code = parse_code("3R15").expect("expected a valid code");
+        assert_eq!((code.numbers, code.replacement), (vec![3], Some(15)));
+        let code = parse_code("S#").expect("expected a valid code");
+        assert!(code.numbers.is_empty());
+        assert_eq!(
+            parse_code("5CS").map(|c| c.event_type),
+            Ok(EventTypeEnum::CS)
+        );
+        assert_eq!(
+            parse_code("oe").map(|c| c.event_type),
+            Ok(EventTypeEnum::OE)
+        );
+        assert_eq!(
+            parse_code("MT").map(|c| c.awarded_to),
+            Ok(Some(TeamSideEnum::Them))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_codes() {
+        // missing evaluation, evaluation not available, missing player
+        assert!(parse_code("7P").is_err());
+        assert!(parse_code("7B!").is_err());
+        assert!(parse_code("P+").is_err());
+        // unknown event, trailing characters, players not expected
+        assert!(parse_code("7Q+").is_err());
+        assert!(parse_code("7P+x").is_err());
+        assert!(parse_code("4OE").is_err());
+        assert!(parse_code("4.9A#").is_err());
+        let error = parse_code_line("S# 7Z").expect_err("expected an invalid line");
+        assert_eq!(error.token, 1);
+    }
+
+    #[test]
+    fn resolve_rally() {
+        let team = make_team();
+        let (snapshot, options) = make_snapshot(&team);
+        let ids: Vec<_> = team.players.iter().map(|p| p.id).collect();
+        let events = resolve_code_line(
+            "S- 2D+ 1E+ 4aX1#",
+            &team,
+            &team.players,
+            &snapshot,
+            &options,
+            1,
+        )
+        .expect("expected a valid line");
+        assert_eq!(events.len(), 4);
+        // the server is the player in position 1
+        assert_eq!(events[0].event.player, Some(ids[0]));
+        assert_eq!(events[1].event.player, Some(ids[1]));
+        assert_eq!(events[3].event.player, Some(ids[3]));
+        assert_eq!(events[3].event.combination.as_deref(), Some("X1"));
+        assert!(events.iter().all(|e| e.warnings.is_empty()));
+        // the snapshot itself is left untouched
+        assert_eq!((snapshot.score_us, snapshot.score_them), (0, 0));
+    }
+
+    #[test]
+    fn resolve_increasing_timestamps() {
+        let team = make_team();
+        let (mut snapshot, options) = make_snapshot(&team);
+        let events = resolve_code_line(
+            "S- 2D+ 1E+ 4A#",
+            &team,
+            &team.players,
+            &snapshot,
+            &options,
+            1,
+        )
+        .expect("expected a valid line");
+        assert!(events
+            .windows(2)
+            .all(|w| w[0].event.timestamp < w[1].event.timestamp));
+        // the next line starts after the events already added
+        let mut options = options;
+        for e in events.iter() {
+            options = snapshot
+                .add_event(&e.event, &options)
+                .expect("expected a valid event");
+        }
+        let next = resolve_code_line("S+", &team, &team.players, &snapshot, &options, 1)
+            .expect("expected a valid line");
+        let last = events.last().map(|e| e.event.timestamp);
+        assert!(next.iter().all(|e| Some(e.event.timestamp) > last));
+    }
+
+    #[test]
+    fn resolve_invalid_lines() {
+        let team = make_team();
+        let (snapshot, options) = make_snapshot(&team);
+        // reception is not available while serving
+        let error = resolve_code_line("2P+", &team, &team.players, &snapshot, &options, 1)
+            .expect_err("expected an invalid line");
+        assert_eq!(error.token, 0);
+        // 8 is on the bench
+        let error = resolve_code_line("S- 8D+", &team, &team.players, &snapshot, &options, 1)
+            .expect_err("expected an invalid line");
+        assert_eq!(error.token, 1);
+        // the libero cannot block
+        let error = resolve_code_line("S- 7B+", &team, &team.players, &snapshot, &options, 1)
+            .expect_err("expected an invalid line");
+        assert_eq!(error.token, 1);
+        // unknown combination
+        let error = resolve_code_line("S- 2D+ 4AZ9#", &team, &team.players, &snapshot, &options, 1)
+            .expect_err("expected an invalid line");
+        assert_eq!(error.token, 2);
+    }
+
+    #[test]
+    fn resolve_substitution_and_warnings() {
+        let team = make_team();
+        let (snapshot, options) = make_snapshot(&team);
+        let ids: Vec<_> = team.players.iter().map(|p| p.id).collect();
+        let events = resolve_code_line("4R8 3S+", &team, &team.players, &snapshot, &options, 1)
+            .expect("expected a valid line");
+        assert_eq!(events[0].event.player, Some(ids[3]));
+        assert_eq!(events[0].event.target_player, Some(ids[7]));
+        // the player in position 1 was expected to serve
+        assert_eq!(events[1].event.player, Some(ids[2]));
+        assert_eq!(events[1].warnings, vec![LineupWarningEnum::WrongServer]);
+        // tagged only once accepted
+        assert!(events[1].event.tags.is_empty());
+        // 4 already left the court
+        let error = resolve_code_line("4R8 4R8", &team, &team.players, &snapshot, &options, 1)
+            .expect_err("expected an invalid line");
+        assert_eq!(error.token, 1);
+    }
+
+    #[test]
+    fn resolve_substitution_in_past_season() {
+        let mut team = make_team();
+        let ids: Vec<_> = team.players.iter().map(|p| p.id).collect();
+        // in 2024 the bench player wore 15, in 2025 they took 8 and a newcomer took 15
+        team.year = 2024;
+        team.players[7].number = 15;
+        team.sync_current_season();
+        team.year = 2025;
+        team.players[7].number = 8;
+        let newcomer = PlayerEntry {
+            name: "newcomer".to_string(),
+            number: 15,
+            ..Default::default()
+        };
+        team.players.push(newcomer.clone());
+        team.sync_current_season();
+        let (snapshot, options) = make_snapshot(&team);
+        let past = team.season_players(Some(2024));
+        let events = resolve_code_line("3R15", &team, &past, &snapshot, &options, 1)
+            .expect("expected a valid line");
+        assert_eq!(events[0].event.target_player, Some(ids[7]));
+        let replacements = snapshot
+            .current_lineup
+            .get_available_replacements(&past, ids[2]);
+        assert!(replacements.iter().all(|(_, p)| p.id != newcomer.id));
+        // with the current roster 15 is the newcomer
+        let events = resolve_code_line("3R15", &team, &team.players, &snapshot, &options, 1)
+            .expect("expected a valid line");
+        assert_eq!(events[0].event.target_player, Some(newcomer.id));
+    }
+}
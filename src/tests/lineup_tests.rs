@@ -1,10 +1,12 @@
 #[cfg(test)]
 mod tests {
     use crate::shapes::{
-        enums::{RoleEnum, TeamSideEnum},
+        enums::{EventTypeEnum, LineupWarningEnum, RoleEnum, TeamSideEnum, ZoneEnum},
         set::SetEntry,
         snapshot::Snapshot,
+        team::{LineupTemplateEntry, TeamEntry},
     };
+    use crate::tests::fixtures::event;
     use uuid::Uuid;
 
     fn make_empty_snapshot(
@@ -184,4 +186,131 @@ mod tests {
         assert!(!snapshot.current_lineup.is_back_row_player(&mb2));
         assert!(snapshot.current_lineup.is_back_row_player(&oh1));
     }
+
+    #[test]
+    fn check_event() {
+        let lineup: [Uuid; 6] = [
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let libero = Uuid::new_v4();
+        let snapshot = make_empty_snapshot(1, TeamSideEnum::Us, lineup, lineup[0], libero);
+        let current = &snapshot.current_lineup;
+        assert!(current
+            .check_event(&event(EventTypeEnum::S, lineup[0], None), None)
+            .is_empty());
+        assert_eq!(
+            current.check_event(&event(EventTypeEnum::S, lineup[1], None), None),
+            vec![LineupWarningEnum::WrongServer]
+        );
+        // position 1 is a back row position
+        assert_eq!(
+            current.check_event(&event(EventTypeEnum::B, lineup[0], None), None),
+            vec![LineupWarningEnum::BackRowBlock]
+        );
+        assert!(current
+            .check_event(&event(EventTypeEnum::B, lineup[2], None), None)
+            .is_empty());
+        assert_eq!(
+            current.check_event(
+                &event(EventTypeEnum::A, lineup[0], None),
+                Some(ZoneEnum::Four)
+            ),
+            vec![LineupWarningEnum::BackRowAttack]
+        );
+        assert!(current
+            .check_event(
+                &event(EventTypeEnum::A, lineup[0], None),
+                Some(ZoneEnum::One)
+            )
+            .is_empty());
+        // without a combination the attack is assumed to be at the net
+        assert_eq!(
+            current.check_event(&event(EventTypeEnum::A, lineup[0], None), None),
+            vec![LineupWarningEnum::BackRowAttack]
+        );
+        assert!(current
+            .check_event(&event(EventTypeEnum::A, lineup[2], None), None)
+            .is_empty());
+        assert_eq!(
+            current.check_event(&event(EventTypeEnum::A, libero, None), Some(ZoneEnum::Two)),
+            vec![LineupWarningEnum::LiberoAttack]
+        );
+    }
+
+    #[test]
+    fn swap_initial_players() {
+        let lineup: [Uuid; 6] = [
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let libero = Uuid::new_v4();
+        let mut set = SetEntry::new(1, TeamSideEnum::Us, lineup, libero, None, lineup[0])
+            .expect("expected a valid set");
+        assert!(set.swap_initial_players(lineup[0], lineup[1]));
+        assert_eq!(set.initial_positions[0], lineup[1]);
+        assert_eq!(set.initial_positions[1], lineup[0]);
+        assert!(!set.swap_initial_players(lineup[0], Uuid::new_v4()));
+        let mut replacement = event(EventTypeEnum::R, lineup[2], None);
+        replacement.target_player = Some(Uuid::new_v4());
+        set.events.push(replacement);
+        assert!(!set.swap_initial_players(lineup[2], lineup[3]));
+    }
+
+    #[test]
+    fn lineup_template_rotation() {
+        let positions: [Uuid; 6] = [
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let template = LineupTemplateEntry {
+            name: "standard 5-1".to_string(),
+            positions,
+            setter: positions[0],
+            libero: Uuid::new_v4(),
+        };
+        let rotated = template.rotated(1);
+        // the player in position 2 serves next
+        assert_eq!(rotated.positions[0], positions[1]);
+        assert_eq!(rotated.positions[5], positions[0]);
+        assert_eq!(rotated.setter, template.setter);
+        assert_eq!(template.rotated(6), template);
+        // the setter moves like in the snapshot of a set started from the rotated lineup
+        let set = SetEntry::new(
+            1,
+            TeamSideEnum::Us,
+            template.rotated(2).positions,
+            template.libero,
+            None,
+            template.setter,
+        )
+        .expect("expected a valid set");
+        assert_eq!(
+            Snapshot::new(&set)
+                .expect("expected a valid snapshot")
+                .current_lineup
+                .get(4),
+            Some(template.setter)
+        );
+    }
+
+    #[test]
+    fn lineup_templates_default_to_empty() {
+        let team: TeamEntry =
+            serde_json::from_str(r#"{"name": "team", "year": 2024, "players": []}"#)
+                .expect("expected a valid team");
+        assert!(team.lineups.is_empty());
+    }
 }
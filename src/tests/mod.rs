@@ -1,3 +1,51 @@
+mod code_line_tests;
 mod lineup_tests;
+mod notes_tests;
+mod opponent_tests;
+mod rally_tests;
+mod scouting_tests;
+mod season_tests;
 mod snapshot_tests;
+mod stats_tests;
 mod substitution_tests;
+mod timing_tests;
+mod video_tests;
+
+/// Events shared by the tests, the fields not given are left empty.
+#[cfg(test)]
+mod fixtures {
+    use crate::shapes::{
+        enums::{EvalEnum, EventTypeEnum},
+        snapshot::EventEntry,
+    };
+    use chrono::{DateTime, Duration, Utc};
+    use uuid::Uuid;
+
+    pub fn kick_off() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-01-01T18:00:00+01:00")
+            .expect("expected a valid date")
+            .with_timezone(&Utc)
+    }
+
+    pub fn event(event_type: EventTypeEnum, player: Uuid, eval: Option<EvalEnum>) -> EventEntry {
+        EventEntry {
+            player: Some(player),
+            eval,
+            ..EventEntry::new(Utc::now(), event_type)
+        }
+    }
+
+    /// An event played the given number of seconds after the kick off.
+    pub fn event_at(
+        event_type: EventTypeEnum,
+        player: Option<Uuid>,
+        eval: Option<EvalEnum>,
+        seconds: i64,
+    ) -> EventEntry {
+        EventEntry {
+            player,
+            eval,
+            ..EventEntry::new(kick_off() + Duration::seconds(seconds), event_type)
+        }
+    }
+}
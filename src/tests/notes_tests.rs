@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod tests {
+    use crate::shapes::{
+        enums::{EvalEnum, EventTypeEnum, TeamSideEnum},
+        set::SetEntry,
+        snapshot::EventEntry,
+        tags::{format_tags, parse_tags},
+    };
+    use crate::tests::fixtures::event;
+    use csv::{ReaderBuilder, WriterBuilder};
+    use uuid::Uuid;
+
+    #[test]
+    fn tags_parsing() {
+        assert_eq!(
+            parse_tags("#Timeout, tactical  timeout #tactical"),
+            vec!["timeout", "tactical"]
+        );
+        assert!(parse_tags(" , # ").is_empty());
+        assert_eq!(
+            format_tags(&["timeout".to_string(), "tactical".to_string()]),
+            "#timeout #tactical"
+        );
+    }
+
+    #[test]
+    fn notes_csv_roundtrip() {
+        let player = Uuid::new_v4();
+        let mut noted = event(EventTypeEnum::S, player, Some(EvalEnum::Perfect));
+        noted.notes = Some("short serve, zone 1".to_string());
+        noted.tags = vec!["tactical".to_string(), "timeout".to_string()];
+        let mut writer = WriterBuilder::new().has_headers(false).from_writer(vec![]);
+        writer
+            .serialize(&noted)
+            .expect("expected a serialized event");
+        let plain = event(EventTypeEnum::S, player, Some(EvalEnum::Error));
+        writer
+            .serialize(&plain)
+            .expect("expected a serialized event");
+        let data = writer.into_inner().expect("expected csv data");
+        // events scouted before notes and tags existed have two fields less
+        let csv = String::from_utf8(data).expect("expected utf8");
+        let old_record = csv
+            .lines()
+            .last()
+            .and_then(|line| line.strip_suffix(",,"))
+            .expect("expected a record");
+        let csv = format!("{}{}\n", csv, old_record);
+        let mut reader = ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(csv.as_bytes());
+        let events: Vec<EventEntry> = reader
+            .deserialize()
+            .collect::<Result<_, _>>()
+            .expect("expected valid events");
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].notes, noted.notes);
+        assert_eq!(events[0].tags, noted.tags);
+        assert_eq!(events[1].notes, None);
+        assert!(events[1].tags.is_empty());
+        assert_eq!(events[2].notes, None);
+        assert!(events[2].tags.is_empty());
+    }
+
+    #[test]
+    fn tagged_stats() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let tagged = |e: EventEntry| EventEntry {
+            tags: vec!["pressure".to_string()],
+            ..e
+        };
+        let mut set = SetEntry::new(1, TeamSideEnum::Us, positions, Uuid::new_v4(), None, setter)
+            .expect("expected a valid set");
+        set.events = vec![
+            tagged(event(S, setter, Some(Perfect))),
+            event(S, setter, Some(Positive)),
+            event(D, oh1, Some(Positive)),
+            tagged(event(A, oh1, Some(Perfect))),
+            tagged(event(S, setter, Some(Error))),
+        ];
+        let count = |stats: &crate::shapes::stats::Stats, event_type| {
+            stats
+                .events
+                .query(Some(event_type), None, None, None, None, None)
+                .map(|(_, v)| *v)
+                .sum::<u32>()
+        };
+        let (snapshot, _) = set.compute_snapshot().expect("expected a valid snapshot");
+        let stats = set
+            .compute_tagged_stats("pressure")
+            .expect("expected tagged stats");
+        assert_eq!(count(&snapshot.stats, S), 3);
+        assert_eq!(count(&stats, S), 2);
+        assert_eq!(count(&stats, D), 0);
+        assert_eq!(count(&stats, A), 1);
+        let untagged = set
+            .compute_tagged_stats("missing")
+            .expect("expected tagged stats");
+        assert_eq!(count(&untagged, S), 0);
+    }
+
+    #[test]
+    fn tagged_deferred_stats() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let tagged = |e: EventEntry| EventEntry {
+            tags: vec!["pressure".to_string()],
+            ..e
+        };
+        let mut set = SetEntry::new(
+            1,
+            TeamSideEnum::Them,
+            positions,
+            Uuid::new_v4(),
+            None,
+            setter,
+        )
+        .expect("expected a valid set");
+        set.events = vec![
+            // tagged reception, the rally is won by an untagged attack
+            tagged(event(P, oh1, Some(Positive))),
+            event(E, setter, Some(Perfect)),
+            event(A, oh1, Some(Perfect)),
+            event(S, setter, Some(Error)),
+            // untagged reception, the rally is won by a tagged attack
+            event(P, oh1, Some(Negative)),
+            tagged(event(E, setter, Some(Positive))),
+            tagged(event(A, oh1, Some(Perfect))),
+        ];
+        let stats = set
+            .compute_tagged_stats("pressure")
+            .expect("expected tagged stats");
+        let receptions: Vec<_> = stats.reception.query(None, None, None, None).collect();
+        assert_eq!(receptions.len(), 1);
+        assert_eq!(receptions[0].0.eval, Positive);
+        assert!(receptions[0].0.sideout);
+        let sets: Vec<_> = stats.sets.query(None, None, None, None).collect();
+        assert_eq!(sets.len(), 1);
+        assert_eq!(sets[0].0.eval, Positive);
+        // nothing is lost or counted twice in the whole set
+        let (snapshot, _) = set.compute_snapshot().expect("expected a valid snapshot");
+        assert_eq!(
+            snapshot
+                .stats
+                .reception
+                .query(None, None, None, None)
+                .count(),
+            2
+        );
+        assert_eq!(snapshot.stats.sets.query(None, None, None, None).count(), 2);
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use crate::shapes::{opponent::OpponentEntry, r#match::MatchEntry};
+    use chrono::DateTime;
+    use uuid::Uuid;
+
+    fn opponent() -> OpponentEntry {
+        OpponentEntry {
+            id: Uuid::new_v4(),
+            name: "Volley Rossi".to_string(),
+            aliases: vec!["V. Rossi".to_string()],
+            ..Default::default()
+        }
+    }
+
+    fn match_against(opponent: &str, opponent_id: Option<Uuid>) -> MatchEntry {
+        MatchEntry {
+            opponent: opponent.to_string(),
+            opponent_id,
+            season: None,
+            date: DateTime::parse_from_rfc3339("2025-01-01T18:00:00+01:00")
+                .expect("expected a valid date"),
+            id: "match".to_string(),
+            team: Default::default(),
+            players: vec![],
+            home: true,
+            sets: vec![],
+            notes: None,
+            tags: vec![],
+        }
+    }
+
+    #[test]
+    fn matches_name_and_aliases() {
+        let opponent = opponent();
+        assert!(opponent.matches("volley rossi"));
+        assert!(opponent.matches("  Volley   ROSSI "));
+        assert!(opponent.matches("v rossi"));
+        assert!(opponent.matches("V.Rossi"));
+        assert!(!opponent.matches("Volley Bianchi"));
+        assert!(!opponent.matches(""));
+    }
+
+    #[test]
+    fn played_by_id_or_legacy_name() {
+        let opponent = opponent();
+        assert!(opponent.played(&match_against("whatever", Some(opponent.id))));
+        assert!(!opponent.played(&match_against("Volley Rossi", Some(Uuid::new_v4()))));
+        assert!(opponent.played(&match_against("V. Rossi", None)));
+        assert!(!opponent.played(&match_against("Volley Bianchi", None)));
+    }
+}
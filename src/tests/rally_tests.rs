@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use crate::shapes::{
+        enums::{EvalEnum, EventTypeEnum, PhaseEnum, RallyLengthEnum, TeamSideEnum},
+        set::SetEntry,
+        snapshot::EventEntry,
+    };
+    use crate::tests::fixtures::event_at;
+    use uuid::Uuid;
+
+    #[test]
+    fn rallies_from_events() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let bench = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let mut set = SetEntry::new(1, TeamSideEnum::Us, positions, Uuid::new_v4(), None, setter)
+            .expect("expected a valid set");
+        set.events = vec![
+            // ace
+            event_at(S, Some(setter), Some(Perfect), 0),
+            // long exchange, lost
+            event_at(S, Some(setter), Some(Positive), 10),
+            event_at(D, Some(oh1), Some(Positive), 12),
+            event_at(E, Some(setter), Some(Positive), 13),
+            event_at(A, Some(oh1), Some(Error), 14),
+            // substitutions are not part of any rally
+            EventEntry {
+                target_player: Some(bench),
+                ..event_at(R, Some(oh1), None, 20)
+            },
+            // opponent error on their serve
+            event_at(OE, None, None, 30),
+            // still in progress
+            event_at(S, Some(setter), Some(Positive), 40),
+        ];
+        let (snapshot, _) = set
+            .compute_snapshot()
+            .expect("expected successful computation");
+        assert_eq!(snapshot.rallies.len(), 3);
+        let ace = &snapshot.rallies[0];
+        assert_eq!(ace.serving_team, TeamSideEnum::Us);
+        assert_eq!(ace.winner, Some(TeamSideEnum::Us));
+        assert_eq!(ace.contacts(), 1);
+        assert_eq!(ace.score, (1, 0));
+        let lost = &snapshot.rallies[1];
+        assert_eq!(lost.winner, Some(TeamSideEnum::Them));
+        assert_eq!(lost.contacts(), 4);
+        assert_eq!(lost.length(), RallyLengthEnum::Medium);
+        assert_eq!(lost.duration().map(|d| d.num_seconds()), Some(4));
+        assert_eq!(lost.score, (1, 1));
+        let opponent_error = &snapshot.rallies[2];
+        assert_eq!(opponent_error.serving_team, TeamSideEnum::Them);
+        assert_eq!(opponent_error.events.len(), 1);
+        assert_eq!(opponent_error.contacts(), 0);
+        let in_progress = snapshot
+            .current_rally
+            .as_ref()
+            .expect("expected a rally in progress");
+        assert_eq!(in_progress.winner, None);
+        assert_eq!(in_progress.events.len(), 1);
+        // rally stats only count completed rallies
+        assert_eq!(
+            snapshot
+                .stats
+                .rally_won_perc(None, None, Some(RallyLengthEnum::Short)),
+            Some((100.0, 2, 2))
+        );
+        assert_eq!(
+            snapshot
+                .stats
+                .rally_won_perc(Some(PhaseEnum::Break), None, None),
+            Some((50.0, 2, 1))
+        );
+    }
+}
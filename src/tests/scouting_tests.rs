@@ -0,0 +1,315 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errors::{AppError, IOError},
+        providers::{match_writer::MatchWriter, set_writer::SetWriter},
+        screens::{
+            scouting_screen::ScoutingScreen,
+            screen::{AppAction, Renderable, ScreenAsync},
+        },
+        shapes::{
+            enums::{EvalEnum, EventTypeEnum, TeamSideEnum},
+            player::PlayerEntry,
+            r#match::MatchEntry,
+            set::SetEntry,
+            settings::Settings,
+            snapshot::EventEntry,
+            team::TeamEntry,
+        },
+        tests::fixtures::{event_at, kick_off},
+    };
+    use async_trait::async_trait;
+    use chrono::{DateTime, FixedOffset};
+    use crokey::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::{backend::TestBackend, Terminal};
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    /// Keeps the events of the set in memory, in place of the csv file.
+    #[derive(Debug, Default)]
+    struct MemorySetWriter {
+        events: Mutex<Vec<EventEntry>>,
+    }
+
+    impl MemorySetWriter {
+        fn stored(&self) -> Vec<EventEntry> {
+            self.events.lock().expect("expected the lock").clone()
+        }
+    }
+
+    #[async_trait]
+    impl SetWriter for MemorySetWriter {
+        async fn create(
+            &self,
+            _m: &MatchEntry,
+            _set_number: u8,
+            _serving_team: TeamSideEnum,
+            _positions: [Uuid; 6],
+            _libero: Uuid,
+            _fallback_libero: Option<Uuid>,
+            _setter: Uuid,
+            _events: Vec<EventEntry>,
+        ) -> Result<SetEntry, AppError> {
+            Err(AppError::IO(IOError::Msg("not supported".to_string())))
+        }
+
+        async fn update(&self, _m: &MatchEntry, _set: &SetEntry) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        async fn append_event(
+            &self,
+            _m: &MatchEntry,
+            _set_number: u8,
+            event: &EventEntry,
+        ) -> Result<(), AppError> {
+            self.events
+                .lock()
+                .expect("expected the lock")
+                .push(event.clone());
+            Ok(())
+        }
+
+        async fn remove_last_event(
+            &self,
+            _m: &MatchEntry,
+            _set_number: u8,
+        ) -> Result<Option<EventEntry>, AppError> {
+            Ok(self.events.lock().expect("expected the lock").pop())
+        }
+
+        async fn replace_last_event(
+            &self,
+            _m: &MatchEntry,
+            _set_number: u8,
+            event: &EventEntry,
+        ) -> Result<(), AppError> {
+            match self.events.lock().expect("expected the lock").last_mut() {
+                Some(last) => {
+                    *last = event.clone();
+                    Ok(())
+                }
+                None => Err(AppError::IO(IOError::Msg("no events".to_string()))),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct MemoryMatchWriter;
+
+    #[async_trait]
+    impl MatchWriter for MemoryMatchWriter {
+        async fn create(
+            &self,
+            _team: &TeamEntry,
+            _opponent: String,
+            _opponent_id: Option<Uuid>,
+            _season: Option<u16>,
+            _date: DateTime<FixedOffset>,
+            _home: bool,
+        ) -> Result<MatchEntry, AppError> {
+            Err(AppError::IO(IOError::Msg("not supported".to_string())))
+        }
+
+        async fn update(&self, _m: &MatchEntry) -> Result<(), AppError> {
+            Ok(())
+        }
+    }
+
+    struct Scouting {
+        screen: ScoutingScreen<MemoryMatchWriter, MemorySetWriter>,
+        set_writer: Arc<MemorySetWriter>,
+        terminal: Terminal<TestBackend>,
+    }
+
+    impl Scouting {
+        /// Opens the scouting screen on a set already holding the given number of aces.
+        fn new(aces: i64) -> (Self, Uuid) {
+            let players: Vec<PlayerEntry> = (1..=7)
+                .map(|number| PlayerEntry {
+                    name: format!("player {}", number),
+                    number,
+                    ..Default::default()
+                })
+                .collect();
+            let ids: Vec<Uuid> = players.iter().map(|p| p.id).collect();
+            let server = ids[0];
+            let positions = [ids[0], ids[1], ids[2], ids[3], ids[4], ids[5]];
+            let mut set = SetEntry::new(1, TeamSideEnum::Us, positions, ids[6], None, server)
+                .expect("expected a valid set");
+            set.events = (0..aces)
+                .map(|i| event_at(EventTypeEnum::S, Some(server), Some(EvalEnum::Perfect), i))
+                .collect();
+            let (snapshot, options) = set.compute_snapshot().expect("expected a valid snapshot");
+            let set_writer = Arc::new(MemorySetWriter {
+                events: Mutex::new(set.events.clone()),
+            });
+            let current_match = MatchEntry {
+                opponent: "opponent".to_string(),
+                opponent_id: None,
+                date: kick_off().fixed_offset(),
+                season: None,
+                id: "match".to_string(),
+                team: TeamEntry {
+                    name: "team".to_string(),
+                    players: players.clone(),
+                    ..Default::default()
+                },
+                players,
+                home: true,
+                sets: vec![],
+                notes: None,
+                tags: vec![],
+            };
+            let settings = Settings {
+                analytics_enabled: false,
+                ..Default::default()
+            };
+            let screen = ScoutingScreen::new(
+                settings,
+                current_match,
+                set,
+                snapshot,
+                options,
+                None,
+                Arc::new(MemoryMatchWriter),
+                set_writer.clone(),
+            );
+            let terminal =
+                Terminal::new(TestBackend::new(200, 50)).expect("expected a test terminal");
+            (
+                Scouting {
+                    screen,
+                    set_writer,
+                    terminal,
+                },
+                server,
+            )
+        }
+
+        /// Renders the screen, which binds the keys of the current step, then presses the key.
+        async fn press(&mut self, code: KeyCode, modifiers: KeyModifiers) -> AppAction {
+            let screen = &mut self.screen;
+            self.terminal
+                .draw(|f| {
+                    let area = f.area();
+                    screen.render(f, area, area, area);
+                })
+                .expect("expected the screen to render");
+            self.screen.handle_key(KeyEvent::new(code, modifiers)).await
+        }
+
+        async fn undo(&mut self) -> AppAction {
+            self.press(KeyCode::Char('z'), KeyModifiers::CONTROL).await
+        }
+
+        async fn redo(&mut self) -> AppAction {
+            self.press(KeyCode::Char('y'), KeyModifiers::CONTROL).await
+        }
+
+        /// Records an ace by the server in position 1.
+        async fn ace(&mut self) -> AppAction {
+            self.press(KeyCode::Char('s'), KeyModifiers::NONE).await;
+            self.press(KeyCode::Char('#'), KeyModifiers::NONE).await
+        }
+
+        fn timestamps(&self) -> Vec<DateTime<chrono::Utc>> {
+            self.set_writer
+                .stored()
+                .iter()
+                .map(|e| e.timestamp)
+                .collect()
+        }
+    }
+
+    #[tokio::test]
+    async fn undo_then_redo() {
+        let (mut scouting, server) = Scouting::new(2);
+        let recorded = scouting.timestamps();
+        scouting.undo().await;
+        assert_eq!(scouting.set_writer.stored().len(), 1);
+        scouting.redo().await;
+        let stored = scouting.set_writer.stored();
+        assert_eq!(scouting.timestamps(), recorded);
+        let last = stored.last().expect("expected the redone event");
+        assert_eq!(last.event_type, EventTypeEnum::S);
+        assert_eq!(last.player, Some(server));
+        assert_eq!(last.eval, Some(EvalEnum::Perfect));
+        // nothing left to redo
+        scouting.redo().await;
+        assert_eq!(scouting.timestamps(), recorded);
+    }
+
+    #[tokio::test]
+    async fn undo_twice_then_redo_twice() {
+        let (mut scouting, _) = Scouting::new(2);
+        let recorded = scouting.timestamps();
+        // the undone serve is opened again at the evaluation, going back to the event
+        // selection takes a second undo before the previous event is removed
+        scouting.undo().await;
+        scouting.undo().await;
+        scouting.undo().await;
+        assert!(scouting.set_writer.stored().is_empty());
+        scouting.redo().await;
+        assert_eq!(scouting.timestamps(), recorded[..1]);
+        scouting.redo().await;
+        assert_eq!(scouting.timestamps(), recorded);
+    }
+
+    #[tokio::test]
+    async fn new_event_clears_redo() {
+        let (mut scouting, _) = Scouting::new(2);
+        let recorded = scouting.timestamps();
+        scouting.undo().await;
+        // the serve is evaluated again, replacing the undone one
+        scouting.press(KeyCode::Char('#'), KeyModifiers::NONE).await;
+        let replaced = scouting.timestamps();
+        assert_eq!(replaced.len(), 2);
+        assert_ne!(replaced[1], recorded[1]);
+        scouting.redo().await;
+        assert_eq!(scouting.timestamps(), replaced);
+    }
+
+    #[tokio::test]
+    async fn undo_out_of_set_over() {
+        let (mut scouting, _) = Scouting::new(24);
+        let action = scouting.ace().await;
+        assert!(matches!(action, AppAction::None));
+        assert_eq!(scouting.set_writer.stored().len(), 25);
+        // the first key only dismisses the set over message, the screen stays open
+        let action = scouting.undo().await;
+        assert!(matches!(action, AppAction::None));
+        assert_eq!(scouting.set_writer.stored().len(), 25);
+        // scouting keys are ignored once the set is over
+        scouting.press(KeyCode::Char('s'), KeyModifiers::NONE).await;
+        assert_eq!(scouting.set_writer.stored().len(), 25);
+        scouting.undo().await;
+        assert_eq!(scouting.set_writer.stored().len(), 24);
+        // the set point can be played again, or redone
+        scouting.redo().await;
+        assert_eq!(scouting.set_writer.stored().len(), 25);
+        scouting.press(KeyCode::Esc, KeyModifiers::NONE).await;
+        let action = scouting.press(KeyCode::Esc, KeyModifiers::NONE).await;
+        assert!(matches!(action, AppAction::Back(true, None)));
+    }
+
+    #[tokio::test]
+    async fn code_line_stops_at_wrong_server() {
+        let (mut scouting, server) = Scouting::new(0);
+        scouting.press(KeyCode::Char('i'), KeyModifiers::NONE).await;
+        for c in "2S# S#".chars() {
+            scouting.press(KeyCode::Char(c), KeyModifiers::NONE).await;
+        }
+        scouting.press(KeyCode::Enter, KeyModifiers::NONE).await;
+        // the player in position 1 was expected to serve: nothing is added until confirmed
+        assert!(scouting.set_writer.stored().is_empty());
+        scouting.press(KeyCode::Enter, KeyModifiers::NONE).await;
+        let stored = scouting.set_writer.stored();
+        assert_eq!(stored.len(), 2);
+        assert_ne!(stored[0].player, Some(server));
+        assert_eq!(stored[0].tags, vec!["wrong-server".to_string()]);
+        // the rest of the line goes on once the serve is accepted
+        assert_eq!(stored[1].player, Some(server));
+        assert!(stored[1].tags.is_empty());
+    }
+}
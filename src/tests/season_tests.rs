@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use crate::shapes::{enums::RoleEnum, player::PlayerEntry, team::TeamEntry};
+    use uuid::Uuid;
+
+    fn player(name: &str, number: u8, role: RoleEnum) -> PlayerEntry {
+        PlayerEntry {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            role: Some(role),
+            number,
+            deleted: false,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn historical_numbers_by_season() {
+        let setter = player("Ron Gilbert", 1, RoleEnum::Setter);
+        let oh1 = player("David Crane", 7, RoleEnum::OutsideHitter);
+        let mut team = TeamEntry {
+            name: "My Team".to_string(),
+            year: 2024,
+            players: vec![setter.clone(), oh1.clone()],
+            ..Default::default()
+        };
+        team.sync_current_season();
+        // new season: the outside hitter changes shirt and role, the setter leaves
+        team.year = 2025;
+        team.players[0].deleted = true;
+        team.players[1].number = 10;
+        team.players[1].role = Some(RoleEnum::OppositeHitter);
+        team.sync_current_season();
+        assert_eq!(team.seasons.len(), 2);
+
+        let past = team.season_players(Some(2024));
+        let past_oh1 = past
+            .iter()
+            .find(|p| p.id == oh1.id)
+            .expect("expected a player");
+        assert_eq!(past_oh1.number, 7);
+        assert_eq!(past_oh1.role, Some(RoleEnum::OutsideHitter));
+        assert_eq!(past.iter().filter(|p| !p.deleted).count(), 2);
+        // the team itself is untouched, saving it keeps the current season
+        assert!(team.players[0].deleted);
+        assert_eq!(team.players[1].number, 10);
+
+        let current = team.season_players(Some(2025));
+        assert_eq!(current[1].number, 10);
+        assert_eq!(current.iter().filter(|p| !p.deleted).count(), 1);
+
+        // matches without a season use the current roster
+        assert_eq!(team.season_players(None)[1].number, 10);
+    }
+}
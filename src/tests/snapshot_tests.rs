@@ -1,11 +1,12 @@
 mod tests {
     use crate::{
-        errors::{AppError, SnapshotError},
+        errors::{AppError, MatchError, SnapshotError},
         shapes::{
             enums::{
-                ErrorTypeEnum, EvalEnum, EventTypeEnum, PhaseEnum, RoleEnum, TeamSideEnum, ZoneEnum,
+                ErrorTypeEnum, EvalEnum, EventTypeEnum, PhaseEnum, RoleEnum, RotationEnum,
+                TeamSideEnum, ZoneEnum,
             },
-            set::SetEntry,
+            set::{MidSetStartEntry, SetEntry},
             snapshot::{EventEntry, Snapshot},
         },
     };
@@ -149,9 +150,21 @@ mod tests {
                 event_type: EventTypeEnum::S,
                 player: Some(setter),
                 target_player: None,
+                second_blocker: None,
+                third_blocker: None,
+                touched: false,
+                combination: None,
+                serve_type: None,
+                notes: None,
+                tags: vec![],
+                awarded_to: None,
                 eval: Some(EvalEnum::Perfect),
                 timestamp: Utc::now(),
             }],
+            video: None,
+            notes: None,
+            tags: vec![],
+            mid_set_start: None,
         };
         let (mut snapshot, mut availeble_options) = set
             .compute_snapshot()
@@ -209,6 +222,14 @@ mod tests {
                     eval: Some(EvalEnum::Error),
                     player: Some(setter),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -236,6 +257,14 @@ mod tests {
                     eval: Some(EvalEnum::Perfect),
                     player: Some(oh1),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -263,6 +292,14 @@ mod tests {
                     eval: Some(EvalEnum::Perfect),
                     player: Some(opposite),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -307,6 +344,14 @@ mod tests {
                     eval: Some(EvalEnum::Positive),
                     player: Some(oh1),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -334,6 +379,14 @@ mod tests {
                     eval: None,
                     player: None,
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -360,6 +413,14 @@ mod tests {
                     event_type: EventTypeEnum::R,
                     eval: None,
                     target_player: Some(setter_replacement),
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     player: Some(setter),
                     timestamp: Utc::now(),
                 },
@@ -392,6 +453,14 @@ mod tests {
                     event_type: EventTypeEnum::S,
                     player: Some(oh1),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     eval: Some(EvalEnum::Negative),
                     timestamp: Utc::now(),
                 },
@@ -420,6 +489,14 @@ mod tests {
                     eval: Some(EvalEnum::Negative),
                     player: Some(mb2),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -447,6 +524,14 @@ mod tests {
                     eval: None,
                     player: None,
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -474,6 +559,14 @@ mod tests {
                     eval: Some(EvalEnum::Perfect),
                     player: Some(libero),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -501,6 +594,14 @@ mod tests {
                     eval: None,
                     player: Some(setter_replacement),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -540,6 +641,14 @@ mod tests {
                     eval: None,
                     player: None,
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -567,6 +676,14 @@ mod tests {
                     eval: Some(EvalEnum::Over),
                     player: Some(mb2),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -613,6 +730,14 @@ mod tests {
                     eval: Some(EvalEnum::Perfect),
                     player: Some(oh2),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(
@@ -661,6 +786,14 @@ mod tests {
                     eval: Some(EvalEnum::Error),
                     player: Some(oh2),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -688,6 +821,14 @@ mod tests {
                     eval: Some(EvalEnum::Positive),
                     player: Some(libero),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -715,6 +856,14 @@ mod tests {
                     eval: Some(EvalEnum::Negative),
                     player: Some(oh1),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -759,6 +908,14 @@ mod tests {
                     eval: Some(EvalEnum::Positive),
                     player: Some(libero),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -786,6 +943,14 @@ mod tests {
                     eval: Some(EvalEnum::Perfect),
                     player: Some(opposite),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -813,6 +978,14 @@ mod tests {
                     eval: Some(EvalEnum::Positive),
                     player: Some(opposite),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -840,6 +1013,14 @@ mod tests {
                     eval: Some(EvalEnum::Error),
                     player: Some(mb1),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -867,6 +1048,14 @@ mod tests {
                     eval: Some(EvalEnum::Perfect),
                     player: Some(libero),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -894,6 +1083,14 @@ mod tests {
                     eval: Some(EvalEnum::Over),
                     player: Some(opposite),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -921,6 +1118,14 @@ mod tests {
                     eval: None,
                     player: None,
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -948,6 +1153,14 @@ mod tests {
                     eval: Some(EvalEnum::Negative),
                     player: Some(oh2),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -975,6 +1188,14 @@ mod tests {
                     eval: Some(EvalEnum::Positive),
                     player: Some(libero),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -1002,6 +1223,14 @@ mod tests {
                     eval: Some(EvalEnum::Error),
                     player: Some(oh1),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -1029,6 +1258,14 @@ mod tests {
                     eval: None,
                     player: None,
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -1056,6 +1293,14 @@ mod tests {
                     eval: Some(EvalEnum::Error),
                     player: Some(mb1),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 Box::new(|snapshot: &Snapshot, _: &Vec<EventTypeEnum>| {
@@ -1094,6 +1339,14 @@ mod tests {
                     event_type: EventTypeEnum::R,
                     eval: None,
                     target_player: Some(setter),
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     player: Some(setter_replacement),
                     timestamp: Utc::now(),
                 },
@@ -1123,6 +1376,14 @@ mod tests {
                     event_type: EventTypeEnum::CL,
                     eval: None,
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     player: None,
                     timestamp: Utc::now(),
                 },
@@ -1155,6 +1416,14 @@ mod tests {
                     event_type: EventTypeEnum::CS,
                     eval: None,
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     player: Some(opposite),
                     timestamp: Utc::now(),
                 },
@@ -1185,6 +1454,14 @@ mod tests {
                     event_type: EventTypeEnum::P,
                     eval: Some(EvalEnum::Positive),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     player: Some(oh1),
                     timestamp: Utc::now(),
                 },
@@ -1212,6 +1489,14 @@ mod tests {
                     event_type: EventTypeEnum::F,
                     eval: None,
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     player: Some(opposite),
                     timestamp: Utc::now(),
                 },
@@ -1251,6 +1536,14 @@ mod tests {
                     event_type: EventTypeEnum::OE,
                     eval: None,
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     player: None,
                     timestamp: Utc::now(),
                 },
@@ -1278,6 +1571,14 @@ mod tests {
                     event_type: EventTypeEnum::S,
                     eval: Some(EvalEnum::Positive),
                     target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     player: Some(opposite),
                     timestamp: Utc::now(),
                 },
@@ -1338,6 +1639,14 @@ mod tests {
                 eval: None,
                 player: Some(setter),                    // out
                 target_player: Some(setter_replacement), // in
+                second_blocker: None,
+                third_blocker: None,
+                touched: false,
+                combination: None,
+                serve_type: None,
+                notes: None,
+                tags: vec![],
+                awarded_to: None,
                 timestamp: Utc::now(),
             },
             &[EventTypeEnum::R],
@@ -1355,6 +1664,14 @@ mod tests {
                 eval: None,
                 player: Some(oh1),                       // out
                 target_player: Some(setter_replacement), // in
+                second_blocker: None,
+                third_blocker: None,
+                touched: false,
+                combination: None,
+                serve_type: None,
+                notes: None,
+                tags: vec![],
+                awarded_to: None,
                 timestamp: Utc::now(),
             },
             &[EventTypeEnum::R],
@@ -1372,6 +1689,14 @@ mod tests {
                 eval: None,
                 player: Some(fallback_libero),               // out
                 target_player: Some(some_other_replacement), // in
+                second_blocker: None,
+                third_blocker: None,
+                touched: false,
+                combination: None,
+                serve_type: None,
+                notes: None,
+                tags: vec![],
+                awarded_to: None,
                 timestamp: Utc::now(),
             },
             &[EventTypeEnum::R],
@@ -1390,6 +1715,14 @@ mod tests {
                     eval: None,
                     player: Some(oh1),                           // out
                     target_player: Some(some_other_replacement), // in
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 &[EventTypeEnum::R],
@@ -1402,6 +1735,14 @@ mod tests {
                 eval: None,
                 player: Some(some_other_replacement),   // out
                 target_player: Some(some_other_player), // in
+                second_blocker: None,
+                third_blocker: None,
+                touched: false,
+                combination: None,
+                serve_type: None,
+                notes: None,
+                tags: vec![],
+                awarded_to: None,
                 timestamp: Utc::now(),
             },
             &[EventTypeEnum::R],
@@ -1424,6 +1765,14 @@ mod tests {
                     eval: None,
                     player: Some(some_other_replacement), // out
                     target_player: Some(oh1),             // in
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
                     timestamp: Utc::now(),
                 },
                 &[EventTypeEnum::R],
@@ -1483,4 +1832,178 @@ mod tests {
         assert_eq!(total_counter_attacks, 2);
         assert_eq!(successful_counter_attacks, 1);
     }
+
+    #[test]
+    fn score_adjustment() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let positions: [Uuid; 6] = [
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let event = |event_type, player, eval, awarded_to| EventEntry {
+            timestamp: Utc::now(),
+            event_type,
+            player,
+            eval,
+            target_player: None,
+            second_blocker: None,
+            third_blocker: None,
+            touched: false,
+            combination: None,
+            serve_type: None,
+            notes: None,
+            tags: vec![],
+            awarded_to,
+        };
+        let mut set = SetEntry::new(
+            1,
+            TeamSideEnum::Us,
+            positions,
+            Uuid::new_v4(),
+            None,
+            positions[0],
+        )
+        .expect("expected a valid set");
+        set.events = vec![
+            // ace, then a sanction point to the opponent
+            event(S, Some(positions[0]), Some(Perfect), None),
+            event(SA, None, None, Some(TeamSideEnum::Them)),
+        ];
+        let (snapshot, available_options) =
+            set.compute_snapshot().expect("expected a valid snapshot");
+        assert_eq!((snapshot.score_us, snapshot.score_them), (1, 1));
+        assert_eq!(snapshot.get_serving_team(), Some(TeamSideEnum::Them));
+        assert_eq!(
+            snapshot.current_lineup.get_current_phase(),
+            PhaseEnum::SideOut
+        );
+        assert!(available_options.contains(&P));
+        // adjustments are not rallies
+        assert_eq!(snapshot.rallies.len(), 1);
+        assert_eq!(
+            snapshot
+                .stats
+                .opponent_errors
+                .query(None, None)
+                .map(|(_, v)| *v)
+                .sum::<u32>(),
+            0
+        );
+        let rotation = snapshot
+            .current_lineup
+            .get_current_rotation()
+            .expect("expected a rotation");
+        // a point awarded to us while receiving makes us rotate and serve
+        set.events
+            .push(event(SA, None, None, Some(TeamSideEnum::Us)));
+        let (snapshot, available_options) =
+            set.compute_snapshot().expect("expected a valid snapshot");
+        assert_eq!((snapshot.score_us, snapshot.score_them), (2, 1));
+        assert_eq!(snapshot.get_serving_team(), Some(TeamSideEnum::Us));
+        assert_ne!(
+            snapshot
+                .current_lineup
+                .get_current_rotation()
+                .expect("expected a rotation"),
+            rotation
+        );
+        assert!(available_options.contains(&S));
+        assert_eq!(snapshot.rallies.len(), 1);
+    }
+
+    #[test]
+    fn mid_set_start() {
+        use EventTypeEnum::*;
+        let positions: [Uuid; 6] = [
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let setter = positions[0];
+        let mut set = SetEntry::new(2, TeamSideEnum::Us, positions, Uuid::new_v4(), None, setter)
+            .expect("expected a valid set");
+        // joined at 12-9 with the setter in 4, while receiving
+        let start = MidSetStartEntry {
+            score_us: 12,
+            score_them: 9,
+            rotation: RotationEnum::Four,
+            serving_team: TeamSideEnum::Them,
+        };
+        assert!(start.is_valid(2));
+        set.mid_set_start = Some(start);
+        let (snapshot, available_options) =
+            set.compute_snapshot().expect("expected a valid snapshot");
+        assert_eq!((snapshot.score_us, snapshot.score_them), (12, 9));
+        assert_eq!(snapshot.scouted_from, Some((12, 9)));
+        assert_eq!(
+            snapshot
+                .current_lineup
+                .get_current_rotation()
+                .expect("expected a rotation"),
+            RotationEnum::Four as u8
+        );
+        assert_eq!(snapshot.get_serving_team(), Some(TeamSideEnum::Them));
+        assert!(available_options.contains(&P));
+        set.events = vec![EventEntry {
+            timestamp: Utc::now(),
+            event_type: OE,
+            player: None,
+            eval: None,
+            target_player: None,
+            second_blocker: None,
+            third_blocker: None,
+            touched: false,
+            combination: None,
+            serve_type: None,
+            notes: None,
+            tags: vec![],
+            awarded_to: None,
+        }];
+        let (snapshot, _) = set.compute_snapshot().expect("expected a valid snapshot");
+        assert_eq!((snapshot.score_us, snapshot.score_them), (13, 9));
+        // only the scouted rally counts in the stats
+        assert_eq!(snapshot.rallies.len(), 1);
+        assert_eq!(
+            snapshot
+                .stats
+                .opponent_errors
+                .query(None, None)
+                .map(|(_, v)| *v)
+                .sum::<u32>(),
+            1
+        );
+        // a set that is already over cannot be joined
+        let over = MidSetStartEntry {
+            score_us: 25,
+            score_them: 20,
+            ..start
+        };
+        assert!(!over.is_valid(2));
+        // nor replayed from a set file
+        set.mid_set_start = Some(over);
+        assert!(matches!(
+            set.compute_snapshot(),
+            Err(AppError::Match(MatchError::SetEntryError(_)))
+        ));
+        assert!(MidSetStartEntry {
+            score_us: 15,
+            score_them: 14,
+            ..start
+        }
+        .is_valid(2));
+        assert!(!MidSetStartEntry {
+            score_us: 15,
+            score_them: 13,
+            ..start
+        }
+        .is_valid(5));
+    }
 }
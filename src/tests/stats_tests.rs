@@ -0,0 +1,564 @@
+#[cfg(test)]
+mod tests {
+    use crate::shapes::{
+        enums::{
+            BlockCompositionEnum, EvalEnum, EventTypeEnum, PhaseEnum, ServeTypeEnum, TeamSideEnum,
+            ZoneEnum,
+        },
+        set::SetEntry,
+        snapshot::{EventEntry, Snapshot},
+        stats::Metric,
+    };
+    use crate::tests::fixtures::event;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn compute_snapshot(
+        serving_team: TeamSideEnum,
+        positions: [Uuid; 6],
+        events: Vec<EventEntry>,
+    ) -> Snapshot {
+        let mut set = SetEntry::new(
+            1,
+            serving_team,
+            positions,
+            Uuid::new_v4(),
+            None,
+            positions[0],
+        )
+        .expect("expected a valid set");
+        set.events = events;
+        let (snapshot, _) = set
+            .compute_snapshot()
+            .expect("expected successful computation");
+        snapshot
+    }
+
+    #[test]
+    fn rotation_analysis() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let snapshot = compute_snapshot(
+            TeamSideEnum::Us,
+            positions,
+            vec![
+                // break point won in rotation 0
+                event(S, setter, Some(Perfect)),
+                // break point lost in rotation 0
+                event(S, setter, Some(Error)),
+                // first-ball side-out in rotation 0
+                event(P, oh1, Some(Perfect)),
+                event(A, oh1, Some(Perfect)),
+                // break point won in the next rotation
+                event(S, oh1, Some(Perfect)),
+            ],
+        );
+        let stats = &snapshot.stats;
+        assert_eq!(
+            stats.phase_win_percentage(PhaseEnum::Break, Some(0)),
+            Some((50.0, 2, 1))
+        );
+        assert_eq!(
+            stats.phase_win_percentage(PhaseEnum::SideOut, Some(0)),
+            Some((100.0, 1, 1))
+        );
+        assert_eq!(stats.first_ball_sideout(Some(0)), Some((100.0, 1, 1)));
+        assert_eq!(stats.rotation_passes(Some(0)), Some(1));
+        assert_eq!(stats.rotation_passes(None), Some(2));
+        assert_eq!(stats.points_won_lost(None, Some(0)), (2, 1));
+        assert_eq!(stats.points_won_lost(None, None), (3, 1));
+    }
+
+    #[test]
+    fn reception_analysis() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let oh2 = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            oh2,
+            Uuid::new_v4(),
+        ];
+        let snapshot = compute_snapshot(
+            TeamSideEnum::Them,
+            positions,
+            vec![
+                // perfect pass, blocked attack
+                event(P, oh1, Some(Perfect)),
+                event(A, oh1, Some(Over)),
+                // negative pass, attack kept in play, then converted
+                event(P, oh2, Some(Negative)),
+                event(A, oh1, Some(Positive)),
+                event(A, oh1, Some(Perfect)),
+            ],
+        );
+        let stats = &snapshot.stats;
+        assert_eq!(
+            stats.reception_sideout_percentage(Some(oh1), None, None),
+            Some((0.0, 1, 0))
+        );
+        assert_eq!(
+            stats.reception_sideout_percentage(Some(oh2), None, Some(Negative)),
+            Some((100.0, 1, 1))
+        );
+        assert_eq!(
+            stats.reception_kill_percentage(Some(oh1), None, Some(Perfect)),
+            Some((0.0, 1, 0))
+        );
+        // the first attack after the negative pass was not a kill
+        assert_eq!(
+            stats.reception_kill_percentage(Some(oh2), None, None),
+            Some((0.0, 1, 0))
+        );
+        assert_eq!(
+            stats.reception_sideout_percentage(None, None, None),
+            Some((50.0, 2, 1))
+        );
+    }
+
+    #[test]
+    fn setter_distribution_top_zone() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let mb2 = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            mb2,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let mut events = vec![];
+        for attacker in [oh1, oh1, mb2] {
+            events.push(event(P, oh1, Some(Perfect)));
+            events.push(event(A, attacker, Some(Error)));
+        }
+        let snapshot = compute_snapshot(TeamSideEnum::Them, positions, events);
+        let (zone, share, total) = snapshot
+            .stats
+            .distribution
+            .top_zone(None, Some(0), None, Some(Perfect))
+            .expect("expected a top zone");
+        assert_eq!(zone, ZoneEnum::Two);
+        assert_eq!(total, 3);
+        assert_eq!(format!("{:.1}", share), "66.7");
+        assert_eq!(
+            snapshot
+                .stats
+                .distribution
+                .top_zone(None, Some(0), None, Some(Positive)),
+            None
+        );
+    }
+
+    #[test]
+    fn setter_summary() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let mb2 = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            mb2,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let mut events = vec![];
+        // only the last attack is a kill, so the rotation never changes
+        for (attacker, eval) in [(oh1, Error), (mb2, Error), (oh1, Error), (oh1, Perfect)] {
+            events.push(event(P, oh1, Some(Perfect)));
+            events.push(event(A, attacker, Some(eval)));
+        }
+        let snapshot = compute_snapshot(TeamSideEnum::Them, positions, events);
+        let (total, zone, share, kill) = snapshot
+            .stats
+            .distribution
+            .setter_summary(setter)
+            .expect("expected a summary");
+        assert_eq!(total, 4);
+        assert_eq!(zone, ZoneEnum::Two);
+        assert_eq!(format!("{:.1}", share), "75.0");
+        assert_eq!(format!("{:.1}", kill), "25.0");
+        assert_eq!(snapshot.stats.distribution.setter_summary(oh1), None);
+    }
+
+    #[test]
+    fn playing_time() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let replacement = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let snapshot = compute_snapshot(
+            TeamSideEnum::Us,
+            positions,
+            vec![
+                event(S, setter, Some(Perfect)),
+                event(S, setter, Some(Error)),
+                EventEntry {
+                    timestamp: Utc::now(),
+                    event_type: R,
+                    player: Some(oh1),
+                    eval: None,
+                    target_player: Some(replacement),
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
+                },
+                event(OS, replacement, None),
+            ],
+        );
+        let stats = &snapshot.stats;
+        assert_eq!(stats.on_court_won_lost(setter, None, None), Some((1, 2)));
+        assert_eq!(stats.on_court_won_lost(oh1, None, None), Some((1, 1)));
+        assert_eq!(
+            stats.on_court_won_lost(replacement, Some(PhaseEnum::SideOut), None),
+            Some((0, 1))
+        );
+    }
+
+    #[test]
+    fn libero_swaps() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let libero = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let mut set = SetEntry::new(1, TeamSideEnum::Us, positions, libero, None, setter)
+            .expect("expected a valid set");
+        // a whole turn of rotations: serve lost, then side-out won on an opponent error
+        set.events = (0..6)
+            .flat_map(|_| {
+                [
+                    event(S, setter, Some(Error)),
+                    EventEntry::new(Utc::now(), OE),
+                ]
+            })
+            .collect();
+        let (snapshot, _) = set
+            .compute_snapshot()
+            .expect("expected successful computation");
+        let stats = &snapshot.stats;
+        // the libero leaves the court to the server in rotations 4 and 1, and comes back
+        // with the side-out
+        assert_eq!(stats.libero_swaps(libero, None), Some(2));
+        assert_eq!(stats.libero_swaps(libero, Some(4)), Some(1));
+        assert_eq!(stats.libero_swaps(libero, Some(1)), Some(1));
+        assert_eq!(stats.libero_swaps(libero, Some(0)), None);
+        assert_eq!(stats.libero_swaps(setter, None), None);
+    }
+
+    #[test]
+    fn lineup_combinations_plus_minus() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let replacement = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let snapshot = compute_snapshot(
+            TeamSideEnum::Us,
+            positions,
+            vec![
+                event(S, setter, Some(Perfect)),
+                event(S, setter, Some(Perfect)),
+                event(S, setter, Some(Error)),
+                EventEntry {
+                    timestamp: Utc::now(),
+                    event_type: R,
+                    player: Some(oh1),
+                    eval: None,
+                    target_player: Some(replacement),
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
+                },
+                event(OS, replacement, None),
+            ],
+        );
+        let stats = &snapshot.stats;
+        let mut combinations = stats.combinations_won_lost(None);
+        combinations.sort_by_key(|(_, won, _)| *won);
+        assert_eq!(combinations.len(), 2);
+        assert!(combinations[0].0.contains(&replacement));
+        assert_eq!((combinations[0].1, combinations[0].2), (0, 1));
+        assert!(combinations[1].0.contains(&oh1));
+        assert_eq!((combinations[1].1, combinations[1].2), (2, 1));
+        let pair = |a: Uuid, b: Uuid| {
+            stats
+                .pairs_won_lost(None)
+                .into_iter()
+                .find(|(players, _, _)| players.contains(&a) && players.contains(&b))
+                .map(|(_, won, lost)| (won, lost))
+        };
+        assert_eq!(pair(setter, oh1), Some((2, 1)));
+        assert_eq!(pair(setter, replacement), Some((0, 1)));
+        assert_eq!(pair(oh1, replacement), None);
+        assert_eq!(
+            stats.combinations_won_lost(Some(PhaseEnum::SideOut)).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn set_and_free_ball() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let snapshot = compute_snapshot(
+            TeamSideEnum::Them,
+            positions,
+            vec![
+                // the set does not hide the pass from the attack that follows
+                event(P, oh1, Some(Perfect)),
+                event(E, setter, Some(Perfect)),
+                event(A, oh1, Some(Perfect)),
+                // free ball sent over, then the opponent scores
+                event(S, oh1, Some(Positive)),
+                event(D, oh1, Some(Negative)),
+                event(X, setter, Some(Negative)),
+                EventEntry {
+                    timestamp: Utc::now(),
+                    event_type: OS,
+                    player: None,
+                    eval: None,
+                    target_player: None,
+                    second_blocker: None,
+                    third_blocker: None,
+                    touched: false,
+                    combination: None,
+                    serve_type: None,
+                    notes: None,
+                    tags: vec![],
+                    awarded_to: None,
+                },
+            ],
+        );
+        let stats = &snapshot.stats;
+        assert_eq!(
+            stats.set_kill_percentage(Some(setter), None, None, Some(Perfect)),
+            Some((100.0, 1, 1))
+        );
+        assert_eq!(
+            stats.reception_kill_percentage(Some(oh1), None, Some(Perfect)),
+            Some((100.0, 1, 1))
+        );
+        assert_eq!(
+            stats.freeball_won_percentage(Some(setter), None, None, None),
+            Some((0.0, 1, 0))
+        );
+        assert_eq!(stats.points_won_lost(None, None), (1, 1));
+    }
+
+    #[test]
+    fn block_composition() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let mb2 = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            mb2,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let snapshot = compute_snapshot(
+            TeamSideEnum::Us,
+            positions,
+            vec![
+                event(S, setter, Some(Positive)),
+                EventEntry {
+                    second_blocker: Some(mb2),
+                    touched: true,
+                    ..event(B, oh1, Some(Perfect))
+                },
+            ],
+        );
+        let stats = &snapshot.stats;
+        assert_eq!(stats.block_count(None, None, None, None, None), Some(1));
+        assert_eq!(
+            stats.block_count(Some(mb2), None, None, None, None),
+            Some(1)
+        );
+        assert_eq!(
+            stats.block_count(None, None, None, Some(BlockCompositionEnum::Double), None),
+            Some(1)
+        );
+        assert_eq!(
+            stats.block_count(None, None, None, Some(BlockCompositionEnum::Solo), None),
+            None
+        );
+        // the outside hitter blocks the opponent's right side
+        assert_eq!(
+            stats.block_count(None, Some(0), Some(ZoneEnum::Two), None, Some(Perfect)),
+            Some(1)
+        );
+        assert_eq!(stats.block_touch_count(Some(oh1), None, None), Some(1));
+    }
+
+    #[test]
+    fn attack_combinations() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let combination = |eval| EventEntry {
+            combination: Some("X1".to_string()),
+            ..event(A, oh1, Some(eval))
+        };
+        let snapshot = compute_snapshot(
+            TeamSideEnum::Them,
+            positions,
+            vec![
+                // perfect pass, kill
+                event(P, oh1, Some(Perfect)),
+                combination(Perfect),
+                // negative dig, attack error
+                event(S, setter, Some(Positive)),
+                event(D, oh1, Some(Negative)),
+                combination(Error),
+                // attacks without a combination are not counted
+                event(P, oh1, Some(Perfect)),
+                event(A, oh1, Some(Perfect)),
+            ],
+        );
+        let stats = &snapshot.stats;
+        assert_eq!(stats.attack_combinations.codes(), vec!["X1".to_string()]);
+        let (total, kill, efficiency) = stats
+            .attack_combination_summary(Some("X1"), None, None)
+            .expect("expected a summary");
+        assert_eq!(total, 2);
+        assert_eq!(format!("{:.1}", kill), "50.0");
+        assert_eq!(format!("{:.1}", efficiency), "0.0");
+        let (total, _, efficiency) = stats
+            .attack_combination_summary(Some("X1"), Some(oh1), Some(Negative))
+            .expect("expected a summary");
+        assert_eq!(total, 1);
+        assert_eq!(format!("{:.1}", efficiency), "-100.0");
+        assert_eq!(
+            stats.attack_combination_summary(Some("X1"), None, Some(Positive)),
+            None
+        );
+    }
+
+    #[test]
+    fn serve_types() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let serve = |serve_type, eval| EventEntry {
+            serve_type: Some(serve_type),
+            ..event(S, setter, Some(eval))
+        };
+        let snapshot = compute_snapshot(
+            TeamSideEnum::Us,
+            positions,
+            vec![
+                serve(ServeTypeEnum::JumpSpin, Perfect),
+                serve(ServeTypeEnum::JumpSpin, Negative),
+                event(D, setter, Some(Positive)),
+                event(A, setter, Some(Perfect)),
+                serve(ServeTypeEnum::Float, Error),
+            ],
+        );
+        let stats = &snapshot.stats;
+        let (positive, total, _) = stats
+            .serve_type_positiveness(Some(setter), None, Metric::Positive)
+            .expect("expected serves");
+        assert_eq!(total, 3);
+        assert_eq!(format!("{:.1}", positive), "33.3");
+        assert_eq!(
+            stats.serve_type_positiveness(None, Some(ServeTypeEnum::JumpSpin), Metric::Efficiency),
+            Some((50.0, 2, 1))
+        );
+        assert_eq!(
+            stats.serve_type_positiveness(None, Some(ServeTypeEnum::Float), Metric::Efficiency),
+            Some((-100.0, 1, -1))
+        );
+        assert_eq!(
+            stats.serve_type_positiveness(None, Some(ServeTypeEnum::Underhand), Metric::Positive),
+            None
+        );
+    }
+}
@@ -1,11 +1,12 @@
 mod tests {
     use crate::shapes::{
-        enums::{GenderEnum, RoleEnum, TeamClassificationEnum, TeamSideEnum},
+        enums::{EventTypeEnum, GenderEnum, RoleEnum, TeamClassificationEnum, TeamSideEnum},
         player::PlayerEntry,
         set::SetEntry,
-        snapshot::Snapshot,
+        snapshot::{EventEntry, Snapshot},
         team::TeamEntry,
     };
+    use chrono::Utc;
     use uuid::Uuid;
 
     #[test]
@@ -133,6 +134,7 @@ mod tests {
                     role: Some(RoleEnum::Setter),
                     number: 1,
                     deleted: false,
+                    ..Default::default()
                 },
                 PlayerEntry {
                     id: oh1,
@@ -140,6 +142,7 @@ mod tests {
                     role: Some(RoleEnum::OutsideHitter),
                     number: 2,
                     deleted: false,
+                    ..Default::default()
                 },
                 PlayerEntry {
                     id: mb2,
@@ -147,6 +150,7 @@ mod tests {
                     role: Some(RoleEnum::MiddleBlocker),
                     number: 3,
                     deleted: false,
+                    ..Default::default()
                 },
                 PlayerEntry {
                     id: opposite,
@@ -154,6 +158,7 @@ mod tests {
                     role: Some(RoleEnum::OppositeHitter),
                     number: 4,
                     deleted: false,
+                    ..Default::default()
                 },
                 PlayerEntry {
                     id: oh2,
@@ -161,6 +166,7 @@ mod tests {
                     role: Some(RoleEnum::OutsideHitter),
                     number: 5,
                     deleted: false,
+                    ..Default::default()
                 },
                 PlayerEntry {
                     id: mb1,
@@ -168,6 +174,7 @@ mod tests {
                     role: Some(RoleEnum::MiddleBlocker),
                     number: 6,
                     deleted: false,
+                    ..Default::default()
                 },
                 PlayerEntry {
                     id: libero,
@@ -175,6 +182,7 @@ mod tests {
                     role: Some(RoleEnum::Libero),
                     number: 7,
                     deleted: false,
+                    ..Default::default()
                 },
                 PlayerEntry {
                     id: setter_replacement,
@@ -182,6 +190,7 @@ mod tests {
                     role: Some(RoleEnum::Setter),
                     number: 8,
                     deleted: false,
+                    ..Default::default()
                 },
                 PlayerEntry {
                     id: some_replacement,
@@ -189,9 +198,13 @@ mod tests {
                     role: Some(RoleEnum::OppositeHitter),
                     number: 9,
                     deleted: false,
+                    ..Default::default()
                 },
             ],
             year: 2024,
+            seasons: vec![],
+            combinations: vec![],
+            lineups: vec![],
         };
 
         (team, setter, setter_replacement)
@@ -214,7 +227,7 @@ mod tests {
         let snapshot: Snapshot = Snapshot::new(&set).expect("expected a valid snapshot");
         let options = snapshot
             .current_lineup
-            .get_available_replacements(&team, setter);
+            .get_available_replacements(&team.players, setter);
         assert_eq!(options.len(), 2);
         assert_eq!(options[0].1.id, setter_replacement);
     }
@@ -240,7 +253,7 @@ mod tests {
             .expect("no errors expected");
         let options = snapshot
             .current_lineup
-            .get_available_replacements(&team, setter_replacement);
+            .get_available_replacements(&team.players, setter_replacement);
         assert_eq!(options.len(), 1);
         assert_eq!(options[0].1.id, setter);
     }
@@ -271,7 +284,7 @@ mod tests {
         assert_eq!(snapshot.current_lineup.get_substitutions().len(), 2);
         let options = snapshot
             .current_lineup
-            .get_available_replacements(&team, setter);
+            .get_available_replacements(&team.players, setter);
         assert_eq!(options.len(), 0);
     }
 
@@ -306,7 +319,60 @@ mod tests {
             .expect("no errors expected");
         let options = snapshot
             .current_lineup
-            .get_available_replacements(&team, setter);
+            .get_available_replacements(&team.players, setter);
         assert!(!options.iter().any(|p| p.1.id == setter_replacement));
     }
+
+    #[test]
+    fn setup_conflicts() {
+        let positions: [Uuid; 6] = [
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let libero = Uuid::new_v4();
+        let bench = Uuid::new_v4();
+        let mut set = SetEntry::new(1, TeamSideEnum::Us, positions, libero, None, positions[0])
+            .expect("expected a valid set");
+        set.events.push(EventEntry {
+            timestamp: Utc::now(),
+            event_type: EventTypeEnum::R,
+            player: Some(positions[2]),
+            eval: None,
+            target_player: Some(Uuid::new_v4()),
+            second_blocker: None,
+            third_blocker: None,
+            touched: false,
+            combination: None,
+            serve_type: None,
+            notes: None,
+            tags: vec![],
+            awarded_to: None,
+        });
+        assert_eq!(set.setup_conflicts().expect("expected a replay"), vec![]);
+        // same players in a different order: the substitution still fits
+        let mut reordered = positions;
+        reordered.swap(2, 3);
+        let fixed = set
+            .with_setup(reordered, libero, None, positions[0])
+            .expect("expected a valid set");
+        assert_eq!(fixed.events.len(), 1);
+        assert_eq!(fixed.setup_conflicts().expect("expected a replay"), vec![]);
+        // the replaced player did not start the set anymore
+        let mut changed = positions;
+        changed[2] = bench;
+        let conflicts = set
+            .with_setup(changed, libero, None, positions[0])
+            .expect("expected a valid set")
+            .setup_conflicts()
+            .expect("expected a replay");
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].event_index, 0);
+        assert_eq!(conflicts[0].event_type, EventTypeEnum::R);
+        // the setter must be in the lineup
+        assert!(set.with_setup(changed, libero, None, libero).is_err());
+    }
 }
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use crate::shapes::{
+        enums::{EvalEnum, EventTypeEnum, TeamSideEnum},
+        set::SetEntry,
+        timing::format_seconds,
+    };
+    use crate::tests::fixtures::event_at;
+    use uuid::Uuid;
+
+    #[test]
+    fn set_timing_from_events() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let mut set = SetEntry::new(1, TeamSideEnum::Us, positions, Uuid::new_v4(), None, setter)
+            .expect("expected a valid set");
+        set.events = vec![
+            // 8 seconds of play
+            event_at(S, Some(setter), Some(Positive), 0),
+            event_at(D, Some(oh1), Some(Positive), 4),
+            event_at(A, Some(oh1), Some(Perfect), 8),
+            // 20 seconds later, ace
+            event_at(S, Some(setter), Some(Perfect), 28),
+            // timeout, two minutes later
+            event_at(S, Some(setter), Some(Error), 148),
+            // 30 seconds later, 5 seconds of play
+            event_at(D, Some(oh1), Some(Positive), 178),
+            event_at(A, Some(oh1), Some(Perfect), 183),
+        ];
+        let (snapshot, _) = set.compute_snapshot().expect("expected a valid snapshot");
+        let timing = snapshot.get_timing(1).expect("expected the set timing");
+        assert_eq!(timing.duration_seconds, 183);
+        assert_eq!(timing.playing_seconds, 13);
+        assert_eq!(
+            timing
+                .avg_between_rallies_seconds
+                .map(|v| format!("{:.1}", v)),
+            Some("25.0".to_string())
+        );
+        assert_eq!(timing.long_breaks.len(), 1);
+        assert_eq!(timing.long_breaks[0].seconds, 120);
+        assert_eq!(timing.long_breaks[0].score, (2, 0));
+        // no events, no timing
+        set.events = vec![];
+        let (snapshot, _) = set.compute_snapshot().expect("expected a valid snapshot");
+        assert!(snapshot.get_timing(1).is_none());
+    }
+
+    #[test]
+    fn seconds_formatting() {
+        assert_eq!(format_seconds(0), "0:00");
+        assert_eq!(format_seconds(75), "1:15");
+        assert_eq!(format_seconds(3725), "1:02:05");
+        assert_eq!(format_seconds(-3), "0:00");
+    }
+}
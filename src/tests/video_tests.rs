@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use crate::tests::fixtures::{event_at, kick_off};
+    use crate::{
+        reporting::clip_list::{render_clip_list, timecode},
+        shapes::{
+            enums::{ClipListFormatEnum, EvalEnum, EventTypeEnum, TeamSideEnum},
+            player::PlayerEntry,
+            r#match::MatchEntry,
+            set::SetEntry,
+            team::TeamEntry,
+            video::{build_clips, parse_position, ClipFilter, VideoEntry},
+        },
+    };
+    use chrono::{DateTime, Duration};
+    use std::path::PathBuf;
+    use uuid::Uuid;
+
+    #[test]
+    fn video_positions() {
+        assert_eq!(parse_position("42"), Some(42.0));
+        assert_eq!(parse_position("1:05"), Some(65.0));
+        assert_eq!(parse_position("1:02:03.5"), Some(3723.5));
+        assert_eq!(parse_position("1:75"), None);
+        assert_eq!(parse_position("1:2:3:4"), None);
+        assert_eq!(parse_position(""), None);
+        // the serve of the first rally can be seen two minutes into the video
+        let serve = event_at(EventTypeEnum::S, None, Some(EvalEnum::Positive), 0);
+        let mut video = VideoEntry::new(PathBuf::from("set1.mp4"), serve.timestamp);
+        video.sync(&serve, 120.0);
+        assert_eq!(video.position(serve.timestamp), 120.0);
+        assert_eq!(
+            video.position(serve.timestamp + Duration::seconds(30)),
+            150.0
+        );
+        assert_eq!(timecode(150.0), "00:02:30:00");
+        assert_eq!(timecode(3723.52), "01:02:03:13");
+    }
+
+    #[test]
+    fn clips_from_filter() {
+        use EvalEnum::*;
+        use EventTypeEnum::*;
+        let setter = Uuid::new_v4();
+        let oh1 = Uuid::new_v4();
+        let positions: [Uuid; 6] = [
+            setter,
+            oh1,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let mut set = SetEntry::new(
+            1,
+            TeamSideEnum::Them,
+            positions,
+            Uuid::new_v4(),
+            None,
+            setter,
+        )
+        .expect("expected a valid set");
+        set.events = vec![
+            event_at(P, Some(oh1), Some(Negative), 0),
+            event_at(A, Some(oh1), Some(Perfect), 4),
+            event_at(P, Some(oh1), Some(Negative), 30),
+            event_at(P, Some(oh1), Some(Perfect), 60),
+            // close to the previous reception: without filtering by eval the clips are merged
+            event_at(P, Some(oh1), Some(Negative), 63),
+        ];
+        let mut video = VideoEntry::new(PathBuf::from("/videos/set1.mp4"), kick_off());
+        video.sync(&set.events[0], 10.0);
+        set.video = Some(video);
+        let players = vec![PlayerEntry {
+            id: oh1,
+            name: "David Crane".to_string(),
+            number: 7,
+            ..Default::default()
+        }];
+        let m = MatchEntry {
+            opponent: "Them".to_string(),
+            opponent_id: None,
+            season: None,
+            date: DateTime::parse_from_rfc3339("2025-01-01T18:00:00+01:00")
+                .expect("expected a valid date"),
+            id: "match".to_string(),
+            team: TeamEntry {
+                players: players.clone(),
+                ..Default::default()
+            },
+            players,
+            home: true,
+            sets: vec![set],
+            notes: None,
+            tags: vec![],
+        };
+        let filter = ClipFilter {
+            player: Some(oh1),
+            event_type: Some(P),
+            eval: Some(Negative),
+        };
+        let clips = build_clips(&m, &filter);
+        assert_eq!(clips.len(), 3);
+        assert_eq!((clips[0].start, clips[0].end), (5.0, 13.0));
+        assert_eq!(clips[0].label, "set 1 7 David Crane P -");
+        assert_eq!((clips[2].start, clips[2].end), (68.0, 76.0));
+        let all_receptions = ClipFilter {
+            event_type: Some(P),
+            ..Default::default()
+        };
+        let clips = build_clips(&m, &all_receptions);
+        assert_eq!(clips.len(), 3);
+        assert_eq!((clips[2].start, clips[2].end), (65.0, 76.0));
+        let edl = render_clip_list("us - them", &clips, ClipListFormatEnum::Edl);
+        assert!(edl.contains(
+            "001  AX       V     C        00:00:05:00 00:00:13:00 00:00:00:00 00:00:08:00"
+        ));
+        assert!(edl.contains(
+            "003  AX       V     C        00:01:05:00 00:01:16:00 00:00:16:00 00:00:27:00"
+        ));
+        let concat = render_clip_list("us - them", &clips, ClipListFormatEnum::FfmpegConcat);
+        assert!(concat.contains("file '/videos/set1.mp4'\ninpoint 65.000\noutpoint 76.000"));
+    }
+}